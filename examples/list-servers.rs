@@ -26,7 +26,7 @@ async fn main() {
 
     let servers: Vec<openstack::compute::Server> = os
         .find_servers()
-        .sort_by(openstack::Sort::Asc(sorting))
+        .sort_by(openstack::Sort::Asc(sorting.clone()))
         .detailed()
         .into_stream()
         .take(10)
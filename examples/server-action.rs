@@ -51,6 +51,34 @@ async fn main() {
             .wait()
             .await
             .expect("Failed to reach ACTIVE"),
+        "pause" => server
+            .pause()
+            .await
+            .expect("Cannot pause the server")
+            .wait()
+            .await
+            .expect("Failed to reach PAUSED"),
+        "unpause" => server
+            .unpause()
+            .await
+            .expect("Cannot unpause the server")
+            .wait()
+            .await
+            .expect("Failed to reach ACTIVE"),
+        "shelve" => server
+            .shelve()
+            .await
+            .expect("Cannot shelve the server")
+            .wait()
+            .await
+            .expect("Failed to reach SHELVED"),
+        "unshelve" => server
+            .unshelve()
+            .await
+            .expect("Cannot unshelve the server")
+            .wait()
+            .await
+            .expect("Failed to reach ACTIVE"),
         "delete" => server
             .delete()
             .await
@@ -58,7 +86,10 @@ async fn main() {
             .wait()
             .await
             .expect("Failed to delete the server"),
-        _ => panic!("Unknown action, supported are 'start' and 'stop'"),
+        _ => panic!(
+            "Unknown action, supported are 'start', 'stop', 'reboot', 'pause', 'unpause', \
+             'shelve', 'unshelve' and 'delete'"
+        ),
     }
 }
 
@@ -14,17 +14,71 @@
 
 //! Foundation bits exposing the Block Storage API.
 
+use std::collections::HashMap;
 use std::fmt::Debug;
 
 use osauth::services::BLOCK_STORAGE;
 use osauth::ErrorKind;
 use serde::Serialize;
 
+use super::super::common::ApiVersion;
 use super::super::session::Session;
 use super::super::utils;
-use super::super::Result;
+use super::super::{Error, Result};
 use super::protocol::*;
 
+const API_VERSION_EXTEND_ATTACHED: ApiVersion = ApiVersion(3, 42);
+
+/// Associate QoS specs with a volume type.
+pub async fn associate_qos_specs<S1, S2>(session: &Session, id: S1, volume_type: S2) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    trace!(
+        "Associating QoS specs {} with volume type {}",
+        id.as_ref(),
+        volume_type.as_ref()
+    );
+    let _ = session
+        .get(BLOCK_STORAGE, &["qos-specs", id.as_ref(), "associate"])
+        .query(&[("vol_type_id", volume_type.as_ref())])
+        .send()
+        .await?;
+    debug!(
+        "Associated QoS specs {} with volume type {}",
+        id.as_ref(),
+        volume_type.as_ref()
+    );
+    Ok(())
+}
+
+/// Delete QoS specs.
+pub async fn delete_qos_specs<S: AsRef<str>>(session: &Session, id: S, force: bool) -> Result<()> {
+    trace!("Deleting QoS specs {}", id.as_ref());
+    let _ = session
+        .delete(BLOCK_STORAGE, &["qos-specs", id.as_ref()])
+        .query(&[("force", force)])
+        .send()
+        .await?;
+    debug!("Successfully deleted QoS specs {}", id.as_ref());
+    Ok(())
+}
+
+/// Delete a snapshot.
+pub async fn delete_snapshot<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
+    trace!("Deleting snapshot {}", id.as_ref());
+    let _ = session
+        .delete(BLOCK_STORAGE, &["snapshots", id.as_ref()])
+        .send()
+        .await?;
+    debug!(
+        "Successfully requested deletion of snapshot {}",
+        id.as_ref()
+    );
+    Ok(())
+}
+
 /// Delete a volume.
 pub async fn delete_volume<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
     trace!("Deleting volume {}", id.as_ref());
@@ -36,6 +90,230 @@ pub async fn delete_volume<S: AsRef<str>>(session: &Session, id: S) -> Result<()
     Ok(())
 }
 
+/// Delete a volume type.
+pub async fn delete_volume_type<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
+    trace!("Deleting volume type {}", id.as_ref());
+    let _ = session
+        .delete(BLOCK_STORAGE, &["types", id.as_ref()])
+        .send()
+        .await?;
+    debug!("Successfully deleted volume type {}", id.as_ref());
+    Ok(())
+}
+
+/// Delete an extra spec of a volume type.
+pub async fn delete_volume_type_extra_spec<S1, S2>(session: &Session, id: S1, key: S2) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    trace!(
+        "Deleting extra spec {} of volume type {}",
+        key.as_ref(),
+        id.as_ref()
+    );
+    let _ = session
+        .delete(
+            BLOCK_STORAGE,
+            &["types", id.as_ref(), "extra_specs", key.as_ref()],
+        )
+        .send()
+        .await?;
+    debug!(
+        "Deleted extra spec {} of volume type {}",
+        key.as_ref(),
+        id.as_ref()
+    );
+    Ok(())
+}
+
+/// Extend a volume to the new size in GiB.
+///
+/// Extending an attached volume requires API version 3.42.
+pub async fn extend_volume<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    new_size: u64,
+    attached: bool,
+) -> Result<()> {
+    trace!("Extending volume {} to {} GiB", id.as_ref(), new_size);
+    let action = VolumeAction::Extend { new_size };
+    let mut builder = session
+        .post(BLOCK_STORAGE, &["volumes", id.as_ref(), "action"])
+        .json(&action);
+    if attached {
+        let version = session
+            .pick_api_version(BLOCK_STORAGE, Some(API_VERSION_EXTEND_ATTACHED))
+            .await?
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::IncompatibleApiVersion,
+                    "Extending an attached volume requires block storage API version 3.42",
+                )
+            })?;
+        // osauth does not know the microversion header of the block storage service.
+        builder = builder.header("OpenStack-API-Version", format!("volume {}", version));
+    }
+    let _ = builder.send().await?;
+    debug!(
+        "Successfully extended volume {} to {} GiB",
+        id.as_ref(),
+        new_size
+    );
+    Ok(())
+}
+
+/// Disassociate QoS specs from a volume type.
+pub async fn disassociate_qos_specs<S1, S2>(
+    session: &Session,
+    id: S1,
+    volume_type: S2,
+) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    trace!(
+        "Disassociating QoS specs {} from volume type {}",
+        id.as_ref(),
+        volume_type.as_ref()
+    );
+    let _ = session
+        .get(BLOCK_STORAGE, &["qos-specs", id.as_ref(), "disassociate"])
+        .query(&[("vol_type_id", volume_type.as_ref())])
+        .send()
+        .await?;
+    debug!(
+        "Disassociated QoS specs {} from volume type {}",
+        id.as_ref(),
+        volume_type.as_ref()
+    );
+    Ok(())
+}
+
+/// Get QoS specs.
+pub async fn get_qos_specs<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<QosSpecs> {
+    let s = id_or_name.as_ref();
+    match get_qos_specs_by_id(session, s).await {
+        Ok(value) => Ok(value),
+        Err(err) if err.kind() == ErrorKind::ResourceNotFound => {
+            let result = utils::one(
+                list_qos_specs(session)
+                    .await?
+                    .into_iter()
+                    .filter(|item| item.name == s),
+                "QoS specs with given name or ID not found",
+                "Too many QoS specs found with given name",
+            )?;
+            trace!("Received {:?}", result);
+            Ok(result)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Get QoS specs by their ID.
+pub async fn get_qos_specs_by_id<S: AsRef<str>>(session: &Session, id: S) -> Result<QosSpecs> {
+    trace!("Fetching QoS specs {}", id.as_ref());
+    let root: QosSpecsRoot = session
+        .get(BLOCK_STORAGE, &["qos-specs", id.as_ref()])
+        .fetch()
+        .await?;
+    trace!("Received {:?}", root.qos_specs);
+    Ok(root.qos_specs)
+}
+
+/// Get a snapshot.
+pub async fn get_snapshot<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<Snapshot> {
+    let s = id_or_name.as_ref();
+    match get_snapshot_by_id(session, s).await {
+        Ok(value) => Ok(value),
+        Err(err) if err.kind() == ErrorKind::ResourceNotFound => {
+            get_snapshot_by_name(session, s).await
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Get a snapshot by its ID.
+pub async fn get_snapshot_by_id<S: AsRef<str>>(session: &Session, id: S) -> Result<Snapshot> {
+    trace!("Fetching snapshot {}", id.as_ref());
+    let root: SnapshotRoot = session
+        .get(BLOCK_STORAGE, &["snapshots", id.as_ref()])
+        .fetch()
+        .await?;
+    trace!("Received {:?}", root.snapshot);
+    Ok(root.snapshot)
+}
+
+/// Get a snapshot by its name.
+pub async fn get_snapshot_by_name<S: AsRef<str>>(session: &Session, name: S) -> Result<Snapshot> {
+    trace!("Get snapshot by name {}", name.as_ref());
+    let root: SnapshotsRoot = session
+        .get(BLOCK_STORAGE, &["snapshots"])
+        .query(&[("name", name.as_ref())])
+        .fetch()
+        .await?;
+    let result = utils::one(
+        root.snapshots,
+        "Snapshot with given name or ID not found",
+        "Too many snapshots found with given name",
+    )?;
+    trace!("Received {:?}", result);
+    Ok(result)
+}
+
+/// Get a volume type.
+pub async fn get_volume_type<S: AsRef<str>>(
+    session: &Session,
+    id_or_name: S,
+) -> Result<VolumeType> {
+    let s = id_or_name.as_ref();
+    match get_volume_type_by_id(session, s).await {
+        Ok(value) => Ok(value),
+        Err(err) if err.kind() == ErrorKind::ResourceNotFound => {
+            get_volume_type_by_name(session, s).await
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Get a volume type by its ID.
+pub async fn get_volume_type_by_id<S: AsRef<str>>(session: &Session, id: S) -> Result<VolumeType> {
+    trace!("Fetching volume type {}", id.as_ref());
+    let root: VolumeTypeRoot = session
+        .get(BLOCK_STORAGE, &["types", id.as_ref()])
+        .fetch()
+        .await?;
+    trace!("Received {:?}", root.volume_type);
+    Ok(root.volume_type)
+}
+
+/// Get a volume type by its name.
+///
+/// The API does not support filtering volume types by name, so all types are
+/// fetched and filtered locally.
+pub async fn get_volume_type_by_name<S: AsRef<str>>(
+    session: &Session,
+    name: S,
+) -> Result<VolumeType> {
+    trace!("Get volume type by name {}", name.as_ref());
+    let root: VolumeTypesRoot = session
+        .get(BLOCK_STORAGE, &["types"])
+        .query(&[("is_public", "None")])
+        .fetch()
+        .await?;
+    let result = utils::one(
+        root.volume_types
+            .into_iter()
+            .filter(|item| item.name == name.as_ref()),
+        "Volume type with given name or ID not found",
+        "Too many volume types found with given name",
+    )?;
+    trace!("Received {:?}", result);
+    Ok(result)
+}
+
 /// Get an volume.
 pub async fn get_volume<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<Volume> {
     let s = id_or_name.as_ref();
@@ -76,6 +354,29 @@ pub async fn get_volume_by_name<S: AsRef<str>>(session: &Session, name: S) -> Re
     Ok(result)
 }
 
+/// List QoS specs.
+pub async fn list_qos_specs(session: &Session) -> Result<Vec<QosSpecs>> {
+    trace!("Listing QoS specs");
+    let root: QosSpecsListRoot = session.get(BLOCK_STORAGE, &["qos-specs"]).fetch().await?;
+    trace!("Received QoS specs: {:?}", root.qos_specs);
+    Ok(root.qos_specs)
+}
+
+/// List snapshots.
+pub async fn list_snapshots<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<Vec<Snapshot>> {
+    trace!("Listing snapshots with {:?}", query);
+    let root: SnapshotsRoot = session
+        .get(BLOCK_STORAGE, &["snapshots", "detail"])
+        .query(query)
+        .fetch()
+        .await?;
+    trace!("Received snapshots: {:?}", root.snapshots);
+    Ok(root.snapshots)
+}
+
 /// List volumes.
 pub async fn list_volumes<Q: Serialize + Sync + Debug>(
     session: &Session,
@@ -91,6 +392,138 @@ pub async fn list_volumes<Q: Serialize + Sync + Debug>(
     Ok(root.volumes)
 }
 
+/// List projects with access to a private volume type.
+pub async fn list_volume_type_access<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+) -> Result<Vec<VolumeTypeAccess>> {
+    trace!("Listing access to volume type {}", id.as_ref());
+    let root: VolumeTypeAccessRoot = session
+        .get(
+            BLOCK_STORAGE,
+            &["types", id.as_ref(), "os-volume-type-access"],
+        )
+        .fetch()
+        .await?;
+    trace!("Received volume type access: {:?}", root.volume_type_access);
+    Ok(root.volume_type_access)
+}
+
+/// List volume types.
+pub async fn list_volume_types<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<Vec<VolumeType>> {
+    trace!("Listing volume types with {:?}", query);
+    let root: VolumeTypesRoot = session
+        .get(BLOCK_STORAGE, &["types"])
+        .query(query)
+        .fetch()
+        .await?;
+    trace!("Received volume types: {:?}", root.volume_types);
+    Ok(root.volume_types)
+}
+
+/// Create or update extra specs of a volume type.
+pub async fn set_volume_type_extra_specs<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    extra_specs: HashMap<String, String>,
+) -> Result<HashMap<String, String>> {
+    debug!(
+        "Setting extra specs {:?} on volume type {}",
+        extra_specs,
+        id.as_ref()
+    );
+    let body = ExtraSpecsRoot { extra_specs };
+    let root: ExtraSpecsRoot = session
+        .post(BLOCK_STORAGE, &["types", id.as_ref(), "extra_specs"])
+        .json(&body)
+        .fetch()
+        .await?;
+    debug!("Updated extra specs of volume type {}", id.as_ref());
+    Ok(root.extra_specs)
+}
+
+/// Update a volume.
+pub async fn update_volume<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    update: VolumeUpdate,
+) -> Result<Volume> {
+    debug!("Updating volume {} with {:?}", id.as_ref(), update);
+    let body = VolumeUpdateRoot { volume: update };
+    let root: VolumeRoot = session
+        .put(BLOCK_STORAGE, &["volumes", id.as_ref()])
+        .json(&body)
+        .fetch()
+        .await?;
+    debug!("Updated volume {:?}", root.volume);
+    Ok(root.volume)
+}
+
+/// Run an action on a volume.
+pub async fn volume_action<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    action: VolumeAction,
+) -> Result<()> {
+    trace!("Running {:?} on volume {}", action, id.as_ref());
+    let _ = session
+        .post(BLOCK_STORAGE, &["volumes", id.as_ref(), "action"])
+        .json(&action)
+        .send()
+        .await?;
+    debug!("Successfully ran {:?} on volume {}", action, id.as_ref());
+    Ok(())
+}
+
+/// Run an action on a volume type.
+pub async fn volume_type_action<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    action: VolumeTypeAction,
+) -> Result<()> {
+    trace!("Running {:?} on volume type {}", action, id.as_ref());
+    let _ = session
+        .post(BLOCK_STORAGE, &["types", id.as_ref(), "action"])
+        .json(&action)
+        .send()
+        .await?;
+    debug!(
+        "Successfully ran {:?} on volume type {}",
+        action,
+        id.as_ref()
+    );
+    Ok(())
+}
+
+/// Create QoS specs.
+pub async fn create_qos_specs(session: &Session, request: QosSpecsCreate) -> Result<QosSpecs> {
+    debug!("Creating QoS specs with {:?}", request);
+    let body = QosSpecsCreateRoot { qos_specs: request };
+    let root: QosSpecsRoot = session
+        .post(BLOCK_STORAGE, &["qos-specs"])
+        .json(&body)
+        .fetch()
+        .await?;
+    debug!("Created QoS specs {:?}", root.qos_specs);
+    Ok(root.qos_specs)
+}
+
+/// Create a snapshot.
+pub async fn create_snapshot(session: &Session, request: SnapshotCreate) -> Result<Snapshot> {
+    debug!("Creating a snapshot with {:?}", request);
+    let body = SnapshotCreateRoot { snapshot: request };
+    let root: SnapshotRoot = session
+        .post(BLOCK_STORAGE, &["snapshots"])
+        .json(&body)
+        .fetch()
+        .await?;
+    trace!("Requested creation of snapshot {:?}", root.snapshot);
+    Ok(root.snapshot)
+}
+
 /// Create a volume.
 pub async fn create_volume(session: &Session, request: VolumeCreate) -> Result<Volume> {
     debug!("Creating a volume with {:?}", request);
@@ -103,3 +536,21 @@ pub async fn create_volume(session: &Session, request: VolumeCreate) -> Result<V
     trace!("Requested creation of volume {:?}", root.volume);
     Ok(root.volume)
 }
+
+/// Create a volume type.
+pub async fn create_volume_type(
+    session: &Session,
+    request: VolumeTypeCreate,
+) -> Result<VolumeType> {
+    debug!("Creating a volume type with {:?}", request);
+    let body = VolumeTypeCreateRoot {
+        volume_type: request,
+    };
+    let root: VolumeTypeRoot = session
+        .post(BLOCK_STORAGE, &["types"])
+        .json(&body)
+        .fetch()
+        .await?;
+    debug!("Created volume type {:?}", root.volume_type);
+    Ok(root.volume_type)
+}
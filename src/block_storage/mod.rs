@@ -16,7 +16,16 @@
 
 mod api;
 mod protocol;
+mod qos_specs;
+mod snapshots;
+mod volume_types;
 mod volumes;
 
-pub use self::protocol::{VolumeAttachment, VolumeSortKey, VolumeStatus};
-pub use self::volumes::{NewVolume, Volume, VolumeQuery};
+pub use self::protocol::{
+    QosSpecsConsumer, RetypeMigrationPolicy, SnapshotSortKey, SnapshotStatus, VolumeAttachment,
+    VolumeSortKey, VolumeStatus,
+};
+pub use self::qos_specs::{NewQosSpecs, QosSpecs};
+pub use self::snapshots::{NewSnapshot, Snapshot, SnapshotQuery, SnapshotStatusWaiter};
+pub use self::volume_types::{NewVolumeType, VolumeType, VolumeTypeQuery};
+pub use self::volumes::{NewVolume, Volume, VolumeQuery, VolumeStatusWaiter};
@@ -45,6 +45,48 @@ protocol_enum! {
     }
 }
 
+protocol_enum! {
+    #[doc = "Where QoS specs are enforced."]
+    enum QosSpecsConsumer {
+        BackEnd = "back-end",
+        Both = "both",
+        FrontEnd = "front-end"
+    }
+}
+
+protocol_enum! {
+    #[doc = "Migration policy to use when changing the volume type."]
+    enum RetypeMigrationPolicy {
+        Never = "never",
+        OnDemand = "on-demand"
+    }
+}
+
+protocol_enum! {
+    #[doc = "Possible snapshot statuses."]
+    enum SnapshotStatus {
+        Creating = "creating",
+        Available = "available",
+        BackingUp = "backing-up",
+        Deleting = "deleting",
+        Error = "error",
+        Deleted = "deleted",
+        Unmanaging = "unmanaging",
+        Restoring = "restoring",
+        ErrorDeleting = "error_deleting"
+    }
+}
+
+protocol_enum! {
+    #[doc = "Available sort keys for snapshots."]
+    enum SnapshotSortKey {
+        CreatedAt = "created_at",
+        Id = "id",
+        Name = "name",
+        UpdatedAt = "updated_at"
+    }
+}
+
 protocol_enum! {
     #[doc = "Available sort keys."]
     enum VolumeSortKey {
@@ -128,6 +170,116 @@ impl Default for VolumeSortKey {
     }
 }
 
+/// QoS specs.
+#[derive(Debug, Clone, Deserialize)]
+pub struct QosSpecs {
+    pub consumer: QosSpecsConsumer,
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub specs: HashMap<String, String>,
+}
+
+/// A QoS specs root.
+#[derive(Clone, Debug, Deserialize)]
+pub struct QosSpecsRoot {
+    pub qos_specs: QosSpecs,
+}
+
+/// A list of QoS specs.
+#[derive(Clone, Debug, Deserialize)]
+pub struct QosSpecsListRoot {
+    pub qos_specs: Vec<QosSpecs>,
+}
+
+/// QoS specs arguments for a create request.
+#[derive(Debug, Clone, Serialize)]
+pub struct QosSpecsCreate {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consumer: Option<QosSpecsConsumer>,
+    #[serde(flatten)]
+    pub specs: HashMap<String, String>,
+}
+
+/// A QoS specs create request.
+#[derive(Clone, Debug, Serialize)]
+pub struct QosSpecsCreateRoot {
+    pub qos_specs: QosSpecsCreate,
+}
+
+/// A snapshot.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Snapshot {
+    #[serde(default)]
+    pub consumes_quota: Option<bool>,
+    pub created_at: DateTime,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub group_snapshot_id: Option<String>,
+    pub id: String,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(rename = "os-extended-snapshot-attributes:progress", default)]
+    pub progress: Option<String>,
+    #[serde(rename = "os-extended-snapshot-attributes:project_id", default)]
+    pub project_id: Option<String>,
+    pub size: u64,
+    pub status: SnapshotStatus,
+    #[serde(default)]
+    pub updated_at: Option<DateTime>,
+    #[serde(default)]
+    pub user_id: Option<String>,
+    pub volume_id: String,
+}
+
+/// A snapshot root.
+#[derive(Clone, Debug, Deserialize)]
+pub struct SnapshotRoot {
+    pub snapshot: Snapshot,
+}
+
+/// A list of snapshots.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SnapshotsRoot {
+    pub snapshots: Vec<Snapshot>,
+}
+
+/// Snapshot arguments for a create request.
+#[derive(Debug, Clone, Serialize)]
+pub struct SnapshotCreate {
+    pub volume_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+/// A snapshot create request.
+#[derive(Clone, Debug, Serialize)]
+pub struct SnapshotCreateRoot {
+    pub snapshot: SnapshotCreate,
+}
+
+impl SnapshotCreate {
+    pub fn new(volume_id: String) -> SnapshotCreate {
+        SnapshotCreate {
+            volume_id,
+            force: None,
+            name: None,
+            description: None,
+            metadata: None,
+        }
+    }
+}
+
 /// A volume attachment.
 #[derive(Debug, Clone, Deserialize)]
 #[non_exhaustive]
@@ -233,6 +385,115 @@ pub struct VolumesRoot {
     pub volumes: Vec<Volume>,
 }
 
+/// A volume update.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct VolumeUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// A volume update request.
+#[derive(Clone, Debug, Serialize)]
+pub struct VolumeUpdateRoot {
+    pub volume: VolumeUpdate,
+}
+
+/// An action to perform on a volume.
+#[derive(Clone, Debug, Serialize)]
+pub enum VolumeAction {
+    #[serde(rename = "os-extend")]
+    Extend { new_size: u64 },
+    #[serde(rename = "os-reset_status")]
+    ResetStatus { status: VolumeStatus },
+    #[serde(rename = "os-retype")]
+    Retype {
+        new_type: String,
+        migration_policy: RetypeMigrationPolicy,
+    },
+    #[serde(rename = "os-set_bootable")]
+    SetBootable { bootable: bool },
+    #[serde(rename = "os-update_readonly_flag")]
+    SetReadonly { readonly: bool },
+}
+
+/// A volume type.
+#[derive(Debug, Clone, Deserialize)]
+pub struct VolumeType {
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub extra_specs: HashMap<String, String>,
+    pub id: String,
+    pub is_public: bool,
+    pub name: String,
+    #[serde(default)]
+    pub qos_specs_id: Option<String>,
+}
+
+/// A volume type root.
+#[derive(Clone, Debug, Deserialize)]
+pub struct VolumeTypeRoot {
+    pub volume_type: VolumeType,
+}
+
+/// A list of volume types.
+#[derive(Clone, Debug, Deserialize)]
+pub struct VolumeTypesRoot {
+    pub volume_types: Vec<VolumeType>,
+}
+
+/// Volume type arguments for a create request.
+#[derive(Debug, Clone, Serialize)]
+pub struct VolumeTypeCreate {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub extra_specs: HashMap<String, String>,
+    #[serde(
+        rename = "os-volume-type-access:is_public",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub is_public: Option<bool>,
+}
+
+/// A volume type create request.
+#[derive(Clone, Debug, Serialize)]
+pub struct VolumeTypeCreateRoot {
+    pub volume_type: VolumeTypeCreate,
+}
+
+/// Extra specs of a volume type.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ExtraSpecsRoot {
+    pub extra_specs: HashMap<String, String>,
+}
+
+/// Access of a project to a private volume type.
+#[derive(Clone, Debug, Deserialize)]
+pub struct VolumeTypeAccess {
+    pub project_id: String,
+}
+
+/// A list of volume type accesses.
+#[derive(Clone, Debug, Deserialize)]
+pub struct VolumeTypeAccessRoot {
+    pub volume_type_access: Vec<VolumeTypeAccess>,
+}
+
+/// An action to perform on a volume type.
+#[derive(Clone, Debug, Serialize)]
+pub enum VolumeTypeAction {
+    #[serde(rename = "addProjectAccess")]
+    AddProjectAccess { project: String },
+    #[serde(rename = "removeProjectAccess")]
+    RemoveProjectAccess { project: String },
+}
+
 /// Volume arguments for a create request.
 #[derive(Debug, Clone, Serialize)]
 pub struct VolumeCreate {
@@ -285,3 +546,89 @@ impl VolumeCreate {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_unknown_enum_values() {
+        assert_unknown_protocol_enums!(
+            QosSpecsConsumer,
+            RetypeMigrationPolicy,
+            SnapshotSortKey,
+            SnapshotStatus,
+            VolumeSortKey,
+            VolumeStatus
+        );
+    }
+
+    #[test]
+    fn test_volume_action_serialize() {
+        assert_eq!(
+            serde_json::to_value(VolumeAction::Extend { new_size: 2 }).unwrap(),
+            serde_json::json!({"os-extend": {"new_size": 2}})
+        );
+        assert_eq!(
+            serde_json::to_value(VolumeAction::Retype {
+                new_type: "fast".into(),
+                migration_policy: RetypeMigrationPolicy::OnDemand,
+            })
+            .unwrap(),
+            serde_json::json!({
+                "os-retype": {"new_type": "fast", "migration_policy": "on-demand"}
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(VolumeAction::ResetStatus {
+                status: VolumeStatus::Available
+            })
+            .unwrap(),
+            serde_json::json!({"os-reset_status": {"status": "available"}})
+        );
+    }
+
+    #[test]
+    fn test_volume_type_json() {
+        let root: VolumeTypesRoot = serde_json::from_value(serde_json::json!({
+            "volume_types": [{
+                "description": "Fast storage",
+                "extra_specs": {"volume_backend_name": "ssd"},
+                "id": "6685584b-1eac-4da6-b5c3-555430cf68ff",
+                "is_public": false,
+                "name": "ssd",
+                "os-volume-type-access:is_public": false,
+                "qos_specs_id": null
+            }]
+        }))
+        .unwrap();
+        let volume_type = &root.volume_types[0];
+        assert_eq!(volume_type.name, "ssd");
+        assert!(!volume_type.is_public);
+        assert!(volume_type.qos_specs_id.is_none());
+        assert_eq!(
+            volume_type.extra_specs.get("volume_backend_name").unwrap(),
+            "ssd"
+        );
+    }
+
+    #[test]
+    fn test_qos_specs_create_serialize() {
+        let mut request = QosSpecsCreate {
+            name: "limited".into(),
+            consumer: Some(QosSpecsConsumer::BackEnd),
+            specs: HashMap::new(),
+        };
+        let _ = request.specs.insert("total_iops_sec".into(), "1000".into());
+        assert_eq!(
+            serde_json::to_value(QosSpecsCreateRoot { qos_specs: request }).unwrap(),
+            serde_json::json!({
+                "qos_specs": {
+                    "name": "limited",
+                    "consumer": "back-end",
+                    "total_iops_sec": "1000"
+                }
+            })
+        );
+    }
+}
@@ -0,0 +1,152 @@
+// Copyright 2026 The rust-openstack Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! QoS specs management via Block Storage API.
+//!
+//! All operations on QoS specs are admin-only by default.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+use super::super::common::{Refresh, VolumeTypeRef};
+use super::super::session::Session;
+use super::super::Result;
+use super::{api, protocol};
+
+/// Structure representing a set of QoS specs.
+#[derive(Clone, Debug)]
+pub struct QosSpecs {
+    session: Session,
+    inner: protocol::QosSpecs,
+}
+
+/// A request to create QoS specs.
+#[derive(Clone, Debug)]
+pub struct NewQosSpecs {
+    session: Session,
+    inner: protocol::QosSpecsCreate,
+}
+
+impl QosSpecs {
+    /// Create a QosSpecs object.
+    pub(crate) async fn new<Id: AsRef<str>>(session: Session, id: Id) -> Result<QosSpecs> {
+        let inner = api::get_qos_specs(&session, id).await?;
+        Ok(QosSpecs { session, inner })
+    }
+
+    /// List all QoS specs.
+    pub(crate) async fn list(session: Session) -> Result<Vec<QosSpecs>> {
+        Ok(api::list_qos_specs(&session)
+            .await?
+            .into_iter()
+            .map(|inner| QosSpecs {
+                session: session.clone(),
+                inner,
+            })
+            .collect())
+    }
+
+    transparent_property! {
+        #[doc = "Where the QoS specs are enforced."]
+        consumer: clone protocol::QosSpecsConsumer
+    }
+
+    transparent_property! {
+        #[doc = "UUID of the QoS specs."]
+        id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Name of the QoS specs."]
+        name: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Key-value pairs of the QoS specs."]
+        specs: ref HashMap<String, String>
+    }
+
+    /// Associate the QoS specs with a volume type.
+    pub async fn associate<T: Into<VolumeTypeRef>>(&self, volume_type: T) -> Result<()> {
+        let volume_type = volume_type.into().into_verified(&self.session).await?;
+        api::associate_qos_specs(&self.session, &self.inner.id, volume_type).await
+    }
+
+    /// Delete the QoS specs.
+    ///
+    /// Unless `force` is true, deleting QoS specs that are associated with
+    /// a volume type fails.
+    pub async fn delete(self, force: bool) -> Result<()> {
+        api::delete_qos_specs(&self.session, &self.inner.id, force).await
+    }
+
+    /// Disassociate the QoS specs from a volume type.
+    pub async fn disassociate<T: Into<VolumeTypeRef>>(&self, volume_type: T) -> Result<()> {
+        let volume_type = volume_type.into().into_verified(&self.session).await?;
+        api::disassociate_qos_specs(&self.session, &self.inner.id, volume_type).await
+    }
+}
+
+#[async_trait]
+impl Refresh for QosSpecs {
+    /// Refresh the QoS specs.
+    async fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_qos_specs_by_id(&self.session, &self.inner.id).await?;
+        Ok(())
+    }
+}
+
+impl NewQosSpecs {
+    /// Start creating QoS specs.
+    pub(crate) fn new(session: Session, name: String) -> NewQosSpecs {
+        NewQosSpecs {
+            session,
+            inner: protocol::QosSpecsCreate {
+                name,
+                consumer: None,
+                specs: HashMap::new(),
+            },
+        }
+    }
+
+    /// Request creation of the QoS specs.
+    pub async fn create(self) -> Result<QosSpecs> {
+        let inner = api::create_qos_specs(&self.session, self.inner).await?;
+        Ok(QosSpecs {
+            session: self.session,
+            inner,
+        })
+    }
+
+    creation_inner_field! {
+        #[doc = "Set where the QoS specs are enforced."]
+        set_consumer, with_consumer -> consumer: optional protocol::QosSpecsConsumer
+    }
+
+    /// Key-value pairs of the QoS specs.
+    #[inline]
+    pub fn specs(&mut self) -> &mut HashMap<String, String> {
+        &mut self.inner.specs
+    }
+
+    /// Add a key-value pair to the QoS specs.
+    pub fn with_spec<K, V>(mut self, key: K, value: V) -> NewQosSpecs
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let _ = self.inner.specs.insert(key.into(), value.into());
+        self
+    }
+}
@@ -0,0 +1,443 @@
+// Copyright 2026 The rust-openstack Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Snapshot management via Block Storage API.
+
+use async_trait::async_trait;
+use futures::stream::{Stream, TryStreamExt};
+use std::collections::HashMap;
+use std::time::Duration;
+
+use super::super::common::{Refresh, ResourceIterator, ResourceQuery, SnapshotRef, VolumeRef};
+use super::super::session::Session;
+use super::super::utils::Query;
+use super::super::waiter::{DeletionWaiter, Waiter};
+use super::super::{Error, ErrorKind, Result, Sort};
+use super::{api, protocol, Volume};
+
+/// A query to snapshot list.
+#[derive(Clone, Debug)]
+pub struct SnapshotQuery {
+    session: Session,
+    query: Query,
+    can_paginate: bool,
+    sort: Vec<String>,
+}
+
+/// Structure representing a single snapshot.
+#[derive(Clone, Debug)]
+pub struct Snapshot {
+    session: Session,
+    inner: protocol::Snapshot,
+}
+
+/// A request to create a snapshot.
+#[derive(Clone, Debug)]
+pub struct NewSnapshot {
+    session: Session,
+    inner: protocol::SnapshotCreate,
+    volume: VolumeRef,
+}
+
+/// Waiter for snapshot status to change.
+#[derive(Debug)]
+pub struct SnapshotStatusWaiter {
+    snapshot: Snapshot,
+    target: protocol::SnapshotStatus,
+}
+
+impl Snapshot {
+    /// Create a Snapshot object.
+    pub(crate) async fn new<Id: AsRef<str>>(session: Session, id: Id) -> Result<Snapshot> {
+        let inner = api::get_snapshot(&session, id).await?;
+        Ok(Snapshot { session, inner })
+    }
+
+    transparent_property! {
+        #[doc = "Whether the snapshot consumes quota."]
+        consumes_quota: Option<bool>
+    }
+
+    transparent_property! {
+        #[doc = "When the snapshot was created."]
+        created_at: protocol::DateTime
+    }
+
+    transparent_property! {
+        #[doc = "Description of the snapshot."]
+        description: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "UUID of the group snapshot this snapshot belongs to."]
+        group_snapshot_id: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "UUID of the snapshot."]
+        id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Metadata of the snapshot."]
+        metadata: ref HashMap<String, String>
+    }
+
+    transparent_property! {
+        #[doc = "Name of the snapshot (if set)."]
+        name: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "Progress of the snapshot creation, e.g. `50%`."]
+        progress: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "UUID of the project."]
+        project_id: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "Size of the snapshot in GiB."]
+        size: u64
+    }
+
+    transparent_property! {
+        #[doc = "Status of the snapshot."]
+        status: clone protocol::SnapshotStatus
+    }
+
+    transparent_property! {
+        #[doc = "When the snapshot was last updated."]
+        updated_at: Option<protocol::DateTime>
+    }
+
+    transparent_property! {
+        #[doc = "UUID of the user."]
+        user_id: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "UUID of the source volume."]
+        volume_id: ref String
+    }
+
+    /// Fetch the source volume.
+    pub async fn volume(&self) -> Result<Volume> {
+        Volume::new(self.session.clone(), &self.inner.volume_id).await
+    }
+
+    /// Delete the snapshot.
+    pub async fn delete(self) -> Result<DeletionWaiter<Snapshot>> {
+        api::delete_snapshot(&self.session, &self.inner.id).await?;
+        Ok(DeletionWaiter::new(
+            self,
+            Duration::new(120, 0),
+            Duration::new(1, 0),
+        ))
+    }
+
+    /// Wait for the snapshot to reach the given status.
+    pub fn wait_for_status(self, target: protocol::SnapshotStatus) -> SnapshotStatusWaiter {
+        SnapshotStatusWaiter {
+            snapshot: self,
+            target,
+        }
+    }
+}
+
+#[async_trait]
+impl Refresh for Snapshot {
+    /// Refresh the snapshot.
+    async fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_snapshot_by_id(&self.session, &self.inner.id).await?;
+        Ok(())
+    }
+}
+
+impl SnapshotStatusWaiter {
+    /// Current state of the snapshot.
+    pub fn current_state(&self) -> &Snapshot {
+        &self.snapshot
+    }
+}
+
+#[async_trait]
+impl Waiter<Snapshot, Error> for SnapshotStatusWaiter {
+    fn default_wait_timeout(&self) -> Option<Duration> {
+        Some(Duration::new(1800, 0))
+    }
+
+    fn default_delay(&self) -> Duration {
+        Duration::new(2, 0)
+    }
+
+    fn timeout_error(&self) -> Error {
+        Error::new(
+            ErrorKind::OperationTimedOut,
+            format!(
+                "Timeout waiting for snapshot {} to reach state {}",
+                self.snapshot.id(),
+                self.target
+            ),
+        )
+    }
+
+    async fn poll(&mut self) -> Result<Option<Snapshot>> {
+        self.snapshot.refresh().await?;
+        let status = self.snapshot.status();
+        if status == self.target {
+            debug!("Snapshot {} reached state {}", self.snapshot.id(), status);
+            Ok(Some(self.snapshot.clone()))
+        } else if matches!(
+            status,
+            protocol::SnapshotStatus::Error | protocol::SnapshotStatus::ErrorDeleting
+        ) {
+            debug!(
+                "Failed to move snapshot {} to {} - status is {}",
+                self.snapshot.id(),
+                self.target,
+                status
+            );
+            Err(Error::new(
+                ErrorKind::OperationFailed,
+                format!("Snapshot {} got into {} state", self.snapshot.id(), status),
+            ))
+        } else {
+            trace!(
+                "Still waiting for snapshot {} to get to state {}, current is {}",
+                self.snapshot.id(),
+                self.target,
+                status
+            );
+            Ok(None)
+        }
+    }
+}
+
+impl SnapshotQuery {
+    pub(crate) fn new(session: Session) -> SnapshotQuery {
+        SnapshotQuery {
+            session,
+            query: Query::new(),
+            can_paginate: true,
+            sort: Vec::new(),
+        }
+    }
+
+    /// Add sorting to the request.
+    pub fn sort_by(mut self, sort: Sort<protocol::SnapshotSortKey>) -> Self {
+        let (field, direction) = sort.into();
+        self.sort.push(format!("{field}:{direction}"));
+        self
+    }
+
+    /// Add marker to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_marker<T: Into<String>>(mut self, marker: T) -> Self {
+        self.can_paginate = false;
+        self.query.push_str("marker", marker);
+        self
+    }
+
+    /// Add limit to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.can_paginate = false;
+        self.query.push("limit", limit);
+        self
+    }
+
+    query_filter! {
+        #[doc = "Filter by snapshot name."]
+        with_name -> name
+    }
+
+    query_filter! {
+        #[doc = "Filter by snapshot status."]
+        with_status -> status: protocol::SnapshotStatus
+    }
+
+    query_filter! {
+        #[doc = "Filter by source volume ID."]
+        with_volume_id -> volume_id
+    }
+
+    /// Convert this query into a stream executing the request.
+    ///
+    /// Returns a `TryStream`, which is a stream with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_stream(
+        mut self,
+    ) -> impl Stream<Item = Result<<SnapshotQuery as ResourceQuery>::Item>> {
+        if !self.sort.is_empty() {
+            self.query.push_str("sort", self.sort.join(","));
+        }
+        debug!("Fetching snapshots with {:?}", self.query);
+        ResourceIterator::new(self).into_stream()
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_stream().try_collect().await`.
+    pub async fn all(self) -> Result<Vec<Snapshot>> {
+        self.into_stream().try_collect().await
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub async fn one(mut self) -> Result<Snapshot> {
+        debug!("Fetching one snapshot with {:?}", self.query);
+        if self.can_paginate {
+            // We need only one result. We fetch maximum two to be able
+            // to check if the query yields more than one result.
+            self.query.push("limit", 2);
+        }
+
+        ResourceIterator::new(self).one().await
+    }
+}
+
+#[async_trait]
+impl ResourceQuery for SnapshotQuery {
+    type Item = Snapshot;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    async fn can_paginate(&self) -> Result<bool> {
+        Ok(self.can_paginate)
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.id().clone()
+    }
+
+    async fn fetch_chunk(
+        &self,
+        limit: Option<usize>,
+        marker: Option<String>,
+    ) -> Result<Vec<Self::Item>> {
+        let query = self.query.with_marker_and_limit(limit, marker);
+        Ok(api::list_snapshots(&self.session, &query)
+            .await?
+            .into_iter()
+            .map(|item| Snapshot {
+                session: self.session.clone(),
+                inner: item,
+            })
+            .collect())
+    }
+}
+
+impl NewSnapshot {
+    /// Start creating a snapshot.
+    pub(crate) fn new(session: Session, volume: VolumeRef) -> NewSnapshot {
+        NewSnapshot {
+            session,
+            inner: protocol::SnapshotCreate::new(String::new()),
+            volume,
+        }
+    }
+
+    /// Request creation of the snapshot.
+    ///
+    /// Returns a waiter that can be used to wait for the snapshot to become
+    /// available.
+    pub async fn create(mut self) -> Result<SnapshotStatusWaiter> {
+        self.inner.volume_id = self.volume.into_verified(&self.session).await?.into();
+        let inner = api::create_snapshot(&self.session, self.inner).await?;
+        Ok(Snapshot {
+            session: self.session,
+            inner,
+        }
+        .wait_for_status(protocol::SnapshotStatus::Available))
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the description."]
+        set_description, with_description -> description: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Allow snapshotting a volume that is attached to a server."]
+        set_force, with_force -> force: optional bool
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the metadata."]
+        set_metadata, with_metadata -> metadata: optional HashMap<String, String>
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the name."]
+        set_name, with_name -> name: optional String
+    }
+}
+
+impl From<Snapshot> for SnapshotRef {
+    fn from(value: Snapshot) -> SnapshotRef {
+        SnapshotRef::new_verified(value.inner.id)
+    }
+}
+
+#[cfg(feature = "block-storage")]
+impl SnapshotRef {
+    /// Verify this reference and convert to an ID, if possible.
+    #[allow(unused)]
+    pub(crate) async fn into_verified(self, session: &Session) -> Result<SnapshotRef> {
+        Ok(if self.verified {
+            self
+        } else {
+            SnapshotRef::new_verified(api::get_snapshot(session, &self.value).await?.id)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::protocol;
+
+    #[test]
+    fn test_snapshot_json() {
+        let snapshot: protocol::SnapshotRoot = serde_json::from_value(serde_json::json!({
+            "snapshot": {
+                "created_at": "2026-03-11T10:05:29.000000",
+                "description": null,
+                "id": "2bb856e1-b3d8-4432-a858-09e4ce939389",
+                "metadata": {"backup": "nightly"},
+                "name": null,
+                "os-extended-snapshot-attributes:progress": "100%",
+                "os-extended-snapshot-attributes:project_id": "0c2eba2c5af04d3f9e9d0d410b371fde",
+                "size": 10,
+                "status": "available",
+                "updated_at": null,
+                "volume_id": "5aa119a8-d25b-45a7-8d1b-88e127885635"
+            }
+        }))
+        .unwrap();
+        let snapshot = snapshot.snapshot;
+        assert_eq!(snapshot.status, protocol::SnapshotStatus::Available);
+        assert_eq!(snapshot.name, None);
+        assert_eq!(snapshot.progress.as_deref(), Some("100%"));
+        assert_eq!(snapshot.metadata.get("backup").unwrap(), "nightly");
+        assert!(snapshot.updated_at.is_none());
+    }
+}
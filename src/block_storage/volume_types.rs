@@ -0,0 +1,326 @@
+// Copyright 2026 The rust-openstack Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Volume type management via Block Storage API.
+
+use async_trait::async_trait;
+use futures::stream::{Stream, TryStreamExt};
+use std::collections::HashMap;
+
+use super::super::common::{ProjectRef, Refresh, ResourceIterator, ResourceQuery, VolumeTypeRef};
+use super::super::session::Session;
+use super::super::utils::Query;
+use super::super::Result;
+use super::{api, protocol, QosSpecs};
+
+/// A query to volume type list.
+#[derive(Clone, Debug)]
+pub struct VolumeTypeQuery {
+    session: Session,
+    query: Query,
+    can_paginate: bool,
+}
+
+/// Structure representing a single volume type.
+#[derive(Clone, Debug)]
+pub struct VolumeType {
+    session: Session,
+    inner: protocol::VolumeType,
+}
+
+/// A request to create a volume type.
+#[derive(Clone, Debug)]
+pub struct NewVolumeType {
+    session: Session,
+    inner: protocol::VolumeTypeCreate,
+}
+
+impl VolumeType {
+    /// Create a VolumeType object.
+    pub(crate) async fn new<Id: AsRef<str>>(session: Session, id: Id) -> Result<VolumeType> {
+        let inner = api::get_volume_type(&session, id).await?;
+        Ok(VolumeType { session, inner })
+    }
+
+    transparent_property! {
+        #[doc = "Description of the volume type."]
+        description: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "Extra specs of the volume type."]
+        extra_specs: ref HashMap<String, String>
+    }
+
+    transparent_property! {
+        #[doc = "UUID of the volume type."]
+        id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Whether the volume type is available to all projects."]
+        is_public: bool
+    }
+
+    transparent_property! {
+        #[doc = "Name of the volume type."]
+        name: ref String
+    }
+
+    transparent_property! {
+        #[doc = "UUID of the associated QoS specs (if any)."]
+        qos_specs_id: ref Option<String>
+    }
+
+    /// Fetch the associated QoS specs (if any).
+    pub async fn qos_specs(&self) -> Result<Option<QosSpecs>> {
+        Ok(match self.inner.qos_specs_id {
+            Some(ref id) => Some(QosSpecs::new(self.session.clone(), id).await?),
+            None => None,
+        })
+    }
+
+    /// Grant a project access to this private volume type (admin-only).
+    pub async fn add_project_access<P: Into<ProjectRef>>(&self, project: P) -> Result<()> {
+        let project = project.into().into_verified(&self.session).await?;
+        api::volume_type_action(
+            &self.session,
+            &self.inner.id,
+            protocol::VolumeTypeAction::AddProjectAccess {
+                project: project.into(),
+            },
+        )
+        .await
+    }
+
+    /// Delete the volume type (admin-only).
+    pub async fn delete(self) -> Result<()> {
+        api::delete_volume_type(&self.session, &self.inner.id).await
+    }
+
+    /// Delete an extra spec (admin-only).
+    pub async fn delete_extra_spec<K: AsRef<str>>(&mut self, key: K) -> Result<()> {
+        api::delete_volume_type_extra_spec(&self.session, &self.inner.id, key.as_ref()).await?;
+        let _ = self.inner.extra_specs.remove(key.as_ref());
+        Ok(())
+    }
+
+    /// List IDs of projects that have access to this private volume type.
+    pub async fn list_project_access(&self) -> Result<Vec<String>> {
+        Ok(api::list_volume_type_access(&self.session, &self.inner.id)
+            .await?
+            .into_iter()
+            .map(|item| item.project_id)
+            .collect())
+    }
+
+    /// Revoke access to this private volume type from a project (admin-only).
+    pub async fn remove_project_access<P: Into<ProjectRef>>(&self, project: P) -> Result<()> {
+        let project = project.into().into_verified(&self.session).await?;
+        api::volume_type_action(
+            &self.session,
+            &self.inner.id,
+            protocol::VolumeTypeAction::RemoveProjectAccess {
+                project: project.into(),
+            },
+        )
+        .await
+    }
+
+    /// Create or update extra specs (admin-only).
+    ///
+    /// Extra specs that are not provided are kept intact.
+    pub async fn set_extra_specs(&mut self, extra_specs: HashMap<String, String>) -> Result<()> {
+        let updated =
+            api::set_volume_type_extra_specs(&self.session, &self.inner.id, extra_specs).await?;
+        self.inner.extra_specs.extend(updated);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Refresh for VolumeType {
+    /// Refresh the volume type.
+    async fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_volume_type_by_id(&self.session, &self.inner.id).await?;
+        Ok(())
+    }
+}
+
+impl VolumeTypeQuery {
+    pub(crate) fn new(session: Session) -> VolumeTypeQuery {
+        VolumeTypeQuery {
+            session,
+            query: Query::new(),
+            can_paginate: true,
+        }
+    }
+
+    /// Add marker to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_marker<T: Into<String>>(mut self, marker: T) -> Self {
+        self.can_paginate = false;
+        self.query.push_str("marker", marker);
+        self
+    }
+
+    /// Add limit to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.can_paginate = false;
+        self.query.push("limit", limit);
+        self
+    }
+
+    query_filter! {
+        #[doc = "Filter by public or private types (only admins can see private types)."]
+        with_public -> is_public: bool
+    }
+
+    /// Convert this query into a stream executing the request.
+    ///
+    /// Returns a `TryStream`, which is a stream with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_stream(
+        self,
+    ) -> impl Stream<Item = Result<<VolumeTypeQuery as ResourceQuery>::Item>> {
+        debug!("Fetching volume types with {:?}", self.query);
+        ResourceIterator::new(self).into_stream()
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_stream().try_collect().await`.
+    pub async fn all(self) -> Result<Vec<VolumeType>> {
+        self.into_stream().try_collect().await
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub async fn one(mut self) -> Result<VolumeType> {
+        debug!("Fetching one volume type with {:?}", self.query);
+        if self.can_paginate {
+            // We need only one result. We fetch maximum two to be able
+            // to check if the query yields more than one result.
+            self.query.push("limit", 2);
+        }
+
+        ResourceIterator::new(self).one().await
+    }
+}
+
+#[async_trait]
+impl ResourceQuery for VolumeTypeQuery {
+    type Item = VolumeType;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    async fn can_paginate(&self) -> Result<bool> {
+        Ok(self.can_paginate)
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.id().clone()
+    }
+
+    async fn fetch_chunk(
+        &self,
+        limit: Option<usize>,
+        marker: Option<String>,
+    ) -> Result<Vec<Self::Item>> {
+        let query = self.query.with_marker_and_limit(limit, marker);
+        Ok(api::list_volume_types(&self.session, &query)
+            .await?
+            .into_iter()
+            .map(|item| VolumeType {
+                session: self.session.clone(),
+                inner: item,
+            })
+            .collect())
+    }
+}
+
+impl NewVolumeType {
+    /// Start creating a volume type.
+    pub(crate) fn new(session: Session, name: String) -> NewVolumeType {
+        NewVolumeType {
+            session,
+            inner: protocol::VolumeTypeCreate {
+                name,
+                description: None,
+                extra_specs: HashMap::new(),
+                is_public: None,
+            },
+        }
+    }
+
+    /// Request creation of the volume type (admin-only).
+    pub async fn create(self) -> Result<VolumeType> {
+        let inner = api::create_volume_type(&self.session, self.inner).await?;
+        Ok(VolumeType {
+            session: self.session,
+            inner,
+        })
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the description."]
+        set_description, with_description -> description: optional String
+    }
+
+    /// Extra specs to set on the volume type.
+    #[inline]
+    pub fn extra_specs(&mut self) -> &mut HashMap<String, String> {
+        &mut self.inner.extra_specs
+    }
+
+    creation_inner_field! {
+        #[doc = "Set whether the volume type is available to all projects."]
+        set_public, with_public -> is_public: optional bool
+    }
+
+    /// Add an extra spec to the volume type.
+    pub fn with_extra_spec<K, V>(mut self, key: K, value: V) -> NewVolumeType
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let _ = self.inner.extra_specs.insert(key.into(), value.into());
+        self
+    }
+}
+
+impl From<VolumeType> for VolumeTypeRef {
+    fn from(value: VolumeType) -> VolumeTypeRef {
+        VolumeTypeRef::new_verified(value.inner.id)
+    }
+}
+
+#[cfg(feature = "block-storage")]
+impl VolumeTypeRef {
+    /// Verify this reference and convert to an ID, if possible.
+    pub(crate) async fn into_verified(self, session: &Session) -> Result<VolumeTypeRef> {
+        Ok(if self.verified {
+            self
+        } else {
+            VolumeTypeRef::new_verified(api::get_volume_type(session, &self.value).await?.id)
+        })
+    }
+}
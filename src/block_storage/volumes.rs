@@ -16,15 +16,15 @@
 
 use async_trait::async_trait;
 use futures::stream::{Stream, TryStreamExt};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt::{self, Display, Formatter};
 use std::time::Duration;
 
-use super::super::common::{Refresh, ResourceIterator, ResourceQuery};
+use super::super::common::{Refresh, ResourceIterator, ResourceQuery, VolumeRef, VolumeTypeRef};
 use super::super::session::Session;
 use super::super::utils::Query;
-use super::super::waiter::DeletionWaiter;
-use super::super::{Result, Sort};
+use super::super::waiter::{DeletionWaiter, Waiter};
+use super::super::{Error, ErrorKind, Result, Sort};
 use super::{api, protocol};
 
 /// A query to volume list.
@@ -41,6 +41,7 @@ pub struct VolumeQuery {
 pub struct Volume {
     session: Session,
     inner: protocol::Volume,
+    dirty: HashSet<&'static str>,
 }
 
 /// A request to create a volume.
@@ -48,6 +49,14 @@ pub struct Volume {
 pub struct NewVolume {
     session: Session,
     inner: protocol::VolumeCreate,
+    volume_type: Option<VolumeTypeRef>,
+}
+
+/// Waiter for volume status to change.
+#[derive(Debug)]
+pub struct VolumeStatusWaiter {
+    volume: Volume,
+    target: protocol::VolumeStatus,
 }
 
 impl Display for Volume {
@@ -60,7 +69,15 @@ impl Volume {
     /// Create an Volume object.
     pub(crate) async fn new<Id: AsRef<str>>(session: Session, id: Id) -> Result<Volume> {
         let inner = api::get_volume(&session, id).await?;
-        Ok(Volume { session, inner })
+        Ok(Volume::from_inner(session, inner))
+    }
+
+    fn from_inner(session: Session, inner: protocol::Volume) -> Volume {
+        Volume {
+            session,
+            inner,
+            dirty: HashSet::new(),
+        }
     }
 
     transparent_property! {
@@ -133,9 +150,14 @@ impl Volume {
         metadata: ref HashMap<String, String>
     }
 
+    update_field_mut! {
+        #[doc = "Update the metadata."]
+        metadata_mut, set_metadata, with_metadata -> metadata: HashMap<String, String>
+    }
+
     transparent_property! {
         #[doc = "Status of the volume."]
-        status: protocol::VolumeStatus
+        status: clone protocol::VolumeStatus
     }
 
     transparent_property! {
@@ -148,6 +170,11 @@ impl Volume {
         description: ref Option<String>
     }
 
+    update_field! {
+        #[doc = "Update the description."]
+        set_description, with_description -> description: optional String
+    }
+
     transparent_property! {
         #[doc = "Whether the volume is multi-attachable."]
         multi_attachable: bool
@@ -173,6 +200,11 @@ impl Volume {
         name: ref String
     }
 
+    update_field! {
+        #[doc = "Update the name."]
+        set_name, with_name -> name
+    }
+
     transparent_property! {
         #[doc = "Whether the volume is bootable."]
         bootable: bool
@@ -242,6 +274,93 @@ impl Volume {
             Duration::new(1, 0),
         ))
     }
+
+    /// Extend the volume to the new size in GiB.
+    ///
+    /// Fails with `IncompatibleApiVersion` for an attached volume if the cloud does not
+    /// support block storage API version 3.42.
+    pub async fn extend(self, new_size: u64) -> Result<VolumeStatusWaiter> {
+        let attached = self.inner.status == protocol::VolumeStatus::InUse;
+        api::extend_volume(&self.session, &self.inner.id, new_size, attached).await?;
+        let target = if attached {
+            protocol::VolumeStatus::InUse
+        } else {
+            protocol::VolumeStatus::Available
+        };
+        Ok(VolumeStatusWaiter::new(self, target))
+    }
+
+    /// Whether the volume is modified.
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Reset the status of the volume (admin-only).
+    ///
+    /// No checks are made that the status matches the real state of the volume.
+    pub async fn reset_status(self, status: protocol::VolumeStatus) -> Result<VolumeStatusWaiter> {
+        let action = protocol::VolumeAction::ResetStatus {
+            status: status.clone(),
+        };
+        api::volume_action(&self.session, &self.inner.id, action).await?;
+        Ok(VolumeStatusWaiter::new(self, status))
+    }
+
+    /// Change the type of the volume.
+    ///
+    /// With `RetypeMigrationPolicy::OnDemand` the volume may be migrated to
+    /// a different backend if the current one does not support the new type.
+    pub async fn retype<T: Into<VolumeTypeRef>>(
+        self,
+        volume_type: T,
+        migration_policy: protocol::RetypeMigrationPolicy,
+    ) -> Result<VolumeStatusWaiter> {
+        let volume_type = volume_type.into().into_verified(&self.session).await?;
+        self.action(protocol::VolumeAction::Retype {
+            new_type: volume_type.into(),
+            migration_policy,
+        })
+        .await
+    }
+
+    /// Save the changes to the volume.
+    #[allow(clippy::field_reassign_with_default)]
+    pub async fn save(&mut self) -> Result<()> {
+        let mut update = protocol::VolumeUpdate::default();
+        save_fields! {
+            self -> update: metadata name
+        };
+        save_option_fields! {
+            self -> update: description
+        };
+        let inner = api::update_volume(&self.session, self.id(), update).await?;
+        self.dirty.clear();
+        self.inner = inner;
+        Ok(())
+    }
+
+    /// Update the bootable flag of the volume.
+    pub async fn set_bootable(self, bootable: bool) -> Result<VolumeStatusWaiter> {
+        self.action(protocol::VolumeAction::SetBootable { bootable })
+            .await
+    }
+
+    /// Update the read-only access mode flag of the volume.
+    pub async fn set_readonly(self, readonly: bool) -> Result<VolumeStatusWaiter> {
+        self.action(protocol::VolumeAction::SetReadonly { readonly })
+            .await
+    }
+
+    /// Run an action and wait for the volume to return to its current status.
+    async fn action(self, action: protocol::VolumeAction) -> Result<VolumeStatusWaiter> {
+        let target = if self.inner.status == protocol::VolumeStatus::InUse {
+            protocol::VolumeStatus::InUse
+        } else {
+            protocol::VolumeStatus::Available
+        };
+        api::volume_action(&self.session, &self.inner.id, action).await?;
+        Ok(VolumeStatusWaiter::new(self, target))
+    }
 }
 
 #[async_trait]
@@ -249,10 +368,80 @@ impl Refresh for Volume {
     /// Refresh the volume.
     async fn refresh(&mut self) -> Result<()> {
         self.inner = api::get_volume_by_id(&self.session, &self.inner.id).await?;
+        self.dirty.clear();
         Ok(())
     }
 }
 
+impl VolumeStatusWaiter {
+    /// Wait for the volume to reach the given status.
+    pub(crate) fn new(volume: Volume, target: protocol::VolumeStatus) -> VolumeStatusWaiter {
+        VolumeStatusWaiter { volume, target }
+    }
+
+    /// Current state of the volume.
+    pub fn current_state(&self) -> &Volume {
+        &self.volume
+    }
+}
+
+#[async_trait]
+impl Waiter<Volume, Error> for VolumeStatusWaiter {
+    fn default_wait_timeout(&self) -> Option<Duration> {
+        Some(Duration::new(600, 0))
+    }
+
+    fn default_delay(&self) -> Duration {
+        Duration::new(1, 0)
+    }
+
+    fn timeout_error(&self) -> Error {
+        Error::new(
+            ErrorKind::OperationTimedOut,
+            format!(
+                "Timeout waiting for volume {} to reach state {}",
+                self.volume.id(),
+                self.target
+            ),
+        )
+    }
+
+    async fn poll(&mut self) -> Result<Option<Volume>> {
+        self.volume.refresh().await?;
+        let status = self.volume.status();
+        if status == self.target {
+            debug!("Volume {} reached state {}", self.volume.id(), status);
+            Ok(Some(self.volume.clone()))
+        } else if matches!(
+            status,
+            protocol::VolumeStatus::Error
+                | protocol::VolumeStatus::ErrorBackingUp
+                | protocol::VolumeStatus::ErrorDeleting
+                | protocol::VolumeStatus::ErrorExtending
+                | protocol::VolumeStatus::ErrorRestoring
+        ) {
+            debug!(
+                "Failed to move volume {} to {} - status is {}",
+                self.volume.id(),
+                self.target,
+                status
+            );
+            Err(Error::new(
+                ErrorKind::OperationFailed,
+                format!("Volume {} got into {} state", self.volume.id(), status),
+            ))
+        } else {
+            trace!(
+                "Still waiting for volume {} to get to state {}, current is {}",
+                self.volume.id(),
+                self.target,
+                status
+            );
+            Ok(None)
+        }
+    }
+}
+
 impl VolumeQuery {
     pub(crate) fn new(session: Session) -> VolumeQuery {
         VolumeQuery {
@@ -360,10 +549,7 @@ impl ResourceQuery for VolumeQuery {
         Ok(api::list_volumes(&self.session, &query)
             .await?
             .into_iter()
-            .map(|item| Volume {
-                session: self.session.clone(),
-                inner: item,
-            })
+            .map(|item| Volume::from_inner(self.session.clone(), item))
             .collect())
     }
 }
@@ -374,16 +560,17 @@ impl NewVolume {
         NewVolume {
             session,
             inner: protocol::VolumeCreate::new(size),
+            volume_type: None,
         }
     }
 
     /// Request creation of the volume.
-    pub async fn create(self) -> Result<Volume> {
+    pub async fn create(mut self) -> Result<Volume> {
+        if let Some(volume_type) = self.volume_type {
+            self.inner.volume_type = Some(volume_type.into_verified(&self.session).await?.into());
+        }
         let inner = api::create_volume(&self.session, self.inner).await?;
-        Ok(Volume {
-            session: self.session,
-            inner,
-        })
+        Ok(Volume::from_inner(self.session, inner))
     }
 
     creation_inner_field! {
@@ -421,9 +608,22 @@ impl NewVolume {
         set_image_id, with_image_id -> image_id: optional String
     }
 
-    creation_inner_field! {
-        #[doc = "Set the volume type."]
-        set_volume_type, with_volume_type -> volume_type: optional String
+    /// Set the volume type.
+    pub fn set_volume_type<T>(&mut self, volume_type: T)
+    where
+        T: Into<VolumeTypeRef>,
+    {
+        self.volume_type = Some(volume_type.into());
+    }
+
+    /// Set the volume type.
+    #[inline]
+    pub fn with_volume_type<T>(mut self, volume_type: T) -> NewVolume
+    where
+        T: Into<VolumeTypeRef>,
+    {
+        self.set_volume_type(volume_type);
+        self
     }
 
     creation_inner_field! {
@@ -436,3 +636,22 @@ impl NewVolume {
         set_consistency_group_id, with_consistency_group_id -> consistency_group_id: optional String
     }
 }
+
+impl From<Volume> for VolumeRef {
+    fn from(value: Volume) -> VolumeRef {
+        VolumeRef::new_verified(value.inner.id)
+    }
+}
+
+#[cfg(feature = "block-storage")]
+impl VolumeRef {
+    /// Verify this reference and convert to an ID, if possible.
+    #[allow(unused)]
+    pub(crate) async fn into_verified(self, session: &Session) -> Result<VolumeRef> {
+        Ok(if self.verified {
+            self
+        } else {
+            VolumeRef::new_verified(api::get_volume(session, &self.value).await?.id)
+        })
+    }
+}
@@ -14,27 +14,48 @@
 
 //! Cloud API.
 
+#[cfg(feature = "compute")]
+use chrono::{DateTime, FixedOffset};
 #[allow(unused_imports)]
 use futures::io::AsyncRead;
+#[cfg(feature = "compute")]
+use osauth::services::COMPUTE;
 #[allow(unused_imports)]
 use std::io;
 
 use super::auth::AuthType;
 #[cfg(feature = "block-storage")]
-use super::block_storage::{NewVolume, Volume, VolumeQuery};
+use super::block_storage::{
+    NewQosSpecs, NewSnapshot, NewVolume, NewVolumeType, QosSpecs, Snapshot, SnapshotQuery, Volume,
+    VolumeQuery, VolumeType, VolumeTypeQuery,
+};
+#[cfg(feature = "compute")]
+use super::common::ApiVersion;
 #[allow(unused_imports)]
-use super::common::{ContainerRef, FlavorRef, NetworkRef};
+use super::common::{
+    ContainerRef, FlavorRef, NetworkRef, ProjectRef, RoleRef, SecurityGroupRef, UserRef, VolumeRef,
+};
 #[cfg(feature = "compute")]
 use super::compute::{
-    Flavor, FlavorQuery, FlavorSummary, KeyPair, KeyPairQuery, NewKeyPair, NewServer, Server,
-    ServerQuery, ServerSummary,
+    AvailabilityZone, AvailabilityZoneQuery, ComputeLimits, ComputeService, ComputeServiceQuery,
+    Flavor, FlavorQuery, FlavorSummary, Hypervisor, HypervisorQuery, HypervisorStatistics, KeyPair,
+    KeyPairQuery, NewFlavor, NewKeyPair, NewServer, NewServerGroup, QuotaSet, Server, ServerGroup,
+    ServerGroupPolicy, ServerGroupQuery, ServerQuery, ServerSummary, TenantUsage,
+};
+#[cfg(feature = "identity")]
+use super::identity::{
+    ApplicationCredential, ApplicationCredentialQuery, Domain, DomainQuery, Group, GroupQuery,
+    NewApplicationCredential, NewDomain, NewGroup, NewProject, NewRole, NewRoleAssignment, NewUser,
+    Project, ProjectQuery, Role, RoleAssignmentQuery, RoleQuery, User, UserQuery,
 };
 #[cfg(feature = "image")]
-use super::image::{Image, ImageQuery};
+use super::image::{Image, ImageQuery, NewImage};
 #[cfg(feature = "network")]
 use super::network::{
     FloatingIp, FloatingIpQuery, Network, NetworkQuery, NewFloatingIp, NewNetwork, NewPort,
-    NewRouter, NewSubnet, Port, PortQuery, Router, RouterQuery, Subnet, SubnetQuery,
+    NewRouter, NewSecurityGroup, NewSecurityGroupRule, NewSubnet, Port, PortQuery, Router,
+    RouterQuery, SecurityGroup, SecurityGroupQuery, SecurityGroupRuleDirection, Subnet,
+    SubnetQuery,
 };
 #[cfg(feature = "object-storage")]
 use super::object_storage::{Container, ContainerQuery, NewObject, Object, ObjectQuery};
@@ -174,6 +195,24 @@ impl Cloud {
         self.session.refresh().await
     }
 
+    /// Absolute compute limits and current usage of the current project.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// let limits = os.compute_limits().await.expect("Unable to get limits");
+    /// println!("Instances left: {:?}", limits.instances.remaining());
+    /// # }
+    /// ```
+    #[cfg(feature = "compute")]
+    pub async fn compute_limits(&self) -> Result<ComputeLimits> {
+        ComputeLimits::load(&self.session).await
+    }
+
     /// Create a new container.
     ///
     /// If the container already exists, this call returns successfully.
@@ -215,6 +254,43 @@ impl Cloud {
         ObjectQuery::new(self.session.clone(), container)
     }
 
+    /// Build a query against application credential list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query. Application credentials of the current user are listed
+    /// unless a user is provided.
+    #[cfg(feature = "identity")]
+    pub fn find_application_credentials(&self) -> ApplicationCredentialQuery {
+        ApplicationCredentialQuery::new(self.session.clone())
+    }
+
+    /// Build a query against availability zone list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query.
+    #[cfg(feature = "compute")]
+    pub fn find_availability_zones(&self) -> AvailabilityZoneQuery {
+        AvailabilityZoneQuery::new(self.session.clone())
+    }
+
+    /// Build a query against compute service list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query. Only available to administrators.
+    #[cfg(feature = "compute")]
+    pub fn find_compute_services(&self) -> ComputeServiceQuery {
+        ComputeServiceQuery::new(self.session.clone())
+    }
+
+    /// Build a query against domain list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query.
+    #[cfg(feature = "identity")]
+    pub fn find_domains(&self) -> DomainQuery {
+        DomainQuery::new(self.session.clone())
+    }
+
     /// Build a query against flavor list.
     ///
     /// The returned object is a builder that should be used to construct
@@ -233,6 +309,24 @@ impl Cloud {
         FloatingIpQuery::new(self.session.clone())
     }
 
+    /// Build a query against group list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query.
+    #[cfg(feature = "identity")]
+    pub fn find_groups(&self) -> GroupQuery {
+        GroupQuery::new(self.session.clone())
+    }
+
+    /// Build a query against hypervisor list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query. Only available to administrators.
+    #[cfg(feature = "compute")]
+    pub fn find_hypervisors(&self) -> HypervisorQuery {
+        HypervisorQuery::new(self.session.clone())
+    }
+
     /// Build a query against image list.
     ///
     /// The returned object is a builder that should be used to construct
@@ -269,6 +363,50 @@ impl Cloud {
         PortQuery::new(self.session.clone())
     }
 
+    /// Build a query against project list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query.
+    #[cfg(feature = "identity")]
+    pub fn find_projects(&self) -> ProjectQuery {
+        ProjectQuery::new(self.session.clone())
+    }
+
+    /// Build a query against role assignment list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// let assignments = os
+    ///     .find_role_assignments()
+    ///     .with_user_id("8f4b3c1ee16a4e0f8c07c4d7bf0f4a6b")
+    ///     .with_names()
+    ///     .all()
+    ///     .await
+    ///     .expect("Unable to fetch role assignments");
+    /// # }
+    /// ```
+    #[cfg(feature = "identity")]
+    pub fn find_role_assignments(&self) -> RoleAssignmentQuery {
+        RoleAssignmentQuery::new(self.session.clone())
+    }
+
+    /// Build a query against role list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query.
+    #[cfg(feature = "identity")]
+    pub fn find_roles(&self) -> RoleQuery {
+        RoleQuery::new(self.session.clone())
+    }
+
     /// Build a query against router list.
     ///
     /// The returned object is a builder that should be used to construct
@@ -278,6 +416,15 @@ impl Cloud {
         RouterQuery::new(self.session.clone())
     }
 
+    /// Build a query against security group list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query.
+    #[cfg(feature = "network")]
+    pub fn find_security_groups(&self) -> SecurityGroupQuery {
+        SecurityGroupQuery::new(self.session.clone())
+    }
+
     /// Build a query against server list.
     ///
     /// The returned object is a builder that should be used to construct
@@ -303,6 +450,24 @@ impl Cloud {
         ServerQuery::new(self.session.clone())
     }
 
+    /// Build a query against server group list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query.
+    #[cfg(feature = "compute")]
+    pub fn find_server_groups(&self) -> ServerGroupQuery {
+        ServerGroupQuery::new(self.session.clone())
+    }
+
+    /// Build a query against snapshot list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query.
+    #[cfg(feature = "block-storage")]
+    pub fn find_snapshots(&self) -> SnapshotQuery {
+        SnapshotQuery::new(self.session.clone())
+    }
+
     /// Build a query against subnet list.
     ///
     /// The returned object is a builder that should be used to construct
@@ -312,6 +477,15 @@ impl Cloud {
         SubnetQuery::new(self.session.clone())
     }
 
+    /// Build a query against user list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query.
+    #[cfg(feature = "identity")]
+    pub fn find_users(&self) -> UserQuery {
+        UserQuery::new(self.session.clone())
+    }
+
     /// Build a query against volume list.
     ///
     /// The returned object is a builder that should be used to construct
@@ -321,6 +495,15 @@ impl Cloud {
         VolumeQuery::new(self.session.clone())
     }
 
+    /// Build a query against volume type list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query.
+    #[cfg(feature = "block-storage")]
+    pub fn find_volume_types(&self) -> VolumeTypeQuery {
+        VolumeTypeQuery::new(self.session.clone())
+    }
+
     /// Get object container metadata by its name.
     ///
     /// # Example
@@ -359,6 +542,85 @@ impl Cloud {
         Object::load(self.session.clone(), container, name).await
     }
 
+    /// Find an application credential of the current user by its name or ID.
+    #[cfg(feature = "identity")]
+    pub async fn get_application_credential<Id: AsRef<str>>(
+        &self,
+        id_or_name: Id,
+    ) -> Result<ApplicationCredential> {
+        ApplicationCredential::new(self.session.clone(), id_or_name).await
+    }
+
+    /// Range of compute API microversions supported by the cloud.
+    ///
+    /// Returns `None` if the compute service does not support microversions. The versions are
+    /// discovered on the first call and cached for the lifetime of the cloud and its clones.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// if let Some((min, max)) = os
+    ///     .get_compute_api_versions()
+    ///     .await
+    ///     .expect("Unable to discover compute versions")
+    /// {
+    ///     println!("Compute API versions from {} to {}", min, max);
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "compute")]
+    pub async fn get_compute_api_versions(&self) -> Result<Option<(ApiVersion, ApiVersion)>> {
+        self.session.get_api_versions(COMPUTE).await
+    }
+
+    /// Get compute quotas of a project.
+    ///
+    /// Only available to administrators.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// let mut quotas = os
+    ///     .get_compute_quotas("8a1d3e4c")
+    ///     .await
+    ///     .expect("Unable to get quotas");
+    /// quotas.set_instances(20);
+    /// quotas.save().await.expect("Unable to update quotas");
+    /// # }
+    /// ```
+    #[cfg(feature = "compute")]
+    pub async fn get_compute_quotas<P: Into<ProjectRef>>(&self, project: P) -> Result<QuotaSet> {
+        QuotaSet::load(self.session.clone(), project).await
+    }
+
+    /// Get compute usage of a project over a period of time.
+    ///
+    /// Only available to administrators or to members of the project.
+    /// A project without usage in the period gets a report with zero totals.
+    #[cfg(feature = "compute")]
+    pub async fn get_compute_usage<P: Into<ProjectRef>>(
+        &self,
+        project: P,
+        start: DateTime<FixedOffset>,
+        end: DateTime<FixedOffset>,
+    ) -> Result<TenantUsage> {
+        TenantUsage::load(&self.session, project, start, end).await
+    }
+
+    /// Find a domain by its name or ID.
+    #[cfg(feature = "identity")]
+    pub async fn get_domain<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<Domain> {
+        Domain::new(self.session.clone(), id_or_name).await
+    }
+
     /// Find a flavor by its name or ID.
     ///
     /// # Example
@@ -395,6 +657,43 @@ impl Cloud {
         FloatingIp::load(self.session.clone(), id).await
     }
 
+    /// Find a group by its name or ID.
+    #[cfg(feature = "identity")]
+    pub async fn get_group<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<Group> {
+        Group::new(self.session.clone(), id_or_name).await
+    }
+
+    /// Find a hypervisor by its ID.
+    ///
+    /// Only available to administrators.
+    #[cfg(feature = "compute")]
+    pub async fn get_hypervisor<Id: AsRef<str>>(&self, id: Id) -> Result<Hypervisor> {
+        Hypervisor::new(self.session.clone(), id).await
+    }
+
+    /// Get summary statistics over all hypervisors.
+    ///
+    /// Only available to administrators. Not supported by clouds that only
+    /// accept compute API version 2.88 or newer.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// let stats = os.get_hypervisor_statistics()
+    ///     .await
+    ///     .expect("Unable to get hypervisor statistics");
+    /// println!("{} of {} vCPUs used", stats.vcpus_used, stats.vcpus);
+    /// # }
+    /// ```
+    #[cfg(feature = "compute")]
+    pub async fn get_hypervisor_statistics(&self) -> Result<HypervisorStatistics> {
+        HypervisorStatistics::load(&self.session).await
+    }
+
     /// Find an image by its name or ID.
     ///
     /// # Example
@@ -482,6 +781,46 @@ impl Cloud {
         Router::load(self.session.clone(), id_or_name).await
     }
 
+    /// Find a project by its name or ID.
+    #[cfg(feature = "identity")]
+    pub async fn get_project<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<Project> {
+        Project::new(self.session.clone(), id_or_name).await
+    }
+
+    /// Find a role by its name or ID.
+    #[cfg(feature = "identity")]
+    pub async fn get_role<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<Role> {
+        Role::new(self.session.clone(), id_or_name).await
+    }
+
+    /// Find QoS specs by their name or ID.
+    #[cfg(feature = "block-storage")]
+    pub async fn get_qos_specs<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<QosSpecs> {
+        QosSpecs::new(self.session.clone(), id_or_name).await
+    }
+
+    /// Find a security group by its name or ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// let security_group = os.get_security_group("default")
+    ///     .await
+    ///     .expect("Unable to get a security group");
+    /// # }
+    /// ```
+    #[cfg(feature = "network")]
+    pub async fn get_security_group<Id: AsRef<str>>(
+        &self,
+        id_or_name: Id,
+    ) -> Result<SecurityGroup> {
+        SecurityGroup::load(self.session.clone(), id_or_name).await
+    }
+
     /// Find a server by its name or ID.
     ///
     /// # Example
@@ -501,6 +840,44 @@ impl Cloud {
         Server::load(self.session.clone(), id_or_name).await
     }
 
+    /// Find a server group by its name or ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// let group = os.get_server_group("web-servers")
+    ///     .await
+    ///     .expect("Unable to get a server group");
+    /// # }
+    /// ```
+    #[cfg(feature = "compute")]
+    pub async fn get_server_group<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<ServerGroup> {
+        ServerGroup::new(self.session.clone(), id_or_name).await
+    }
+
+    /// Find a snapshot by its name or ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// let snapshot = os.get_snapshot("nightly-backup")
+    ///     .await
+    ///     .expect("Unable to get a snapshot");
+    /// # }
+    /// ```
+    #[cfg(feature = "block-storage")]
+    pub async fn get_snapshot<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<Snapshot> {
+        Snapshot::new(self.session.clone(), id_or_name).await
+    }
+
     /// Find an subnet by its name or ID.
     ///
     /// # Example
@@ -520,6 +897,37 @@ impl Cloud {
         Subnet::load(self.session.clone(), id_or_name).await
     }
 
+    /// Find a user by its name or ID.
+    #[cfg(feature = "identity")]
+    pub async fn get_user<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<User> {
+        User::new(self.session.clone(), id_or_name).await
+    }
+
+    /// Find a key pair of the given user by its name.
+    ///
+    /// Only available to administrators, requires compute API version 2.10.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// let keypair = os.get_user_keypair("default", "8a1d3e4c")
+    ///     .await
+    ///     .expect("Unable to get a key pair");
+    /// # }
+    /// ```
+    #[cfg(feature = "compute")]
+    pub async fn get_user_keypair<Id, U>(&self, name: Id, user: U) -> Result<KeyPair>
+    where
+        Id: AsRef<str>,
+        U: Into<UserRef>,
+    {
+        KeyPair::new_for_user(self.session.clone(), name, user).await
+    }
+
     /// Find an volume by its name or ID.
     ///
     /// # Example
@@ -537,6 +945,23 @@ impl Cloud {
         Volume::new(self.session.clone(), id_or_name).await
     }
 
+    /// Find a volume type by its name or ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// let volume_type = os.get_volume_type("ssd").await.expect("Unable to get a volume type");
+    /// # }
+    /// ```
+    #[cfg(feature = "block-storage")]
+    pub async fn get_volume_type<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<VolumeType> {
+        VolumeType::new(self.session.clone(), id_or_name).await
+    }
+
     /// List all containers.
     ///
     /// This call can yield a lot of results, use the
@@ -582,6 +1007,64 @@ impl Cloud {
         self.find_objects(container).all().await
     }
 
+    /// List all application credentials of the current user.
+    #[cfg(feature = "identity")]
+    pub async fn list_application_credentials(&self) -> Result<Vec<ApplicationCredential>> {
+        self.find_application_credentials().all().await
+    }
+
+    /// List all availability zones.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// let zones = os.list_availability_zones()
+    ///     .await
+    ///     .expect("Unable to fetch availability zones");
+    /// for zone in zones.iter().filter(|zone| zone.state.available) {
+    ///     println!("{}", zone.name);
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "compute")]
+    pub async fn list_availability_zones(&self) -> Result<Vec<AvailabilityZone>> {
+        self.find_availability_zones().all().await
+    }
+
+    /// List all compute services.
+    ///
+    /// Only available to administrators.
+    #[cfg(feature = "compute")]
+    pub async fn list_compute_services(&self) -> Result<Vec<ComputeService>> {
+        self.find_compute_services().all().await
+    }
+
+    /// List compute usage of all projects over a period of time.
+    ///
+    /// Only available to administrators.
+    #[cfg(feature = "compute")]
+    pub async fn list_compute_usages(
+        &self,
+        start: DateTime<FixedOffset>,
+        end: DateTime<FixedOffset>,
+    ) -> Result<Vec<TenantUsage>> {
+        TenantUsage::load_all(&self.session, start, end).await
+    }
+
+    /// List all domains.
+    ///
+    /// This call can yield a lot of results, use the
+    /// [find_domains](#method.find_domains) call to limit the number of
+    /// domains to receive.
+    #[cfg(feature = "identity")]
+    pub async fn list_domains(&self) -> Result<Vec<Domain>> {
+        self.find_domains().all().await
+    }
+
     /// List all flavors.
     ///
     /// This call can yield a lot of results, use the
@@ -624,6 +1107,24 @@ impl Cloud {
         self.find_floating_ips().all().await
     }
 
+    /// List all groups.
+    ///
+    /// This call can yield a lot of results, use the
+    /// [find_groups](#method.find_groups) call to limit the number of
+    /// groups to receive.
+    #[cfg(feature = "identity")]
+    pub async fn list_groups(&self) -> Result<Vec<Group>> {
+        self.find_groups().all().await
+    }
+
+    /// List all hypervisors.
+    ///
+    /// Only available to administrators.
+    #[cfg(feature = "compute")]
+    pub async fn list_hypervisors(&self) -> Result<Vec<Hypervisor>> {
+        self.find_hypervisors().all().await
+    }
+
     /// List all images.
     ///
     /// This call can yield a lot of results, use the
@@ -725,6 +1226,53 @@ impl Cloud {
         self.find_routers().all().await
     }
 
+    /// List all projects.
+    ///
+    /// This call can yield a lot of results, use the
+    /// [find_projects](#method.find_projects) call to limit the number of
+    /// projects to receive.
+    #[cfg(feature = "identity")]
+    pub async fn list_projects(&self) -> Result<Vec<Project>> {
+        self.find_projects().all().await
+    }
+
+    /// List all roles.
+    ///
+    /// This call can yield a lot of results, use the
+    /// [find_roles](#method.find_roles) call to limit the number of
+    /// roles to receive.
+    #[cfg(feature = "identity")]
+    pub async fn list_roles(&self) -> Result<Vec<Role>> {
+        self.find_roles().all().await
+    }
+
+    /// List all QoS specs.
+    #[cfg(feature = "block-storage")]
+    pub async fn list_qos_specs(&self) -> Result<Vec<QosSpecs>> {
+        QosSpecs::list(self.session.clone()).await
+    }
+
+    /// List all security groups.
+    ///
+    /// This call can yield a lot of results, use the
+    /// [find_security_groups](#method.find_security_groups) call to limit
+    /// the number of security groups to receive.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// let sg_list = os.list_security_groups().await.expect("Unable to fetch security groups");
+    /// # }
+    /// ```
+    #[cfg(feature = "network")]
+    pub async fn list_security_groups(&self) -> Result<Vec<SecurityGroup>> {
+        self.find_security_groups().all().await
+    }
+
     /// List all servers.
     ///
     /// This call can yield a lot of results, use the
@@ -746,6 +1294,33 @@ impl Cloud {
         self.find_servers().all().await
     }
 
+    /// List all server groups of the current project.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// let groups = os.list_server_groups().await.expect("Unable to fetch server groups");
+    /// # }
+    /// ```
+    #[cfg(feature = "compute")]
+    pub async fn list_server_groups(&self) -> Result<Vec<ServerGroup>> {
+        self.find_server_groups().all().await
+    }
+
+    /// List all snapshots.
+    ///
+    /// This call can yield a lot of results, use the
+    /// [find_snapshots](#method.find_snapshots) call to limit the number of
+    /// snapshots to receive.
+    #[cfg(feature = "block-storage")]
+    pub async fn list_snapshots(&self) -> Result<Vec<Snapshot>> {
+        self.find_snapshots().all().await
+    }
+
     /// List all subnets.
     ///
     /// This call can yield a lot of results, use the
@@ -767,12 +1342,28 @@ impl Cloud {
         self.find_subnets().all().await
     }
 
+    /// List all users.
+    ///
+    /// This call can yield a lot of results, use the
+    /// [find_users](#method.find_users) call to limit the number of
+    /// users to receive.
+    #[cfg(feature = "identity")]
+    pub async fn list_users(&self) -> Result<Vec<User>> {
+        self.find_users().all().await
+    }
+
     /// List all volumes.
     #[cfg(feature = "block-storage")]
     pub async fn list_volumes(&self) -> Result<Vec<Volume>> {
         self.find_volumes().all().await
     }
 
+    /// List all volume types.
+    #[cfg(feature = "block-storage")]
+    pub async fn list_volume_types(&self) -> Result<Vec<VolumeType>> {
+        self.find_volume_types().all().await
+    }
+
     /// Prepare a new object for creation.
     ///
     /// This call returns a `NewObject` object, which is a builder
@@ -787,6 +1378,92 @@ impl Cloud {
         NewObject::new(self.session.clone(), container.into(), object.into(), body)
     }
 
+    /// Prepare a new application credential for creation.
+    ///
+    /// This call returns a `NewApplicationCredential` object, which is
+    /// a builder to populate application credential fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    /// use openstack::identity::AccessRule;
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// let credential = os.new_application_credential("ci")
+    ///     .with_access_rule(AccessRule::new("compute", "GET", "/v2.1/servers"))
+    ///     .create()
+    ///     .await
+    ///     .expect("Unable to create an application credential");
+    /// let auth = credential.to_auth().await.expect("Unable to build authentication");
+    /// let ci = openstack::Cloud::new(auth).await.expect("Unable to authenticate");
+    /// # }
+    /// ```
+    #[cfg(feature = "identity")]
+    pub fn new_application_credential<S>(&self, name: S) -> NewApplicationCredential
+    where
+        S: Into<String>,
+    {
+        NewApplicationCredential::new(self.session.clone(), name.into())
+    }
+
+    /// Prepare a new domain for creation.
+    ///
+    /// This call returns a `NewDomain` object, which is a builder to populate
+    /// domain fields.
+    #[cfg(feature = "identity")]
+    pub fn new_domain<S>(&self, name: S) -> NewDomain
+    where
+        S: Into<String>,
+    {
+        NewDomain::new(self.session.clone(), name.into())
+    }
+
+    /// Prepare a new flavor for creation.
+    ///
+    /// This call returns a `NewFlavor` object, which is a builder to populate
+    /// flavor fields. RAM size is in MiB, root disk size is in GiB.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// let mut flavor = os
+    ///     .new_flavor("m1.private", 2048, 2, 20)
+    ///     .with_public(false)
+    ///     .create()
+    ///     .await
+    ///     .expect("Unable to create a flavor");
+    /// flavor
+    ///     .set_extra_spec("hw:cpu_policy", "dedicated")
+    ///     .await
+    ///     .expect("Unable to set an extra spec");
+    /// # }
+    /// ```
+    #[cfg(feature = "compute")]
+    pub fn new_flavor<S>(
+        &self,
+        name: S,
+        ram_size: u64,
+        vcpu_count: u32,
+        root_size: u64,
+    ) -> NewFlavor
+    where
+        S: Into<String>,
+    {
+        NewFlavor::new(
+            self.session.clone(),
+            name.into(),
+            ram_size,
+            vcpu_count,
+            root_size,
+        )
+    }
+
     /// Prepare a new floating IP for creation.
     ///
     /// This call returns a `NewFloatingIp` object, which is a builder
@@ -799,6 +1476,50 @@ impl Cloud {
         NewFloatingIp::new(self.session.clone(), floating_network.into())
     }
 
+    /// Prepare a new group for creation.
+    ///
+    /// This call returns a `NewGroup` object, which is a builder to populate
+    /// group fields.
+    #[cfg(feature = "identity")]
+    pub fn new_group<S>(&self, name: S) -> NewGroup
+    where
+        S: Into<String>,
+    {
+        NewGroup::new(self.session.clone(), name.into())
+    }
+
+    /// Prepare a new image for creation.
+    ///
+    /// This call returns a `NewImage` object, which is a builder to populate
+    /// image fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    /// use openstack::image::{ImageContainerFormat, ImageDiskFormat};
+    /// use openstack::waiter::Waiter;
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// let data = futures::io::Cursor::new(vec![0u8; 1024]);
+    /// let image = os.new_image()
+    ///     .with_name("cirros")
+    ///     .with_container_format(ImageContainerFormat::Bare)
+    ///     .with_disk_format(ImageDiskFormat::Raw)
+    ///     .create_with_data(data)
+    ///     .await
+    ///     .expect("Unable to upload an image")
+    ///     .wait()
+    ///     .await
+    ///     .expect("Image did not become active");
+    /// # }
+    /// ```
+    #[cfg(feature = "image")]
+    pub fn new_image(&self) -> NewImage {
+        NewImage::new(self.session.clone())
+    }
+
     /// Prepare a new key pair for creation.
     ///
     /// This call returns a `NewKeyPair` object, which is a builder to populate
@@ -832,6 +1553,70 @@ impl Cloud {
         NewPort::new(self.session.clone(), network.into())
     }
 
+    /// Prepare a new project for creation.
+    ///
+    /// This call returns a `NewProject` object, which is a builder to populate
+    /// project fields.
+    #[cfg(feature = "identity")]
+    pub fn new_project<S>(&self, name: S) -> NewProject
+    where
+        S: Into<String>,
+    {
+        NewProject::new(self.session.clone(), name.into())
+    }
+
+    /// Prepare a new role for creation.
+    ///
+    /// This call returns a `NewRole` object, which is a builder to populate
+    /// role fields.
+    #[cfg(feature = "identity")]
+    pub fn new_role<S>(&self, name: S) -> NewRole
+    where
+        S: Into<String>,
+    {
+        NewRole::new(self.session.clone(), name.into())
+    }
+
+    /// Prepare a new role assignment.
+    ///
+    /// This call returns a `NewRoleAssignment` object, which is a builder
+    /// to populate the user or group and the project or domain.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// os.new_role_assignment("member")
+    ///     .with_user("alice")
+    ///     .with_project("demo")
+    ///     .create()
+    ///     .await
+    ///     .expect("Unable to assign a role");
+    /// # }
+    /// ```
+    #[cfg(feature = "identity")]
+    pub fn new_role_assignment<R>(&self, role: R) -> NewRoleAssignment
+    where
+        R: Into<RoleRef>,
+    {
+        NewRoleAssignment::new(self.session.clone(), role.into())
+    }
+
+    /// Prepare new QoS specs for creation.
+    ///
+    /// This call returns a `NewQosSpecs` object, which is a builder to
+    /// populate QoS specs fields.
+    #[cfg(feature = "block-storage")]
+    pub fn new_qos_specs<S>(&self, name: S) -> NewQosSpecs
+    where
+        S: Into<String>,
+    {
+        NewQosSpecs::new(self.session.clone(), name.into())
+    }
+
     /// Prepare a new router for creation.
     ///
     /// This call returns a `NewRouter` object, which is a builder to populate
@@ -841,6 +1626,49 @@ impl Cloud {
         NewRouter::new(self.session.clone())
     }
 
+    /// Prepare a new security group for creation.
+    ///
+    /// This call returns a `NewSecurityGroup` object, which is a builder to
+    /// populate security group fields.
+    #[cfg(feature = "network")]
+    pub fn new_security_group(&self) -> NewSecurityGroup {
+        NewSecurityGroup::new(self.session.clone())
+    }
+
+    /// Prepare a new security group rule for creation.
+    ///
+    /// This call returns a `NewSecurityGroupRule` object, which is a builder
+    /// to populate security group rule fields.
+    ///
+    /// # Example
+    ///
+    /// Allow incoming SSH connections from a private network:
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    /// use openstack::network::{SecurityGroupRuleDirection, SecurityGroupRuleProtocol};
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// let rule = os.new_security_group_rule("default", SecurityGroupRuleDirection::Ingress)
+    ///     .with_protocol(SecurityGroupRuleProtocol::TCP)
+    ///     .with_port_range(22, 22)
+    ///     .with_remote_ip_prefix("10.0.0.0/8".parse().unwrap())
+    ///     .create().await.expect("Unable to create a security group rule");
+    /// # }
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn new_security_group_rule<G>(
+        &self,
+        security_group: G,
+        direction: SecurityGroupRuleDirection,
+    ) -> NewSecurityGroupRule
+    where
+        G: Into<SecurityGroupRef>,
+    {
+        NewSecurityGroupRule::new(self.session.clone(), security_group.into(), direction)
+    }
+
     /// Prepare a new server for creation.
     ///
     /// This call returns a `NewServer` object, which is a builder to populate
@@ -854,6 +1682,80 @@ impl Cloud {
         NewServer::new(self.session.clone(), name.into(), flavor.into())
     }
 
+    /// Prepare a new server group for creation.
+    ///
+    /// This call returns a `NewServerGroup` object, which is a builder to populate
+    /// server group fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// let group = os
+    ///     .new_server_group("web-servers", openstack::compute::ServerGroupPolicy::AntiAffinity)
+    ///     .create()
+    ///     .await
+    ///     .expect("Unable to create a server group");
+    /// let server = os
+    ///     .new_server("web-1", "m1.small")
+    ///     .with_image("centos7")
+    ///     .with_server_group(group)
+    ///     .create()
+    ///     .await
+    ///     .expect("Unable to request a server");
+    /// # }
+    /// ```
+    #[cfg(feature = "compute")]
+    pub fn new_server_group<S>(&self, name: S, policy: ServerGroupPolicy) -> NewServerGroup
+    where
+        S: Into<String>,
+    {
+        NewServerGroup::new(self.session.clone(), name.into(), policy)
+    }
+
+    /// Prepare a new snapshot of a volume for creation.
+    ///
+    /// This call returns a `NewSnapshot` object, which is a builder to
+    /// populate snapshot fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    /// use openstack::waiter::Waiter;
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// let snapshot = os.new_snapshot("my-volume")
+    ///     .with_name("nightly-backup")
+    ///     .with_force(true)
+    ///     .create().await.expect("Unable to request a snapshot")
+    ///     .wait().await.expect("Snapshot failed");
+    /// # }
+    /// ```
+    #[cfg(feature = "block-storage")]
+    pub fn new_snapshot<V>(&self, volume: V) -> NewSnapshot
+    where
+        V: Into<VolumeRef>,
+    {
+        NewSnapshot::new(self.session.clone(), volume.into())
+    }
+
+    /// Prepare a new user for creation.
+    ///
+    /// This call returns a `NewUser` object, which is a builder to populate
+    /// user fields.
+    #[cfg(feature = "identity")]
+    pub fn new_user<S>(&self, name: S) -> NewUser
+    where
+        S: Into<String>,
+    {
+        NewUser::new(self.session.clone(), name.into())
+    }
+
     /// Prepare a new volume for creation.
     ///
     /// This call returns a `NewVolume` object, which is a builder to populate
@@ -866,6 +1768,32 @@ impl Cloud {
         NewVolume::new(self.session.clone(), size.into())
     }
 
+    /// Prepare a new volume type for creation.
+    ///
+    /// This call returns a `NewVolumeType` object, which is a builder to
+    /// populate volume type fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// let volume_type = os.new_volume_type("ssd")
+    ///     .with_extra_spec("volume_backend_name", "ssd-pool")
+    ///     .with_public(false)
+    ///     .create().await.expect("Unable to create a volume type");
+    /// # }
+    /// ```
+    #[cfg(feature = "block-storage")]
+    pub fn new_volume_type<S>(&self, name: S) -> NewVolumeType
+    where
+        S: Into<String>,
+    {
+        NewVolumeType::new(self.session.clone(), name.into())
+    }
+
     /// Prepare a new subnet for creation.
     ///
     /// This call returns a `NewSubnet` object, which is a builder to populate
@@ -22,6 +22,7 @@ pub use osauth::ApiVersion;
 
 pub use self::resourceiterator::{ResourceIterator, ResourceQuery};
 pub use self::types::{
-    ContainerRef, FlavorRef, ImageRef, KeyPairRef, NetworkRef, ObjectRef, PortRef, ProjectRef,
-    Refresh, RouterRef, SecurityGroupRef, SnapshotRef, SubnetRef, UserRef, VolumeRef,
+    ContainerRef, DomainRef, FlavorRef, GroupRef, ImageRef, KeyPairRef, NetworkRef, ObjectRef,
+    PortRef, ProjectRef, Refresh, RoleRef, RouterRef, SecurityGroupRef, ServerGroupRef,
+    SnapshotRef, SubnetRef, UserRef, VolumeRef, VolumeTypeRef,
 };
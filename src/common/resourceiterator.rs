@@ -119,9 +119,10 @@ where
     /// Note that no requests are done until you start iterating.
     pub fn into_stream(mut self) -> impl Stream<Item = Result<Q::Item>> {
         try_stream! {
+            // The stream owns the iterator and validates at most once, so there is
+            // no need to record that validation happened.
             if !self.validated {
                 self.query.validate().await?;
-                self.validated = true;
             }
 
             if self.can_paginate.is_none() {
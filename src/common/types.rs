@@ -118,8 +118,12 @@ macro_rules! opaque_resource_type {
 
 opaque_resource_type!(#[doc = "An ID of a `Container`"] ContainerRef ? "object-storage");
 
+opaque_resource_type!(#[doc = "An ID of a `Domain`"] DomainRef ? "identity");
+
 opaque_resource_type!(#[doc = "An ID of a `Flavor`"] FlavorRef ? "compute");
 
+opaque_resource_type!(#[doc = "An ID of a `Group`"] GroupRef ? "identity");
+
 opaque_resource_type!(#[doc = "An ID of an `Image`"] ImageRef ? "image");
 
 opaque_resource_type!(#[doc = "An ID of a `KeyPair`"] KeyPairRef ? "compute");
@@ -132,17 +136,23 @@ opaque_resource_type!(#[doc = "An ID of a `Project`"] ProjectRef ? "identity");
 
 opaque_resource_type!(#[doc = "An ID of a `Port`"] PortRef ? "network");
 
+opaque_resource_type!(#[doc = "An ID of a `Role`"] RoleRef ? "identity");
+
 opaque_resource_type!(#[doc = "An ID of a `Router`"] RouterRef ? "network");
 
 opaque_resource_type!(#[doc = "An ID of a `SecurityGroup`"] SecurityGroupRef ? "network");
 
-opaque_resource_type!(#[doc = "An ID of a `Snapshot`"] SnapshotRef ? "volume");
+opaque_resource_type!(#[doc = "An ID of a `ServerGroup`"] ServerGroupRef ? "compute");
+
+opaque_resource_type!(#[doc = "An ID of a `Snapshot`"] SnapshotRef ? "block-storage");
 
 opaque_resource_type!(#[doc = "An ID of a `Subnet`"] SubnetRef ? "network");
 
 opaque_resource_type!(#[doc = "An ID of a `User`"] UserRef ? "identity");
 
-opaque_resource_type!(#[doc = "An ID of a `Volume`"] VolumeRef ? "volume");
+opaque_resource_type!(#[doc = "An ID of a `Volume`"] VolumeRef ? "block-storage");
+
+opaque_resource_type!(#[doc = "An ID of a `VolumeType`"] VolumeTypeRef ? "block-storage");
 
 #[cfg(test)]
 mod test {
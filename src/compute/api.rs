@@ -17,33 +17,96 @@
 use std::collections::HashMap;
 use std::fmt::Debug;
 
-use osauth::common::{IdAndName, Ref};
+use chrono::{DateTime, FixedOffset, Utc};
+use osauth::common::IdAndName;
 use osauth::services::COMPUTE;
-use osauth::ErrorKind;
 use serde::Serialize;
 
 use super::super::common::ApiVersion;
 use super::super::session::Session;
 use super::super::utils;
-use super::super::Result;
+use super::super::{Error, ErrorKind, Result};
 use super::protocol::*;
 
 const API_VERSION_KEYPAIR_TYPE: ApiVersion = ApiVersion(2, 2);
+const API_VERSION_REMOTE_CONSOLE: ApiVersion = ApiVersion(2, 6);
+const API_VERSION_REMOTE_CONSOLE_MKS: ApiVersion = ApiVersion(2, 8);
+const API_VERSION_LOCKED: ApiVersion = ApiVersion(2, 9);
+const API_VERSION_KEYPAIR_USER: ApiVersion = ApiVersion(2, 10);
+const API_VERSION_SERVICE_FORCED_DOWN: ApiVersion = ApiVersion(2, 11);
+const API_VERSION_EVACUATE: ApiVersion = ApiVersion(2, 14);
+const API_VERSION_SERVER_GROUP_SOFT: ApiVersion = ApiVersion(2, 15);
 const API_VERSION_SERVER_DESCRIPTION: ApiVersion = ApiVersion(2, 19);
+const API_VERSION_MIGRATION_FORCE_COMPLETE: ApiVersion = ApiVersion(2, 22);
+const API_VERSION_SERVER_MIGRATIONS: ApiVersion = ApiVersion(2, 23);
+const API_VERSION_MIGRATION_ABORT: ApiVersion = ApiVersion(2, 24);
+const API_VERSION_LIVE_MIGRATE_AUTO: ApiVersion = ApiVersion(2, 25);
+const API_VERSION_SERVER_TAGS: ApiVersion = ApiVersion(2, 26);
+const API_VERSION_HYPERVISOR_PAGINATION: ApiVersion = ApiVersion(2, 33);
 const API_VERSION_KEYPAIR_PAGINATION: ApiVersion = ApiVersion(2, 35);
+#[cfg(feature = "image")]
+const API_VERSION_IMAGE_ID: ApiVersion = ApiVersion(2, 45);
 const API_VERSION_SERVER_FLAVOR: ApiVersion = ApiVersion(2, 47);
+const API_VERSION_SERVER_CREATE_TAGS: ApiVersion = ApiVersion(2, 52);
+const API_VERSION_SERVICE_UUID: ApiVersion = ApiVersion(2, 53);
 const API_VERSION_FLAVOR_DESCRIPTION: ApiVersion = ApiVersion(2, 55);
+const API_VERSION_MIGRATE_HOST: ApiVersion = ApiVersion(2, 56);
 const API_VERSION_FLAVOR_EXTRA_SPECS: ApiVersion = ApiVersion(2, 61);
+const API_VERSION_SERVER_GROUP_POLICY: ApiVersion = ApiVersion(2, 64);
+const API_VERSION_SERVER_GROUPS: ApiVersion = ApiVersion(2, 71);
+const API_VERSION_LOCKED_REASON: ApiVersion = ApiVersion(2, 73);
+const API_VERSION_SERVER_HOSTNAME: ApiVersion = ApiVersion(2, 90);
+// The last version to accept personality files.
+const API_VERSION_PERSONALITY_MAX: ApiVersion = ApiVersion(2, 56);
 
 async fn server_api_version(session: &Session) -> Result<Option<ApiVersion>> {
     session
         .pick_api_version(
             COMPUTE,
-            vec![API_VERSION_SERVER_DESCRIPTION, API_VERSION_SERVER_FLAVOR],
+            vec![
+                API_VERSION_LOCKED,
+                API_VERSION_SERVER_DESCRIPTION,
+                API_VERSION_SERVER_TAGS,
+                API_VERSION_SERVER_FLAVOR,
+                API_VERSION_SERVER_GROUPS,
+                API_VERSION_LOCKED_REASON,
+            ],
         )
         .await
 }
 
+/// Use the pinned API version for a server request if any, otherwise negotiate one.
+async fn pinned_server_api_version(
+    session: &Session,
+    pinned: Option<ApiVersion>,
+) -> Result<Option<ApiVersion>> {
+    match pinned {
+        Some(version) => {
+            require_api_version(session, version, "Pinned API version").await?;
+            Ok(Some(version))
+        }
+        None => server_api_version(session).await,
+    }
+}
+
+/// Make sure that the compute service supports the given API version.
+///
+/// The `feature` is used in the error message and should describe what requires the version.
+async fn require_api_version(session: &Session, version: ApiVersion, feature: &str) -> Result<()> {
+    let message = match session.get_api_versions(COMPUTE).await? {
+        Some((_, max)) if max >= version => return Ok(()),
+        Some((_, max)) => format!(
+            "{} requires compute API version {}, the maximum supported version is {}",
+            feature, version, max
+        ),
+        None => format!(
+            "{} requires compute API version {}, microversions are not supported",
+            feature, version
+        ),
+    };
+    Err(Error::new(ErrorKind::IncompatibleApiVersion, message))
+}
+
 async fn flavor_api_version(session: &Session) -> Result<Option<ApiVersion>> {
     session
         .pick_api_version(
@@ -56,6 +119,241 @@ async fn flavor_api_version(session: &Session) -> Result<Option<ApiVersion>> {
         .await
 }
 
+async fn server_group_api_version(session: &Session) -> Result<Option<ApiVersion>> {
+    session
+        .pick_api_version(
+            COMPUTE,
+            vec![
+                API_VERSION_SERVER_GROUP_SOFT,
+                API_VERSION_SERVER_GROUP_POLICY,
+            ],
+        )
+        .await
+}
+
+async fn hypervisor_api_version(session: &Session) -> Result<Option<ApiVersion>> {
+    session
+        .pick_api_version(
+            COMPUTE,
+            vec![API_VERSION_HYPERVISOR_PAGINATION, API_VERSION_SERVICE_UUID],
+        )
+        .await
+}
+
+async fn service_api_version(session: &Session) -> Result<Option<ApiVersion>> {
+    session
+        .pick_api_version(
+            COMPUTE,
+            vec![API_VERSION_SERVICE_FORCED_DOWN, API_VERSION_SERVICE_UUID],
+        )
+        .await
+}
+
+/// Run an access action on a flavor.
+async fn flavor_access_action<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    action: FlavorAccessAction,
+) -> Result<()> {
+    debug!("Running {:?} on flavor {}", action, id.as_ref());
+    let _ = session
+        .post(COMPUTE, &["flavors", id.as_ref(), "action"])
+        .json(&action)
+        .send()
+        .await?;
+    debug!("Successfully ran {:?} on flavor {}", action, id.as_ref());
+    Ok(())
+}
+
+/// Run an action on a server with the given API version.
+async fn server_action_with_version<S1, Q>(
+    session: &Session,
+    id: S1,
+    action: Q,
+    version: ApiVersion,
+) -> Result<()>
+where
+    S1: AsRef<str>,
+    Q: Serialize + Send + Debug,
+{
+    trace!(
+        "Running {:?} on server {} with API version {}",
+        action,
+        id.as_ref(),
+        version
+    );
+    let mut builder = session
+        .post(COMPUTE, &["servers", id.as_ref(), "action"])
+        .json(&action);
+    builder.set_api_version(version);
+    let _ = builder.send().await?;
+    debug!("Successfully ran {:?} on server {}", action, id.as_ref());
+    Ok(())
+}
+
+/// Run an action that creates an image from a server, returning the image ID.
+///
+/// The ID is returned in the body since compute API version 2.45 and in the `Location`
+/// header before that. It may be missing, e.g. for backups with zero rotation.
+#[cfg(feature = "image")]
+async fn server_image_action<S1, Q>(session: &Session, id: S1, action: Q) -> Result<Option<String>>
+where
+    S1: AsRef<str>,
+    Q: Serialize + Send + Debug,
+{
+    trace!("Running {:?} on server {}", action, id.as_ref());
+    let image_id_in_body = session
+        .supports_api_version(COMPUTE, API_VERSION_IMAGE_ID)
+        .await?;
+    let mut builder = session
+        .post(COMPUTE, &["servers", id.as_ref(), "action"])
+        .json(&action);
+    if image_id_in_body {
+        builder.set_api_version(API_VERSION_IMAGE_ID);
+    }
+    let response = builder.send().await?;
+    let image_id = if image_id_in_body {
+        let body = response.text().await?;
+        if body.trim().is_empty() {
+            None
+        } else {
+            let created: CreatedImage = serde_json::from_str(&body).map_err(|err| {
+                Error::new(
+                    ErrorKind::InvalidResponse,
+                    format!("Cannot parse image creation response: {}", err),
+                )
+            })?;
+            Some(created.image_id)
+        }
+    } else {
+        response
+            .headers()
+            .get("location")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim_end_matches('/').rsplit('/').next())
+            .map(ToString::to_string)
+    };
+    debug!(
+        "Successfully ran {:?} on server {}, image ID is {:?}",
+        action,
+        id.as_ref(),
+        image_id
+    );
+    Ok(image_id)
+}
+
+/// Format a usage report period as query parameters.
+fn usage_period(
+    start: DateTime<FixedOffset>,
+    end: DateTime<FixedOffset>,
+) -> [(&'static str, String); 2] {
+    const FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.6f";
+    [
+        (
+            "start",
+            start.with_timezone(&Utc).format(FORMAT).to_string(),
+        ),
+        ("end", end.with_timezone(&Utc).format(FORMAT).to_string()),
+    ]
+}
+
+/// Abort an in-progress live migration of a server.
+pub async fn abort_server_migration<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    migration_id: u64,
+) -> Result<()> {
+    require_api_version(session, API_VERSION_MIGRATION_ABORT, "Aborting migrations").await?;
+    trace!(
+        "Aborting migration {} of server {}",
+        migration_id,
+        id.as_ref()
+    );
+    let migration_id = migration_id.to_string();
+    let mut builder = session.delete(
+        COMPUTE,
+        &["servers", id.as_ref(), "migrations", &migration_id],
+    );
+    builder.set_api_version(API_VERSION_MIGRATION_ABORT);
+    let _ = builder.send().await?;
+    debug!(
+        "Successfully requested abort of migration {} of server {}",
+        migration_id,
+        id.as_ref()
+    );
+    Ok(())
+}
+
+/// Grant a project access to a flavor.
+pub async fn add_flavor_access<S1, S2>(session: &Session, id: S1, project_id: S2) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: Into<String>,
+{
+    let action = FlavorAccessAction::Add(FlavorAccessTenant {
+        tenant: project_id.into(),
+    });
+    flavor_access_action(session, id, action).await
+}
+
+/// Add a tag to a server.
+pub async fn add_server_tag<S1, S2>(session: &Session, id: S1, tag: S2) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    require_server_tags(session).await?;
+    debug!("Adding tag {} to server {}", tag.as_ref(), id.as_ref());
+    let _ = session
+        .put(COMPUTE, &["servers", id.as_ref(), "tags", tag.as_ref()])
+        .api_version(API_VERSION_SERVER_TAGS)
+        .send()
+        .await?;
+    debug!("Added tag {} to server {}", tag.as_ref(), id.as_ref());
+    Ok(())
+}
+
+/// Create a flavor.
+pub async fn create_flavor(session: &Session, request: FlavorCreate) -> Result<Flavor> {
+    debug!("Creating a flavor with {:?}", request);
+    let maybe_version = flavor_api_version(session).await?;
+    let body = FlavorCreateRoot { flavor: request };
+    let mut builder = session.post(COMPUTE, &["flavors"]).json(&body);
+    if let Some(version) = maybe_version {
+        builder.set_api_version(version);
+    }
+    let root: FlavorRoot = builder.fetch().await?;
+    debug!("Created flavor {:?}", root.flavor);
+    Ok(root.flavor)
+}
+
+/// Attach a network interface (port) to a server.
+#[cfg(feature = "network")]
+pub async fn create_interface_attachment<S: AsRef<str>>(
+    session: &Session,
+    server_id: S,
+    request: InterfaceAttachmentCreate,
+) -> Result<InterfaceAttachment> {
+    debug!(
+        "Attaching an interface to server {} with {:?}",
+        server_id.as_ref(),
+        request
+    );
+    let body = InterfaceAttachmentCreateRoot {
+        interface_attachment: request,
+    };
+    let root: InterfaceAttachmentRoot = session
+        .post(COMPUTE, &["servers", server_id.as_ref(), "os-interface"])
+        .json(&body)
+        .fetch()
+        .await?;
+    debug!(
+        "Created interface attachment {:?}",
+        root.interface_attachment
+    );
+    Ok(root.interface_attachment)
+}
+
 /// Create a key pair.
 pub async fn create_keypair(session: &Session, request: KeyPairCreate) -> Result<KeyPair> {
     let version = if request.key_type.is_some() {
@@ -77,26 +375,271 @@ pub async fn create_keypair(session: &Session, request: KeyPairCreate) -> Result
     Ok(root.keypair)
 }
 
+/// Create a remote console for a server.
+pub async fn create_remote_console<S: AsRef<str>>(
+    session: &Session,
+    server_id: S,
+    request: RemoteConsoleCreate,
+) -> Result<RemoteConsole> {
+    let version = if request.protocol == RemoteConsoleProtocol::Mks {
+        API_VERSION_REMOTE_CONSOLE_MKS
+    } else {
+        API_VERSION_REMOTE_CONSOLE
+    };
+    require_api_version(session, version, "Remote consoles").await?;
+
+    debug!(
+        "Creating a remote console for server {} with {:?}",
+        server_id.as_ref(),
+        request
+    );
+    let body = RemoteConsoleCreateRoot {
+        remote_console: request,
+    };
+    let root: RemoteConsoleRoot = session
+        .post(COMPUTE, &["servers", server_id.as_ref(), "remote-consoles"])
+        .api_version(version)
+        .json(&body)
+        .fetch()
+        .await?;
+    debug!("Created remote console {:?}", root.remote_console);
+    Ok(root.remote_console)
+}
+
 /// Create a server.
-pub async fn create_server(session: &Session, request: ServerCreate) -> Result<Ref> {
+pub async fn create_server(
+    session: &Session,
+    mut request: ServerCreate,
+) -> Result<CreatedServerRoot> {
+    let mut version = None;
+    if request.description.is_some() {
+        require_api_version(
+            session,
+            API_VERSION_SERVER_DESCRIPTION,
+            "Server description",
+        )
+        .await?;
+        version = Some(API_VERSION_SERVER_DESCRIPTION);
+    }
+    if !request.tags.is_empty() {
+        require_api_version(session, API_VERSION_SERVER_CREATE_TAGS, "Server tags").await?;
+        version = Some(API_VERSION_SERVER_CREATE_TAGS);
+    }
+    if request.hostname.is_some() {
+        require_api_version(session, API_VERSION_SERVER_HOSTNAME, "Server hostname").await?;
+        version = Some(API_VERSION_SERVER_HOSTNAME);
+    }
+    if !request.personality.is_empty() {
+        if let Some(version) = version.filter(|v| *v > API_VERSION_PERSONALITY_MAX) {
+            return Err(Error::new(
+                ErrorKind::IncompatibleApiVersion,
+                format!(
+                    "Personality files cannot be combined with fields that require \
+                     compute API version {}",
+                    version
+                ),
+            ));
+        }
+    }
+
     debug!("Creating a server with {:?}", request);
-    let body = ServerCreateRoot { server: request };
-    let root: CreatedServerRoot = session
-        .post(COMPUTE, &["servers"])
+    let scheduler_hints = std::mem::take(&mut request.scheduler_hints);
+    let body = ServerCreateRoot {
+        server: request,
+        scheduler_hints,
+    };
+    let mut builder = session.post(COMPUTE, &["servers"]).json(&body);
+    if let Some(version) = version {
+        builder.set_api_version(version);
+    }
+    let root: CreatedServerRoot = builder.fetch().await?;
+    trace!("Requested creation of server(s) {:?}", root);
+    Ok(root)
+}
+
+/// Create a backup image of a server.
+#[cfg(feature = "image")]
+pub async fn create_server_backup<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    request: CreateBackup,
+) -> Result<Option<String>> {
+    let action = CreateBackupRoot {
+        create_backup: request,
+    };
+    server_image_action(session, id, action).await
+}
+
+/// Create a server group.
+pub async fn create_server_group(
+    session: &Session,
+    mut request: ServerGroupCreate,
+) -> Result<ServerGroup> {
+    let soft = matches!(
+        request.policy,
+        Some(ServerGroupPolicy::SoftAffinity) | Some(ServerGroupPolicy::SoftAntiAffinity)
+    );
+    if soft {
+        require_api_version(
+            session,
+            API_VERSION_SERVER_GROUP_SOFT,
+            "Soft affinity policies",
+        )
+        .await?;
+    }
+    let maybe_version = server_group_api_version(session).await?;
+    if maybe_version < Some(API_VERSION_SERVER_GROUP_POLICY) {
+        // Older versions accept a list with exactly one policy.
+        request.policies = request.policy.take().into_iter().collect();
+    }
+
+    debug!("Creating a server group with {:?}", request);
+    let body = ServerGroupCreateRoot {
+        server_group: request,
+    };
+    let mut builder = session.post(COMPUTE, &["os-server-groups"]).json(&body);
+    if let Some(version) = maybe_version {
+        builder.set_api_version(version);
+    }
+    let root: ServerGroupRoot = builder.fetch().await?;
+    debug!("Created server group {:?}", root.server_group);
+    Ok(root.server_group)
+}
+
+/// Create an image (snapshot) of a server.
+#[cfg(feature = "image")]
+pub async fn create_server_image<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    request: CreateImage,
+) -> Result<Option<String>> {
+    let action = CreateImageRoot {
+        create_image: request,
+    };
+    server_image_action(session, id, action).await
+}
+
+/// Attach a volume to a server.
+#[cfg(feature = "block-storage")]
+pub async fn create_volume_attachment<S: AsRef<str>>(
+    session: &Session,
+    server_id: S,
+    request: VolumeAttachmentCreate,
+) -> Result<VolumeAttachment> {
+    debug!(
+        "Attaching a volume to server {} with {:?}",
+        server_id.as_ref(),
+        request
+    );
+    let body = VolumeAttachmentCreateRoot {
+        volumeAttachment: request,
+    };
+    let root: VolumeAttachmentRoot = session
+        .post(
+            COMPUTE,
+            &["servers", server_id.as_ref(), "os-volume_attachments"],
+        )
         .json(&body)
         .fetch()
         .await?;
-    trace!("Requested creation of server {:?}", root.server);
-    Ok(root.server)
+    debug!("Created volume attachment {:?}", root.volumeAttachment);
+    Ok(root.volumeAttachment)
 }
 
-/// Delete a key pair.
-pub async fn delete_keypair<S: AsRef<str>>(session: &Session, name: S) -> Result<()> {
-    debug!("Deleting key pair {}", name.as_ref());
+/// Delete a flavor.
+pub async fn delete_flavor<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
+    debug!("Deleting flavor {}", id.as_ref());
     let _ = session
-        .delete(COMPUTE, &["os-keypairs", name.as_ref()])
+        .delete(COMPUTE, &["flavors", id.as_ref()])
         .send()
         .await?;
+    debug!("Flavor {} was deleted", id.as_ref());
+    Ok(())
+}
+
+/// Delete an extra spec of a flavor.
+pub async fn delete_flavor_extra_spec<S1, S2>(session: &Session, id: S1, key: S2) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    debug!(
+        "Deleting extra spec {} from flavor {}",
+        key.as_ref(),
+        id.as_ref()
+    );
+    let _ = session
+        .delete(
+            COMPUTE,
+            &["flavors", id.as_ref(), "os-extra_specs", key.as_ref()],
+        )
+        .send()
+        .await?;
+    debug!(
+        "Deleted extra spec {} from flavor {}",
+        key.as_ref(),
+        id.as_ref()
+    );
+    Ok(())
+}
+
+/// Detach a network interface (port) from a server.
+#[cfg(feature = "network")]
+pub async fn delete_interface_attachment<S1, S2>(
+    session: &Session,
+    server_id: S1,
+    port_id: S2,
+) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    debug!(
+        "Detaching port {} from server {}",
+        port_id.as_ref(),
+        server_id.as_ref()
+    );
+    let _ = session
+        .delete(
+            COMPUTE,
+            &[
+                "servers",
+                server_id.as_ref(),
+                "os-interface",
+                port_id.as_ref(),
+            ],
+        )
+        .send()
+        .await?;
+    debug!(
+        "Requested detaching of port {} from server {}",
+        port_id.as_ref(),
+        server_id.as_ref()
+    );
+    Ok(())
+}
+
+/// Delete a key pair.
+///
+/// Key pairs of other users can be deleted by administrators by providing `user_id`.
+pub async fn delete_keypair<S: AsRef<str>>(
+    session: &Session,
+    name: S,
+    user_id: Option<&str>,
+) -> Result<()> {
+    debug!("Deleting key pair {} (user: {:?})", name.as_ref(), user_id);
+    let mut builder = session.delete(COMPUTE, &["os-keypairs", name.as_ref()]);
+    if let Some(user_id) = user_id {
+        require_api_version(
+            session,
+            API_VERSION_KEYPAIR_USER,
+            "Key pairs of other users",
+        )
+        .await?;
+        builder.set_api_version(API_VERSION_KEYPAIR_USER);
+        builder = builder.query(&[("user_id", user_id)]);
+    }
+    let _ = builder.send().await?;
     debug!("Key pair {} was deleted", name.as_ref());
     Ok(())
 }
@@ -112,6 +655,169 @@ pub async fn delete_server<S: AsRef<str>>(session: &Session, id: S) -> Result<()
     Ok(())
 }
 
+/// Delete a server group.
+pub async fn delete_server_group<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
+    debug!("Deleting server group {}", id.as_ref());
+    let _ = session
+        .delete(COMPUTE, &["os-server-groups", id.as_ref()])
+        .send()
+        .await?;
+    debug!("Server group {} was deleted", id.as_ref());
+    Ok(())
+}
+
+/// Delete a server metadata item.
+pub async fn delete_server_metadata_item<S1, S2>(session: &Session, id: S1, key: S2) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    debug!(
+        "Deleting metadata item {} from server {}",
+        key.as_ref(),
+        id.as_ref()
+    );
+    let _ = session
+        .delete(COMPUTE, &["servers", id.as_ref(), "metadata", key.as_ref()])
+        .send()
+        .await?;
+    debug!(
+        "Deleted metadata item {} from server {}",
+        key.as_ref(),
+        id.as_ref()
+    );
+    Ok(())
+}
+
+/// Delete a tag from a server.
+pub async fn delete_server_tag<S1, S2>(session: &Session, id: S1, tag: S2) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    require_server_tags(session).await?;
+    debug!("Deleting tag {} from server {}", tag.as_ref(), id.as_ref());
+    let _ = session
+        .delete(COMPUTE, &["servers", id.as_ref(), "tags", tag.as_ref()])
+        .api_version(API_VERSION_SERVER_TAGS)
+        .send()
+        .await?;
+    debug!("Deleted tag {} from server {}", tag.as_ref(), id.as_ref());
+    Ok(())
+}
+
+/// Detach a volume from a server.
+#[cfg(feature = "block-storage")]
+pub async fn delete_volume_attachment<S1, S2>(
+    session: &Session,
+    server_id: S1,
+    volume_id: S2,
+) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    debug!(
+        "Detaching volume {} from server {}",
+        volume_id.as_ref(),
+        server_id.as_ref()
+    );
+    let _ = session
+        .delete(
+            COMPUTE,
+            &[
+                "servers",
+                server_id.as_ref(),
+                "os-volume_attachments",
+                volume_id.as_ref(),
+            ],
+        )
+        .send()
+        .await?;
+    debug!(
+        "Successfully requested detaching volume {} from server {}",
+        volume_id.as_ref(),
+        server_id.as_ref()
+    );
+    Ok(())
+}
+
+/// Evacuate a server from a failed host.
+pub async fn evacuate_server<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    host: Option<String>,
+) -> Result<()> {
+    require_api_version(session, API_VERSION_EVACUATE, "Evacuation").await?;
+    let action = EvacuateRoot {
+        evacuate: Evacuate { host },
+    };
+    server_action_with_version(session, id, action, API_VERSION_EVACUATE).await
+}
+
+/// Force completion of an in-progress live migration of a server.
+pub async fn force_complete_server_migration<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    migration_id: u64,
+) -> Result<()> {
+    require_api_version(
+        session,
+        API_VERSION_MIGRATION_FORCE_COMPLETE,
+        "Forcing migration completion",
+    )
+    .await?;
+    trace!(
+        "Forcing completion of migration {} of server {}",
+        migration_id,
+        id.as_ref()
+    );
+    let migration_id = migration_id.to_string();
+    let mut builder = session
+        .post(
+            COMPUTE,
+            &[
+                "servers",
+                id.as_ref(),
+                "migrations",
+                &migration_id,
+                "action",
+            ],
+        )
+        .json(&ForceCompleteRoot { force_complete: () });
+    builder.set_api_version(API_VERSION_MIGRATION_FORCE_COMPLETE);
+    let _ = builder.send().await?;
+    debug!(
+        "Successfully forced completion of migration {} of server {}",
+        migration_id,
+        id.as_ref()
+    );
+    Ok(())
+}
+
+/// Get console output of a server.
+pub async fn get_console_output<S: AsRef<str>>(
+    session: &Session,
+    server_id: S,
+    length: Option<u32>,
+) -> Result<String> {
+    trace!(
+        "Get console output of server {} (length {:?})",
+        server_id.as_ref(),
+        length
+    );
+    let body = ConsoleOutputRequestRoot {
+        request: ConsoleOutputRequest { length },
+    };
+    let root: ConsoleOutput = session
+        .post(COMPUTE, &["servers", server_id.as_ref(), "action"])
+        .json(&body)
+        .fetch()
+        .await?;
+    trace!("Received {} bytes of console output", root.output.len());
+    Ok(root.output)
+}
+
 /// Get a flavor by its ID.
 pub async fn get_extra_specs_by_flavor_id<S: AsRef<str>>(
     session: &Session,
@@ -125,6 +831,30 @@ pub async fn get_extra_specs_by_flavor_id<S: AsRef<str>>(
     Ok(root.extra_specs)
 }
 
+/// Get a hypervisor by its ID.
+pub async fn get_hypervisor<S: AsRef<str>>(session: &Session, id: S) -> Result<Hypervisor> {
+    trace!("Get compute hypervisor {}", id.as_ref());
+    let maybe_version = hypervisor_api_version(session).await?;
+    let mut builder = session.get(COMPUTE, &["os-hypervisors", id.as_ref()]);
+    if let Some(version) = maybe_version {
+        builder.set_api_version(version);
+    }
+    let root: HypervisorRoot = builder.fetch().await?;
+    trace!("Received {:?}", root.hypervisor);
+    Ok(root.hypervisor)
+}
+
+/// Get summary statistics over all hypervisors.
+pub async fn get_hypervisor_statistics(session: &Session) -> Result<HypervisorStatistics> {
+    trace!("Get compute hypervisor statistics");
+    // This call is not available in compute API version 2.88 and newer.
+    let root: HypervisorStatisticsRoot = session
+        .get_json(COMPUTE, &["os-hypervisors", "statistics"])
+        .await?;
+    trace!("Received {:?}", root.hypervisor_statistics);
+    Ok(root.hypervisor_statistics)
+}
+
 /// Get a flavor.
 pub async fn get_flavor<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<Flavor> {
     let s = id_or_name.as_ref();
@@ -165,20 +895,62 @@ pub async fn get_flavor_by_name<S: AsRef<str>>(session: &Session, name: S) -> Re
 }
 
 /// Get a key pair by its name.
-pub async fn get_keypair<S: AsRef<str>>(session: &Session, name: S) -> Result<KeyPair> {
-    trace!("Get compute key pair by name {}", name.as_ref());
-    let maybe_version = session
-        .pick_api_version(COMPUTE, Some(API_VERSION_KEYPAIR_TYPE))
-        .await?;
+///
+/// Key pairs of other users can be fetched by administrators by providing `user_id`.
+pub async fn get_keypair<S: AsRef<str>>(
+    session: &Session,
+    name: S,
+    user_id: Option<&str>,
+) -> Result<KeyPair> {
+    trace!(
+        "Get compute key pair by name {} (user: {:?})",
+        name.as_ref(),
+        user_id
+    );
     let mut builder = session.get(COMPUTE, &["os-keypairs", name.as_ref()]);
-    if let Some(version) = maybe_version {
-        builder.set_api_version(version);
+    if let Some(user_id) = user_id {
+        require_api_version(
+            session,
+            API_VERSION_KEYPAIR_USER,
+            "Key pairs of other users",
+        )
+        .await?;
+        builder.set_api_version(API_VERSION_KEYPAIR_USER);
+        builder = builder.query(&[("user_id", user_id)]);
+    } else {
+        let maybe_version = session
+            .pick_api_version(
+                COMPUTE,
+                vec![API_VERSION_KEYPAIR_TYPE, API_VERSION_KEYPAIR_USER],
+            )
+            .await?;
+        if let Some(version) = maybe_version {
+            builder.set_api_version(version);
+        }
     }
     let root: KeyPairRoot = builder.fetch().await?;
     trace!("Received {:?}", root.keypair);
     Ok(root.keypair)
 }
 
+/// Get absolute limits and usage of the current project.
+pub async fn get_limits(session: &Session) -> Result<AbsoluteLimits> {
+    trace!("Get compute limits");
+    let root: LimitsRoot = session.get_json(COMPUTE, &["limits"]).await?;
+    trace!("Received {:?}", root.limits.absolute);
+    Ok(root.limits.absolute)
+}
+
+/// Get compute quotas of a project.
+pub async fn get_quota_set<S: AsRef<str>>(session: &Session, project_id: S) -> Result<QuotaSet> {
+    trace!("Get compute quotas of project {}", project_id.as_ref());
+    let root: QuotaSetRoot = session
+        .get_json(COMPUTE, &["os-quota-sets", project_id.as_ref()])
+        .await?;
+    trace!("Received {:?}", root.quota_set);
+    Ok(root.quota_set)
+}
+
 /// Get a server.
 pub async fn get_server<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<Server> {
     let s = id_or_name.as_ref();
@@ -222,6 +994,120 @@ pub async fn get_server_by_name<S: AsRef<str>>(session: &Session, name: S) -> Re
     get_server_by_id(session, item.id).await
 }
 
+/// Get a server group.
+pub async fn get_server_group<S: AsRef<str>>(
+    session: &Session,
+    id_or_name: S,
+) -> Result<ServerGroup> {
+    let s = id_or_name.as_ref();
+    match get_server_group_by_id(session, s).await {
+        Ok(value) => Ok(value),
+        Err(err) if err.kind() == ErrorKind::ResourceNotFound => {
+            get_server_group_by_name(session, s).await
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Get a server group by its ID.
+pub async fn get_server_group_by_id<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+) -> Result<ServerGroup> {
+    trace!("Get compute server group with ID {}", id.as_ref());
+    let maybe_version = server_group_api_version(session).await?;
+    let mut builder = session.get(COMPUTE, &["os-server-groups", id.as_ref()]);
+    if let Some(version) = maybe_version {
+        builder.set_api_version(version);
+    }
+    let root: ServerGroupRoot = builder.fetch().await?;
+    trace!("Received {:?}", root.server_group);
+    Ok(root.server_group)
+}
+
+/// Get a server group by its name.
+pub async fn get_server_group_by_name<S: AsRef<str>>(
+    session: &Session,
+    name: S,
+) -> Result<ServerGroup> {
+    trace!("Get compute server group with name {}", name.as_ref());
+    utils::one(
+        list_server_groups(session, &utils::Query::new())
+            .await?
+            .into_iter()
+            .filter(|item| item.name == name.as_ref()),
+        "Server group with given name or ID not found",
+        "Too many server groups found with given name",
+    )
+}
+
+/// Get usage of a project over a period of time.
+pub async fn get_tenant_usage<S: AsRef<str>>(
+    session: &Session,
+    project_id: S,
+    start: DateTime<FixedOffset>,
+    end: DateTime<FixedOffset>,
+) -> Result<TenantUsage> {
+    trace!(
+        "Get compute usage of project {} from {} to {}",
+        project_id.as_ref(),
+        start,
+        end
+    );
+    let root: TenantUsageRoot = session
+        .get(COMPUTE, &["os-simple-tenant-usage", project_id.as_ref()])
+        .query(&usage_period(start, end))
+        .fetch()
+        .await?;
+    trace!("Received {:?}", root.tenant_usage);
+    Ok(root.tenant_usage.unwrap_or_else(|| TenantUsage {
+        server_usages: Vec::new(),
+        start,
+        stop: end,
+        project_id: project_id.as_ref().into(),
+        total_hours: 0.0,
+        total_local_gb_usage: 0.0,
+        total_memory_mb_usage: 0.0,
+        total_vcpus_usage: 0.0,
+    }))
+}
+
+/// List availability zones, optionally with host details.
+pub async fn list_availability_zones(
+    session: &Session,
+    detailed: bool,
+) -> Result<Vec<AvailabilityZone>> {
+    trace!(
+        "Listing compute availability zones (detailed: {})",
+        detailed
+    );
+    let root: AvailabilityZonesRoot = if detailed {
+        session
+            .get_json(COMPUTE, &["os-availability-zone", "detail"])
+            .await?
+    } else {
+        session.get_json(COMPUTE, &["os-availability-zone"]).await?
+    };
+    trace!("Received availability zones: {:?}", root.availability_zones);
+    Ok(root.availability_zones)
+}
+
+/// List compute services.
+pub async fn list_compute_services<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<Vec<ComputeService>> {
+    trace!("Listing compute services with {:?}", query);
+    let maybe_version = service_api_version(session).await?;
+    let mut builder = session.get(COMPUTE, &["os-services"]).query(query);
+    if let Some(version) = maybe_version {
+        builder.set_api_version(version);
+    }
+    let root: ComputeServicesRoot = builder.fetch().await?;
+    trace!("Received compute services: {:?}", root.services);
+    Ok(root.services)
+}
+
 /// List flavors.
 pub async fn list_flavors<Q: Serialize + Sync + Debug>(
     session: &Session,
@@ -255,6 +1141,39 @@ pub async fn list_flavors_detail<Q: Serialize + Sync + Debug>(
     Ok(root.flavors)
 }
 
+/// List projects with access to a flavor.
+pub async fn list_flavor_access<S: AsRef<str>>(session: &Session, id: S) -> Result<Vec<String>> {
+    trace!("Listing access to flavor {}", id.as_ref());
+    let root: FlavorAccessRoot = session
+        .get_json(COMPUTE, &["flavors", id.as_ref(), "os-flavor-access"])
+        .await?;
+    let result = root
+        .flavor_access
+        .into_iter()
+        .map(|item| item.tenant_id)
+        .collect::<Vec<_>>();
+    trace!("Received projects with access to flavor: {:?}", result);
+    Ok(result)
+}
+
+/// List hypervisors with details.
+pub async fn list_hypervisors_detail<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<Vec<Hypervisor>> {
+    trace!("Listing compute hypervisors with {:?}", query);
+    let maybe_version = hypervisor_api_version(session).await?;
+    let mut builder = session
+        .get(COMPUTE, &["os-hypervisors", "detail"])
+        .query(query);
+    if let Some(version) = maybe_version {
+        builder.set_api_version(version);
+    }
+    let root: HypervisorsRoot = builder.fetch().await?;
+    trace!("Received hypervisors: {:?}", root.hypervisors);
+    Ok(root.hypervisors)
+}
+
 /// List key pairs.
 pub async fn list_keypairs<Q: Serialize + Sync + Debug>(
     session: &Session,
@@ -264,7 +1183,11 @@ pub async fn list_keypairs<Q: Serialize + Sync + Debug>(
     let maybe_version = session
         .pick_api_version(
             COMPUTE,
-            vec![API_VERSION_KEYPAIR_TYPE, API_VERSION_KEYPAIR_PAGINATION],
+            vec![
+                API_VERSION_KEYPAIR_TYPE,
+                API_VERSION_KEYPAIR_USER,
+                API_VERSION_KEYPAIR_PAGINATION,
+            ],
         )
         .await?;
     let mut builder = session.get(COMPUTE, &["os-keypairs"]).query(query);
@@ -281,30 +1204,54 @@ pub async fn list_keypairs<Q: Serialize + Sync + Debug>(
     Ok(result)
 }
 
+/// List server groups.
+pub async fn list_server_groups<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<Vec<ServerGroup>> {
+    trace!("Listing compute server groups with {:?}", query);
+    let maybe_version = server_group_api_version(session).await?;
+    let mut builder = session.get(COMPUTE, &["os-server-groups"]).query(query);
+    if let Some(version) = maybe_version {
+        builder.set_api_version(version);
+    }
+    let root: ServerGroupsRoot = builder.fetch().await?;
+    trace!("Received server groups: {:?}", root.server_groups);
+    Ok(root.server_groups)
+}
+
 /// List servers.
 pub async fn list_servers<Q: Serialize + Sync + Debug>(
     session: &Session,
     query: &Q,
+    api_version: Option<ApiVersion>,
 ) -> Result<Vec<IdAndName>> {
     trace!("Listing compute servers with {:?}", query);
-    let root: ServersRoot = session
-        .get(COMPUTE, &["servers"])
-        .query(query)
-        .fetch()
-        .await?;
+    let maybe_version = pinned_server_api_version(session, api_version).await?;
+    let mut builder = session.get(COMPUTE, &["servers"]).query(query);
+    if let Some(version) = maybe_version {
+        builder.set_api_version(version);
+    }
+    let root: ServersRoot = builder.fetch().await?;
     trace!("Received servers: {:?}", root.servers);
-    Ok(root.servers)
+    Ok(root
+        .servers
+        .into_iter()
+        .map(|item| IdAndName {
+            id: item.id,
+            name: item.name,
+        })
+        .collect())
 }
 
 /// List servers with details.
 pub async fn list_servers_detail<Q: Serialize + Sync + Debug>(
     session: &Session,
     query: &Q,
+    api_version: Option<ApiVersion>,
 ) -> Result<Vec<Server>> {
     trace!("Listing compute servers with {:?}", query);
-    let maybe_version = session
-        .pick_api_version(COMPUTE, Some(API_VERSION_SERVER_DESCRIPTION))
-        .await?;
+    let maybe_version = pinned_server_api_version(session, api_version).await?;
     let mut builder = session.get(COMPUTE, &["servers", "detail"]).query(query);
     if let Some(version) = maybe_version {
         builder.set_api_version(version);
@@ -314,6 +1261,184 @@ pub async fn list_servers_detail<Q: Serialize + Sync + Debug>(
     Ok(root.servers)
 }
 
+/// List in-progress migrations of a server.
+pub async fn list_server_migrations<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+) -> Result<Vec<ServerMigration>> {
+    require_api_version(session, API_VERSION_SERVER_MIGRATIONS, "Listing migrations").await?;
+    trace!("Listing migrations of server {}", id.as_ref());
+    let mut builder = session.get(COMPUTE, &["servers", id.as_ref(), "migrations"]);
+    builder.set_api_version(API_VERSION_SERVER_MIGRATIONS);
+    let root: ServerMigrationsRoot = builder.fetch().await?;
+    trace!("Received migrations: {:?}", root.migrations);
+    Ok(root.migrations)
+}
+
+/// List usage of all projects over a period of time.
+pub async fn list_tenant_usages(
+    session: &Session,
+    start: DateTime<FixedOffset>,
+    end: DateTime<FixedOffset>,
+    detailed: bool,
+) -> Result<Vec<TenantUsage>> {
+    trace!(
+        "Listing compute usage from {} to {} (detailed: {})",
+        start,
+        end,
+        detailed
+    );
+    let mut builder = session
+        .get(COMPUTE, &["os-simple-tenant-usage"])
+        .query(&usage_period(start, end));
+    if detailed {
+        builder = builder.query(&[("detailed", 1)]);
+    }
+    let root: TenantUsagesRoot = builder.fetch().await?;
+    trace!("Received compute usage: {:?}", root.tenant_usages);
+    Ok(root.tenant_usages)
+}
+
+/// List volumes attached to a server.
+pub async fn list_volume_attachments<S: AsRef<str>>(
+    session: &Session,
+    server_id: S,
+) -> Result<Vec<VolumeAttachment>> {
+    trace!("Listing volumes attached to server {}", server_id.as_ref());
+    let root: VolumeAttachmentsRoot = session
+        .get_json(
+            COMPUTE,
+            &["servers", server_id.as_ref(), "os-volume_attachments"],
+        )
+        .await?;
+    trace!("Received volume attachments: {:?}", root.volumeAttachments);
+    Ok(root.volumeAttachments)
+}
+
+/// Live-migrate a server, optionally to the given host.
+///
+/// The block migration mode is picked automatically by the cloud.
+pub async fn live_migrate_server<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    host: Option<String>,
+) -> Result<()> {
+    require_api_version(session, API_VERSION_LIVE_MIGRATE_AUTO, "Live migration").await?;
+    let action = LiveMigrateRoot {
+        live_migrate: LiveMigrate {
+            block_migration: "auto".into(),
+            host,
+        },
+    };
+    server_action_with_version(session, id, action, API_VERSION_LIVE_MIGRATE_AUTO).await
+}
+
+/// Cold-migrate a server, optionally to the given host.
+pub async fn migrate_server<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    host: Option<String>,
+) -> Result<()> {
+    match host {
+        Some(host) => {
+            require_api_version(session, API_VERSION_MIGRATE_HOST, "Migration to a host").await?;
+            let action = MigrateRoot {
+                migrate: Some(Migrate { host }),
+            };
+            server_action_with_version(session, id, action, API_VERSION_MIGRATE_HOST).await
+        }
+        None => server_action_with_args(session, id, MigrateRoot { migrate: None }).await,
+    }
+}
+
+/// Revoke access to a flavor from a project.
+pub async fn remove_flavor_access<S1, S2>(session: &Session, id: S1, project_id: S2) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: Into<String>,
+{
+    let action = FlavorAccessAction::Remove(FlavorAccessTenant {
+        tenant: project_id.into(),
+    });
+    flavor_access_action(session, id, action).await
+}
+
+/// Replace all metadata of a server.
+pub async fn replace_server_metadata<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    metadata: HashMap<String, String>,
+) -> Result<HashMap<String, String>> {
+    debug!(
+        "Replacing metadata of server {} with {:?}",
+        id.as_ref(),
+        metadata
+    );
+    let body = MetadataRoot { metadata };
+    let root: MetadataRoot = session
+        .put(COMPUTE, &["servers", id.as_ref(), "metadata"])
+        .json(&body)
+        .fetch()
+        .await?;
+    debug!(
+        "New metadata of server {}: {:?}",
+        id.as_ref(),
+        root.metadata
+    );
+    Ok(root.metadata)
+}
+
+/// Replace all tags of a server.
+pub async fn replace_server_tags<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    tags: Vec<String>,
+) -> Result<Vec<String>> {
+    require_server_tags(session).await?;
+    debug!("Replacing tags of server {} with {:?}", id.as_ref(), tags);
+    let body = TagsRoot { tags };
+    let root: TagsRoot = session
+        .put(COMPUTE, &["servers", id.as_ref(), "tags"])
+        .api_version(API_VERSION_SERVER_TAGS)
+        .json(&body)
+        .fetch()
+        .await?;
+    debug!("New tags of server {}: {:?}", id.as_ref(), root.tags);
+    Ok(root.tags)
+}
+
+/// Make sure that filtering hypervisors by a host name pattern is supported.
+pub async fn require_hypervisor_hostname_pattern(session: &Session) -> Result<()> {
+    require_api_version(
+        session,
+        API_VERSION_SERVICE_UUID,
+        "Filtering hypervisors by host name",
+    )
+    .await
+}
+
+/// Make sure that server tags are supported.
+pub async fn require_server_tags(session: &Session) -> Result<()> {
+    require_api_version(session, API_VERSION_SERVER_TAGS, "Server tags").await
+}
+
+/// Make sure that server tag filters are supported, including by the pinned API version.
+pub async fn require_server_tag_filters(
+    session: &Session,
+    pinned: Option<ApiVersion>,
+) -> Result<()> {
+    match pinned {
+        Some(version) if version < API_VERSION_SERVER_TAGS => Err(Error::new(
+            ErrorKind::IncompatibleApiVersion,
+            format!(
+                "Server tag filters require compute API version {}, the pinned version is {}",
+                API_VERSION_SERVER_TAGS, version
+            ),
+        )),
+        _ => require_server_tags(session).await,
+    }
+}
+
 /// Run an action on a server.
 pub async fn server_action_with_args<S1, Q>(session: &Session, id: S1, action: Q) -> Result<()>
 where
@@ -330,6 +1455,67 @@ where
     Ok(())
 }
 
+/// Set extra specs of a flavor.
+///
+/// Existing extra specs with other keys are not affected.
+pub async fn set_flavor_extra_specs<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    extra_specs: HashMap<String, String>,
+) -> Result<HashMap<String, String>> {
+    debug!(
+        "Setting extra specs {:?} on flavor {}",
+        extra_specs,
+        id.as_ref()
+    );
+    let body = ExtraSpecsRoot { extra_specs };
+    let root: ExtraSpecsRoot = session
+        .post(COMPUTE, &["flavors", id.as_ref(), "os-extra_specs"])
+        .json(&body)
+        .fetch()
+        .await?;
+    debug!(
+        "Set extra specs {:?} on flavor {}",
+        root.extra_specs,
+        id.as_ref()
+    );
+    Ok(root.extra_specs)
+}
+
+/// Set a server metadata item.
+pub async fn set_server_metadata_item<S1, S2, S3>(
+    session: &Session,
+    id: S1,
+    key: S2,
+    value: S3,
+) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: Into<String>,
+    S3: Into<String>,
+{
+    let key = key.into();
+    let mut meta = HashMap::with_capacity(1);
+    let _ = meta.insert(key.clone(), value.into());
+    debug!("Setting metadata {:?} on server {}", meta, id.as_ref());
+    let body = MetadataItemRoot { meta };
+    let _ = session
+        .put(COMPUTE, &["servers", id.as_ref(), "metadata", &key])
+        .json(&body)
+        .send()
+        .await?;
+    debug!("Set metadata item {} on server {}", key, id.as_ref());
+    Ok(())
+}
+
+/// Whether hypervisor pagination is supported.
+#[inline]
+pub async fn supports_hypervisor_pagination(session: &Session) -> Result<bool> {
+    session
+        .supports_api_version(COMPUTE, API_VERSION_HYPERVISOR_PAGINATION)
+        .await
+}
+
 /// Whether key pair pagination is supported.
 #[inline]
 pub async fn supports_keypair_pagination(session: &Session) -> Result<bool> {
@@ -337,3 +1523,81 @@ pub async fn supports_keypair_pagination(session: &Session) -> Result<bool> {
         .supports_api_version(COMPUTE, API_VERSION_KEYPAIR_PAGINATION)
         .await
 }
+
+pub async fn supports_keypair_users(session: &Session) -> Result<bool> {
+    session
+        .supports_api_version(COMPUTE, API_VERSION_KEYPAIR_USER)
+        .await
+}
+
+/// Update a compute service.
+pub async fn update_compute_service<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    update: ComputeServiceUpdate,
+) -> Result<ComputeService> {
+    require_api_version(
+        session,
+        API_VERSION_SERVICE_UUID,
+        "Updating compute services",
+    )
+    .await?;
+    debug!("Updating compute service {} with {:?}", id.as_ref(), update);
+    let mut builder = session
+        .put(COMPUTE, &["os-services", id.as_ref()])
+        .json(&update);
+    builder.set_api_version(API_VERSION_SERVICE_UUID);
+    let root: ComputeServiceRoot = builder.fetch().await?;
+    debug!("Updated compute service {:?}", root.service);
+    Ok(root.service)
+}
+
+/// Update compute quotas of a project.
+pub async fn update_quota_set<S: AsRef<str>>(
+    session: &Session,
+    project_id: S,
+    update: QuotaSetUpdate,
+) -> Result<QuotaSet> {
+    debug!(
+        "Updating compute quotas of project {} with {:?}",
+        project_id.as_ref(),
+        update
+    );
+    let body = QuotaSetUpdateRoot { quota_set: update };
+    let root: QuotaSetRoot = session
+        .put(COMPUTE, &["os-quota-sets", project_id.as_ref()])
+        .json(&body)
+        .fetch()
+        .await?;
+    debug!("Updated compute quotas {:?}", root.quota_set);
+    Ok(root.quota_set)
+}
+
+/// Update a server.
+pub async fn update_server<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    update: ServerUpdate,
+) -> Result<()> {
+    let version = if update.description.is_some() {
+        require_api_version(
+            session,
+            API_VERSION_SERVER_DESCRIPTION,
+            "Server description",
+        )
+        .await?;
+        Some(API_VERSION_SERVER_DESCRIPTION)
+    } else {
+        None
+    };
+
+    debug!("Updating server {} with {:?}", id.as_ref(), update);
+    let body = ServerUpdateRoot { server: update };
+    let mut builder = session.put(COMPUTE, &["servers", id.as_ref()]).json(&body);
+    if let Some(version) = version {
+        builder.set_api_version(version);
+    }
+    let _ = builder.send().await?;
+    debug!("Updated server {}", id.as_ref());
+    Ok(())
+}
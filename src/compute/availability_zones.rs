@@ -0,0 +1,99 @@
+// Copyright 2026 The rust-openstack Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Availability zones via Compute API.
+
+use async_trait::async_trait;
+use futures::stream::{Stream, TryStreamExt};
+
+use super::super::common::{ResourceIterator, ResourceQuery};
+use super::super::session::Session;
+use super::super::Result;
+use super::{api, protocol};
+
+/// A query to availability zone list.
+#[derive(Clone, Debug)]
+pub struct AvailabilityZoneQuery {
+    session: Session,
+    with_hosts: bool,
+}
+
+impl AvailabilityZoneQuery {
+    pub(crate) fn new(session: Session) -> AvailabilityZoneQuery {
+        AvailabilityZoneQuery {
+            session,
+            with_hosts: false,
+        }
+    }
+
+    /// Include hosts and their services in the results (admin only).
+    pub fn with_hosts(mut self) -> Self {
+        self.with_hosts = true;
+        self
+    }
+
+    /// Convert this query into a stream executing the request.
+    ///
+    /// Returns a `TryStream`, which is a stream with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_stream(self) -> impl Stream<Item = Result<protocol::AvailabilityZone>> {
+        debug!(
+            "Fetching availability zones (with hosts: {})",
+            self.with_hosts
+        );
+        ResourceIterator::new(self).into_stream()
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_stream().try_collect().await`.
+    pub async fn all(self) -> Result<Vec<protocol::AvailabilityZone>> {
+        self.into_stream().try_collect().await
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub async fn one(self) -> Result<protocol::AvailabilityZone> {
+        debug!("Fetching one availability zone");
+        ResourceIterator::new(self).one().await
+    }
+}
+
+#[async_trait]
+impl ResourceQuery for AvailabilityZoneQuery {
+    type Item = protocol::AvailabilityZone;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    async fn can_paginate(&self) -> Result<bool> {
+        // Availability zones are never paginated.
+        Ok(false)
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.name.clone()
+    }
+
+    async fn fetch_chunk(
+        &self,
+        _limit: Option<usize>,
+        _marker: Option<String>,
+    ) -> Result<Vec<Self::Item>> {
+        api::list_availability_zones(&self.session, self.with_hosts).await
+    }
+}
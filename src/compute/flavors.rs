@@ -20,7 +20,7 @@ use async_trait::async_trait;
 use futures::stream::{Stream, TryStreamExt};
 use osauth::common::IdAndName;
 
-use super::super::common::{FlavorRef, Refresh, ResourceIterator, ResourceQuery};
+use super::super::common::{FlavorRef, ProjectRef, Refresh, ResourceIterator, ResourceQuery};
 use super::super::session::Session;
 use super::super::utils::Query;
 use super::super::Result;
@@ -55,6 +55,13 @@ pub struct DetailedFlavorQuery {
     inner: FlavorQuery,
 }
 
+/// A request to create a flavor.
+#[derive(Clone, Debug)]
+pub struct NewFlavor {
+    session: Session,
+    inner: protocol::FlavorCreate,
+}
+
 impl Flavor {
     /// Create a flavor object.
     pub(crate) async fn new(session: Session, mut inner: protocol::Flavor) -> Result<Flavor> {
@@ -134,6 +141,68 @@ impl Flavor {
     pub fn vcpu_count(&self) -> u32 {
         self.inner.vcpus
     }
+
+    /// Grant a project access to this flavor.
+    ///
+    /// Only makes sense for private flavors.
+    pub async fn add_access<P>(&self, project: P) -> Result<()>
+    where
+        P: Into<ProjectRef>,
+    {
+        let project = project.into().into_verified(&self.session).await?;
+        api::add_flavor_access(&self.session, &self.inner.id, project).await
+    }
+
+    /// Delete the flavor.
+    pub async fn delete(self) -> Result<()> {
+        api::delete_flavor(&self.session, &self.inner.id).await
+    }
+
+    /// List projects that have access to this private flavor.
+    pub async fn list_access(&self) -> Result<Vec<ProjectRef>> {
+        Ok(api::list_flavor_access(&self.session, &self.inner.id)
+            .await?
+            .into_iter()
+            .map(ProjectRef::new_verified)
+            .collect())
+    }
+
+    /// Revoke access to this flavor from a project.
+    pub async fn remove_access<P>(&self, project: P) -> Result<()>
+    where
+        P: Into<ProjectRef>,
+    {
+        let project = project.into().into_verified(&self.session).await?;
+        api::remove_flavor_access(&self.session, &self.inner.id, project).await
+    }
+
+    /// Set an extra spec of the flavor.
+    pub async fn set_extra_spec<S1, S2>(&mut self, key: S1, value: S2) -> Result<()>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let mut extra_specs = HashMap::with_capacity(1);
+        let _ = extra_specs.insert(key.into(), value.into());
+        self.set_extra_specs(extra_specs).await
+    }
+
+    /// Set several extra specs of the flavor.
+    ///
+    /// Existing extra specs with other keys are not affected.
+    pub async fn set_extra_specs(&mut self, extra_specs: HashMap<String, String>) -> Result<()> {
+        let updated =
+            api::set_flavor_extra_specs(&self.session, &self.inner.id, extra_specs).await?;
+        self.extra_specs.extend(updated);
+        Ok(())
+    }
+
+    /// Remove an extra spec from the flavor.
+    pub async fn unset_extra_spec<S: AsRef<str>>(&mut self, key: S) -> Result<()> {
+        api::delete_flavor_extra_spec(&self.session, &self.inner.id, key.as_ref()).await?;
+        let _ = self.extra_specs.remove(key.as_ref());
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -189,6 +258,21 @@ impl FlavorQuery {
         self
     }
 
+    query_filter! {
+        #[doc = "Filter by minimum root disk size in GiB."]
+        set_min_disk, with_min_disk -> minDisk: u64
+    }
+
+    query_filter! {
+        #[doc = "Filter by minimum RAM size in MiB."]
+        set_min_ram, with_min_ram -> minRam: u64
+    }
+
+    query_filter! {
+        #[doc = "Filter by whether the flavor is public (admin only)."]
+        set_public, with_public -> is_public: bool
+    }
+
     /// Convert this query into a detailed query.
     pub fn detailed(self) -> DetailedFlavorQuery {
         DetailedFlavorQuery { inner: self }
@@ -306,6 +390,83 @@ impl ResourceQuery for DetailedFlavorQuery {
     }
 }
 
+impl NewFlavor {
+    /// Start creating a flavor.
+    pub(crate) fn new(
+        session: Session,
+        name: String,
+        ram_size: u64,
+        vcpu_count: u32,
+        root_size: u64,
+    ) -> NewFlavor {
+        NewFlavor {
+            session,
+            inner: protocol::FlavorCreate {
+                disk: root_size,
+                ephemeral: None,
+                id: None,
+                is_public: None,
+                name,
+                ram: ram_size,
+                rxtx_factor: None,
+                swap: None,
+                vcpus: vcpu_count,
+            },
+        }
+    }
+
+    /// Request creation of the flavor.
+    pub async fn create(self) -> Result<Flavor> {
+        let inner = api::create_flavor(&self.session, self.inner).await?;
+        Flavor::new(self.session, inner).await
+    }
+
+    creation_inner_field! {
+        #[doc = "Set ephemeral disk size in GiB."]
+        set_ephemeral_size, with_ephemeral_size -> ephemeral: optional u64
+    }
+
+    creation_inner_field! {
+        #[doc = "Set ID of the flavor (generated by default)."]
+        set_id, with_id -> id: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set whether the flavor is public (defaults to true)."]
+        set_public, with_public -> is_public: optional bool
+    }
+
+    creation_inner_field! {
+        #[doc = "Set name of the flavor."]
+        set_name, with_name -> name: String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set RAM size in MiB."]
+        set_ram_size, with_ram_size -> ram: u64
+    }
+
+    creation_inner_field! {
+        #[doc = "Set root disk size in GiB."]
+        set_root_size, with_root_size -> disk: u64
+    }
+
+    creation_inner_field! {
+        #[doc = "Set receive/transmit factor."]
+        set_rxtx_factor, with_rxtx_factor -> rxtx_factor: optional f32
+    }
+
+    creation_inner_field! {
+        #[doc = "Set swap size in MiB."]
+        set_swap_size, with_swap_size -> swap: optional u64
+    }
+
+    creation_inner_field! {
+        #[doc = "Set VCPU count."]
+        set_vcpu_count, with_vcpu_count -> vcpus: u32
+    }
+}
+
 impl From<Flavor> for FlavorRef {
     fn from(value: Flavor) -> FlavorRef {
         FlavorRef::new_verified(value.inner.id)
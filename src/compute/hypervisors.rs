@@ -0,0 +1,287 @@
+// Copyright 2026 The rust-openstack Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Hypervisor information via Compute API.
+
+use std::net::IpAddr;
+
+use async_trait::async_trait;
+use futures::stream::{Stream, TryStreamExt};
+
+use super::super::common::{Refresh, ResourceIterator, ResourceQuery};
+use super::super::session::Session;
+use super::super::utils::Query;
+use super::super::Result;
+use super::{api, protocol};
+
+/// Structure representing a hypervisor.
+///
+/// Only available to administrators.
+#[derive(Clone, Debug)]
+pub struct Hypervisor {
+    session: Session,
+    inner: protocol::Hypervisor,
+}
+
+/// A query to hypervisor list.
+#[derive(Clone, Debug)]
+pub struct HypervisorQuery {
+    session: Session,
+    query: Query,
+    can_paginate: bool,
+    hostname_pattern: bool,
+}
+
+impl Hypervisor {
+    /// Create a hypervisor object.
+    pub(crate) fn from_inner(session: Session, inner: protocol::Hypervisor) -> Hypervisor {
+        Hypervisor { session, inner }
+    }
+
+    /// Load a Hypervisor object.
+    pub(crate) async fn new<Id: AsRef<str>>(session: Session, id: Id) -> Result<Hypervisor> {
+        let inner = api::get_hypervisor(&session, id).await?;
+        Ok(Hypervisor::from_inner(session, inner))
+    }
+
+    transparent_property! {
+        #[doc = "Number of tasks currently running (if known)."]
+        current_workload: Option<u64>
+    }
+
+    transparent_property! {
+        #[doc = "Available disk space in GiB, accounting for overcommit (if known)."]
+        disk_available_least: Option<i64>
+    }
+
+    transparent_property! {
+        #[doc = "Free disk space in GiB (if known)."]
+        free_disk_gb: Option<i64>
+    }
+
+    transparent_property! {
+        #[doc = "Free RAM in MiB (if known)."]
+        free_ram_mb: Option<i64>
+    }
+
+    transparent_property! {
+        #[doc = "IP address of the hypervisor host (if known)."]
+        host_ip: Option<IpAddr>
+    }
+
+    transparent_property! {
+        #[doc = "Host name of the hypervisor."]
+        hypervisor_hostname: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Hypervisor type, e.g. `QEMU` (if known)."]
+        hypervisor_type: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "Hypervisor version (if known)."]
+        hypervisor_version: Option<u64>
+    }
+
+    transparent_property! {
+        #[doc = "Unique ID (an integer before compute API version 2.53)."]
+        id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Total local disk space in GiB (if known)."]
+        local_gb: Option<u64>
+    }
+
+    transparent_property! {
+        #[doc = "Used local disk space in GiB (if known)."]
+        local_gb_used: Option<u64>
+    }
+
+    transparent_property! {
+        #[doc = "Total RAM in MiB (if known)."]
+        memory_mb: Option<u64>
+    }
+
+    transparent_property! {
+        #[doc = "Used RAM in MiB (if known)."]
+        memory_mb_used: Option<u64>
+    }
+
+    transparent_property! {
+        #[doc = "Number of running servers (if known)."]
+        running_vms: Option<u64>
+    }
+
+    /// Host of the compute service running this hypervisor (if known).
+    #[inline]
+    pub fn service_host(&self) -> Option<&String> {
+        self.inner.service.as_ref().map(|service| &service.host)
+    }
+
+    /// ID of the compute service running this hypervisor (if known).
+    #[inline]
+    pub fn service_id(&self) -> Option<&String> {
+        self.inner.service.as_ref().map(|service| &service.id)
+    }
+
+    transparent_property! {
+        #[doc = "Whether the hypervisor is up or down."]
+        state: clone protocol::ComputeServiceState
+    }
+
+    transparent_property! {
+        #[doc = "Whether the hypervisor is enabled or disabled."]
+        status: clone protocol::ComputeServiceStatus
+    }
+
+    transparent_property! {
+        #[doc = "Total number of virtual CPUs (if known)."]
+        vcpus: Option<u64>
+    }
+
+    transparent_property! {
+        #[doc = "Number of used virtual CPUs (if known)."]
+        vcpus_used: Option<u64>
+    }
+}
+
+#[async_trait]
+impl Refresh for Hypervisor {
+    /// Refresh the hypervisor.
+    async fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_hypervisor(&self.session, &self.inner.id).await?;
+        Ok(())
+    }
+}
+
+impl HypervisorQuery {
+    pub(crate) fn new(session: Session) -> HypervisorQuery {
+        HypervisorQuery {
+            session,
+            query: Query::new(),
+            can_paginate: true,
+            hostname_pattern: false,
+        }
+    }
+
+    /// Add marker to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_marker<T: Into<String>>(mut self, marker: T) -> Self {
+        self.can_paginate = false;
+        self.query.push_str("marker", marker);
+        self
+    }
+
+    /// Add limit to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.can_paginate = false;
+        self.query.push("limit", limit);
+        self
+    }
+
+    /// Filter by a pattern matching the hypervisor host name.
+    ///
+    /// Fails with `IncompatibleApiVersion` if the cloud does not support compute API
+    /// version 2.53. Using this disables pagination since Nova does not support it here.
+    pub fn with_hostname_pattern<T: Into<String>>(mut self, pattern: T) -> Self {
+        self.can_paginate = false;
+        self.hostname_pattern = true;
+        self.query.push_str("hypervisor_hostname_pattern", pattern);
+        self
+    }
+
+    /// Convert this query into a stream executing the request.
+    ///
+    /// Returns a `TryStream`, which is a stream with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_stream(self) -> impl Stream<Item = Result<Hypervisor>> {
+        debug!("Fetching hypervisors with {:?}", self.query);
+        ResourceIterator::new(self).into_stream()
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_stream().try_collect().await`.
+    pub async fn all(self) -> Result<Vec<Hypervisor>> {
+        self.into_stream().try_collect().await
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub async fn one(mut self) -> Result<Hypervisor> {
+        debug!("Fetching one hypervisor with {:?}", self.query);
+        if self.can_paginate {
+            // We need only one result. We fetch maximum two to be able
+            // to check if the query yieled more than one result.
+            self.query.push("limit", 2);
+        }
+
+        ResourceIterator::new(self).one().await
+    }
+}
+
+impl protocol::HypervisorStatistics {
+    /// Load summary statistics over all hypervisors.
+    pub(crate) async fn load(session: &Session) -> Result<protocol::HypervisorStatistics> {
+        api::get_hypervisor_statistics(session).await
+    }
+}
+
+#[async_trait]
+impl ResourceQuery for HypervisorQuery {
+    type Item = Hypervisor;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    async fn can_paginate(&self) -> Result<bool> {
+        if self.can_paginate {
+            api::supports_hypervisor_pagination(&self.session).await
+        } else {
+            Ok(false)
+        }
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.id().clone()
+    }
+
+    async fn validate(&mut self) -> Result<()> {
+        if self.hostname_pattern {
+            api::require_hypervisor_hostname_pattern(&self.session).await?;
+        }
+        Ok(())
+    }
+
+    async fn fetch_chunk(
+        &self,
+        limit: Option<usize>,
+        marker: Option<String>,
+    ) -> Result<Vec<Self::Item>> {
+        let query = self.query.with_marker_and_limit(limit, marker);
+        Ok(api::list_hypervisors_detail(&self.session, &query)
+            .await?
+            .into_iter()
+            .map(|item| Hypervisor::from_inner(self.session.clone(), item))
+            .collect())
+    }
+}
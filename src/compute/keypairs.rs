@@ -17,11 +17,11 @@
 use async_trait::async_trait;
 use futures::stream::{Stream, TryStreamExt};
 
-use super::super::common::{KeyPairRef, Refresh, ResourceIterator, ResourceQuery};
+use super::super::common::{KeyPairRef, Refresh, ResourceIterator, ResourceQuery, UserRef};
 use super::super::session::Session;
 use super::super::utils::Query;
 use super::super::{Error, ErrorKind, Result};
-use super::{api, protocol};
+use super::{api, protocol, PublicKey};
 
 /// Structure representing a key pair.
 #[derive(Clone, Debug)]
@@ -36,6 +36,7 @@ pub struct KeyPairQuery {
     session: Session,
     query: Query,
     can_paginate: bool,
+    user: Option<UserRef>,
 }
 
 /// A request to create a key pair.
@@ -48,13 +49,30 @@ pub struct NewKeyPair {
 impl KeyPair {
     /// Load a KeyPair object.
     pub(crate) async fn new<Id: AsRef<str>>(session: Session, id: Id) -> Result<KeyPair> {
-        let inner = api::get_keypair(&session, id).await?;
+        let inner = api::get_keypair(&session, id, None).await?;
+        Ok(KeyPair { session, inner })
+    }
+
+    /// Load a KeyPair object of the given user.
+    pub(crate) async fn new_for_user<Id, U>(session: Session, id: Id, user: U) -> Result<KeyPair>
+    where
+        Id: AsRef<str>,
+        U: Into<UserRef>,
+    {
+        let user_id: String = user.into().into_verified(&session).await?.into();
+        let mut inner = api::get_keypair(&session, id, Some(&user_id)).await?;
+        let _ = inner.user_id.get_or_insert(user_id);
         Ok(KeyPair { session, inner })
     }
 
     /// Delete the key pair.
     pub async fn delete(self) -> Result<()> {
-        api::delete_keypair(&self.session, &self.inner.name).await
+        api::delete_keypair(
+            &self.session,
+            &self.inner.name,
+            self.inner.user_id.as_deref(),
+        )
+        .await
     }
 
     transparent_property! {
@@ -64,7 +82,7 @@ impl KeyPair {
 
     transparent_property! {
         #[doc = "Key pair type, if available."]
-        key_type: Option<protocol::KeyPairType>
+        key_type: clone Option<protocol::KeyPairType>
     }
 
     transparent_property! {
@@ -76,13 +94,22 @@ impl KeyPair {
         #[doc = "Public key."]
         public_key: ref String
     }
+
+    transparent_property! {
+        #[doc = "ID of the user owning the key pair (if known)."]
+        user_id: ref Option<String>
+    }
 }
 
 #[async_trait]
 impl Refresh for KeyPair {
     /// Refresh the keypair.
     async fn refresh(&mut self) -> Result<()> {
-        self.inner = api::get_keypair(&self.session, &self.inner.name).await?;
+        let user_id = self.inner.user_id.clone();
+        self.inner = api::get_keypair(&self.session, &self.inner.name, user_id.as_deref()).await?;
+        if self.inner.user_id.is_none() {
+            self.inner.user_id = user_id;
+        }
         Ok(())
     }
 }
@@ -93,9 +120,26 @@ impl KeyPairQuery {
             session,
             query: Query::new(),
             can_paginate: true,
+            user: None,
         }
     }
 
+    /// List key pairs of the given user instead of the current one (admin only).
+    ///
+    /// Requires compute API version 2.10.
+    pub fn set_user<U: Into<UserRef>>(&mut self, value: U) {
+        self.user = Some(value.into());
+    }
+
+    /// List key pairs of the given user instead of the current one (admin only).
+    ///
+    /// Requires compute API version 2.10.
+    #[inline]
+    pub fn with_user<U: Into<UserRef>>(mut self, value: U) -> Self {
+        self.set_user(value);
+        self
+    }
+
     /// Add marker to the request.
     ///
     /// Using this disables automatic pagination.
@@ -159,16 +203,41 @@ impl NewKeyPair {
 
     /// Request creation of a key pair.
     ///
-    /// This call fails immediately if no public_key is provided.
+    /// This call fails immediately if no public_key is provided or if an SSH public key is
+    /// malformed. The fingerprint reported by the cloud is compared with the one computed
+    /// locally, the key pair is deleted and `InvalidResponse` is returned on mismatch.
     pub async fn create(self) -> Result<KeyPair> {
-        if self.inner.public_key.is_none() {
-            return Err(Error::new(
-                ErrorKind::InvalidInput,
-                "Public key contents is required",
-            ));
+        let expected_fingerprint = match self.inner.public_key {
+            Some(_) if self.inner.key_type == Some(protocol::KeyPairType::X509) => None,
+            Some(ref public_key) => Some(PublicKey::parse(public_key)?.fingerprint()),
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "Public key contents is required",
+                ))
+            }
         };
 
         let keypair = api::create_keypair(&self.session, self.inner).await?;
+        if let Some(expected) = expected_fingerprint {
+            if keypair.fingerprint != expected {
+                warn!(
+                    "Fingerprint {} of key pair {} does not match expected {}, deleting it",
+                    keypair.fingerprint, keypair.name, expected
+                );
+                if let Err(err) = api::delete_keypair(&self.session, &keypair.name, None).await {
+                    warn!("Failed to delete key pair {}: {}", keypair.name, err);
+                }
+                return Err(Error::new(
+                    ErrorKind::InvalidResponse,
+                    format!(
+                        "Fingerprint {} of key pair {} does not match expected {}",
+                        keypair.fingerprint, keypair.name, expected
+                    ),
+                ));
+            }
+        }
+
         Ok(KeyPair {
             session: self.session,
             inner: keypair,
@@ -208,9 +277,26 @@ impl NewKeyPair {
     }
 
     creation_inner_field! {
-        #[doc = "Set name of the key pair."]
+        #[doc = "Set public key of the key pair."]
         set_public_key, with_public_key -> public_key: optional String
     }
+
+    /// Set a parsed OpenSSH public key of the key pair.
+    ///
+    /// Also sets the key pair type, which requires compute API version 2.2.
+    pub fn set_ssh_public_key(&mut self, value: PublicKey) {
+        self.inner.key_type = Some(value.key_type());
+        self.inner.public_key = Some(value.to_string());
+    }
+
+    /// Set a parsed OpenSSH public key of the key pair.
+    ///
+    /// Also sets the key pair type, which requires compute API version 2.2.
+    #[inline]
+    pub fn with_ssh_public_key(mut self, value: PublicKey) -> Self {
+        self.set_ssh_public_key(value);
+        self
+    }
 }
 
 #[async_trait]
@@ -237,15 +323,36 @@ impl ResourceQuery for KeyPairQuery {
         marker: Option<String>,
     ) -> Result<Vec<Self::Item>> {
         let query = self.query.with_marker_and_limit(limit, marker);
+        let user_id = self.user.as_ref().map(|user| user.to_string());
         Ok(api::list_keypairs(&self.session, &query)
             .await?
             .into_iter()
-            .map(|item| KeyPair {
-                session: self.session.clone(),
-                inner: item,
+            .map(|mut item| {
+                if item.user_id.is_none() {
+                    item.user_id = user_id.clone();
+                }
+                KeyPair {
+                    session: self.session.clone(),
+                    inner: item,
+                }
             })
             .collect())
     }
+
+    async fn validate(&mut self) -> Result<()> {
+        if let Some(user) = self.user.take() {
+            if !api::supports_keypair_users(&self.session).await? {
+                return Err(Error::new(
+                    ErrorKind::IncompatibleApiVersion,
+                    "Listing key pairs of other users requires compute API version 2.10",
+                ));
+            }
+            let user = user.into_verified(&self.session).await?;
+            self.query.push_str("user_id", user.to_string());
+            self.user = Some(user);
+        }
+        Ok(())
+    }
 }
 
 impl From<KeyPair> for KeyPairRef {
@@ -261,7 +368,33 @@ impl KeyPairRef {
         Ok(if self.verified {
             self
         } else {
-            KeyPairRef::new_verified(api::get_keypair(session, &self.value).await?.name)
+            KeyPairRef::new_verified(api::get_keypair(session, &self.value, None).await?.name)
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use osauth::{NoAuth, Session};
+
+    use super::super::protocol;
+    use super::KeyPair;
+    use crate::Refresh;
+
+    #[tokio::test]
+    async fn test_refresh_failure_keeps_user() {
+        let session = Session::new(NoAuth::new("http://127.0.0.1:9").unwrap())
+            .await
+            .unwrap();
+        let inner: protocol::KeyPair = serde_json::from_value(serde_json::json!({
+            "fingerprint": "aa:bb",
+            "name": "key",
+            "public_key": "ssh-ed25519 AAAA",
+            "user_id": "other-user"
+        }))
+        .unwrap();
+        let mut keypair = KeyPair { session, inner };
+        assert!(keypair.refresh().await.is_err());
+        assert_eq!(keypair.user_id().as_deref(), Some("other-user"));
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright 2026 The rust-openstack Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Server migrations via Compute API.
+
+use chrono::{DateTime, FixedOffset};
+
+use super::super::session::Session;
+use super::super::Result;
+use super::{api, protocol};
+
+/// An in-progress live migration of a server.
+///
+/// Only available to administrators.
+#[derive(Clone, Debug)]
+pub struct ServerMigration {
+    session: Session,
+    inner: protocol::ServerMigration,
+}
+
+impl ServerMigration {
+    /// Create a migration object.
+    pub(crate) fn new(session: Session, inner: protocol::ServerMigration) -> ServerMigration {
+        ServerMigration { session, inner }
+    }
+
+    transparent_property! {
+        #[doc = "Creation date and time."]
+        created_at: DateTime<FixedOffset>
+    }
+
+    transparent_property! {
+        #[doc = "Destination compute host."]
+        dest_compute: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "Destination host address."]
+        dest_host: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "Destination compute node."]
+        dest_node: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "Amount of disk data transferred so far (in bytes)."]
+        disk_processed_bytes: Option<u64>
+    }
+
+    transparent_property! {
+        #[doc = "Amount of disk data left to transfer (in bytes)."]
+        disk_remaining_bytes: Option<u64>
+    }
+
+    transparent_property! {
+        #[doc = "Total amount of disk data to transfer (in bytes)."]
+        disk_total_bytes: Option<u64>
+    }
+
+    transparent_property! {
+        #[doc = "Migration ID."]
+        id: u64
+    }
+
+    transparent_property! {
+        #[doc = "Amount of memory transferred so far (in bytes)."]
+        memory_processed_bytes: Option<u64>
+    }
+
+    transparent_property! {
+        #[doc = "Amount of memory left to transfer (in bytes)."]
+        memory_remaining_bytes: Option<u64>
+    }
+
+    transparent_property! {
+        #[doc = "Total amount of memory to transfer (in bytes)."]
+        memory_total_bytes: Option<u64>
+    }
+
+    /// ID of the migrated server.
+    #[inline]
+    pub fn server_id(&self) -> &String {
+        &self.inner.server_uuid
+    }
+
+    transparent_property! {
+        #[doc = "Source compute host."]
+        source_compute: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "Source compute node."]
+        source_node: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "Migration status."]
+        status: clone protocol::ServerMigrationStatus
+    }
+
+    transparent_property! {
+        #[doc = "Last update date and time (if any)."]
+        updated_at: Option<DateTime<FixedOffset>>
+    }
+
+    /// Abort the migration.
+    ///
+    /// Requires compute API version 2.24.
+    pub async fn abort(&self) -> Result<()> {
+        api::abort_server_migration(&self.session, &self.inner.server_uuid, self.inner.id).await
+    }
+
+    /// Force the migration to complete, e.g. by pausing the server.
+    ///
+    /// Requires compute API version 2.22.
+    pub async fn force_complete(&self) -> Result<()> {
+        api::force_complete_server_migration(&self.session, &self.inner.server_uuid, self.inner.id)
+            .await
+    }
+}
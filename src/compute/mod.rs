@@ -15,20 +15,42 @@
 //! Compute API implementation bits.
 
 mod api;
+mod availability_zones;
 mod block_device_mapping;
 mod flavors;
+mod hypervisors;
 mod keypairs;
+mod migrations;
 mod protocol;
+mod public_keys;
+mod quotas;
+mod server_groups;
 mod servers;
+mod services;
+mod user_data;
+mod validation;
 
+pub use self::availability_zones::AvailabilityZoneQuery;
 pub use self::block_device_mapping::{BlockDevice, BlockDeviceDestinationType, BlockDeviceSource};
-pub use self::flavors::{DetailedFlavorQuery, Flavor, FlavorQuery, FlavorSummary};
+pub use self::flavors::{DetailedFlavorQuery, Flavor, FlavorQuery, FlavorSummary, NewFlavor};
+pub use self::hypervisors::{Hypervisor, HypervisorQuery};
 pub use self::keypairs::{KeyPair, KeyPairQuery, NewKeyPair};
+pub use self::migrations::ServerMigration;
 pub use self::protocol::{
-    AddressType, KeyPairType, RebootType, ServerAddress, ServerFlavor, ServerPowerState,
-    ServerSortKey, ServerStatus,
+    AddressType, AvailabilityZone, AvailabilityZoneService, AvailabilityZoneState,
+    ComputeServiceState, ComputeServiceStatus, HypervisorStatistics, KeyPairType, RebootType,
+    RemoteConsole, RemoteConsoleProtocol, RemoteConsoleType, ServerAddress, ServerFlavor,
+    ServerGroupPolicy, ServerMigrationStatus, ServerPowerState, ServerSortKey, ServerStatus,
+    ServerUsage, TenantUsage, VolumeAttachment,
 };
+pub use self::public_keys::PublicKey;
+pub use self::quotas::{ComputeLimit, ComputeLimits, QuotaSet};
+pub use self::server_groups::{NewServerGroup, ServerGroup, ServerGroupQuery};
 pub use self::servers::{
-    DetailedServerQuery, NewServer, Server, ServerAction, ServerCreationWaiter, ServerNIC,
-    ServerQuery, ServerStatusWaiter, ServerSummary,
+    DetailedServerQuery, NewServer, Server, ServerAction, ServerBatchCreationWaiter,
+    ServerCreationWaiter, ServerMigrationWaiter, ServerNIC, ServerQuery, ServerStatusWaiter,
+    ServerSummary,
 };
+pub use self::services::{ComputeService, ComputeServiceQuery};
+pub use self::user_data::MultipartUserData;
+pub use self::validation::ServerProblem;
@@ -20,9 +20,10 @@
 use std::collections::HashMap;
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
-use chrono::{DateTime, FixedOffset};
-use osauth::common::{empty_as_default, IdAndName, Ref};
+use chrono::{DateTime, FixedOffset, NaiveDateTime, TimeZone, Utc};
+use osauth::common::{empty_as_default, empty_map_as_default, IdAndName, Ref};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
 
 use super::BlockDevice;
 
@@ -63,6 +64,8 @@ protocol_enum! {
 
 protocol_enum! {
     #[doc = "Possible server statuses."]
+    #[doc = ""]
+    #[doc = "The `UNKNOWN` status reported by Nova is represented as `Unknown(\"UNKNOWN\")`."]
     enum ServerStatus {
         Active = "ACTIVE",
         Building = "BUILD",
@@ -81,7 +84,6 @@ protocol_enum! {
         Shelved = "SHELVED",
         ShelvedOffloaded = "SHELVED_OFFLOADED",
         SoftDeleted = "SOFT_DELETED",
-        Unknown = "UNKNOWN",
         UpdatingPassword = "PASSWORD",
         VerifyingResize = "VERIFY_RESIZE"
     }
@@ -115,6 +117,22 @@ protocol_enum! {
     }
 }
 
+protocol_enum! {
+    #[doc = "State of a compute service or a hypervisor."]
+    enum ComputeServiceState {
+        Down = "down",
+        Up = "up"
+    }
+}
+
+protocol_enum! {
+    #[doc = "Status of a compute service or a hypervisor."]
+    enum ComputeServiceStatus {
+        Disabled = "disabled",
+        Enabled = "enabled"
+    }
+}
+
 protocol_enum! {
     #[doc = "Type of a key pair."]
     enum KeyPairType {
@@ -123,6 +141,60 @@ protocol_enum! {
     }
 }
 
+protocol_enum! {
+    #[doc = "Policy of a server group."]
+    enum ServerGroupPolicy {
+        Affinity = "affinity",
+        AntiAffinity = "anti-affinity",
+        SoftAffinity = "soft-affinity",
+        SoftAntiAffinity = "soft-anti-affinity"
+    }
+}
+
+protocol_enum! {
+    #[doc = "Status of a server migration."]
+    enum ServerMigrationStatus {
+        Accepted = "accepted",
+        Cancelled = "cancelled",
+        Cancelling = "cancelling",
+        Completed = "completed",
+        Confirmed = "confirmed",
+        Done = "done",
+        Error = "error",
+        Failed = "failed",
+        Finished = "finished",
+        Migrating = "migrating",
+        PostMigrating = "post-migrating",
+        Preparing = "preparing",
+        Queued = "queued",
+        Reverted = "reverted",
+        Running = "running"
+    }
+}
+
+protocol_enum! {
+    #[doc = "Protocol of a remote console."]
+    enum RemoteConsoleProtocol {
+        Mks = "mks",
+        Rdp = "rdp",
+        Serial = "serial",
+        Spice = "spice",
+        Vnc = "vnc"
+    }
+}
+
+protocol_enum! {
+    #[doc = "Type of a remote console."]
+    enum RemoteConsoleType {
+        NoVnc = "novnc",
+        RdpHtml5 = "rdp-html5",
+        Serial = "serial",
+        SpiceHtml5 = "spice-html5",
+        WebMks = "webmks",
+        XvpVnc = "xvpvnc"
+    }
+}
+
 /// Address of a server.
 #[derive(Clone, Debug, Deserialize)]
 pub struct ServerAddress {
@@ -136,7 +208,7 @@ pub struct ServerAddress {
     pub addr_type: Option<AddressType>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ExtraSpecsRoot {
     pub extra_specs: HashMap<String, String>,
 }
@@ -197,6 +269,7 @@ where
         .serialize(s)
 }
 
+/// A server, most fields are missing for servers in a cell that is down (API 2.69+).
 #[derive(Clone, Debug, Deserialize)]
 pub struct Server {
     #[serde(deserialize_with = "empty_as_default", default, rename = "accessIPv4")]
@@ -205,18 +278,22 @@ pub struct Server {
     pub access_ipv6: Option<Ipv6Addr>,
     #[serde(default)]
     pub addresses: HashMap<String, Vec<ServerAddress>>,
-    #[serde(rename = "OS-EXT-AZ:availability_zone")]
+    #[serde(rename = "OS-EXT-AZ:availability_zone", default)]
     pub availability_zone: String,
     #[serde(rename = "created")]
     pub created_at: DateTime<FixedOffset>,
     #[serde(deserialize_with = "empty_as_default", default)]
     pub description: Option<String>,
-    pub flavor: AnyFlavor,
+    #[serde(default)]
+    pub flavor: Option<AnyFlavor>,
     #[serde(
         deserialize_with = "bool_from_config_drive_string",
-        rename = "config_drive"
+        rename = "config_drive",
+        default
     )]
     pub has_config_drive: bool,
+    #[serde(rename = "OS-EXT-SRV-ATTR:host", default)]
+    pub host: Option<String>,
     pub id: String,
     #[serde(deserialize_with = "empty_as_default", default)]
     pub image: Option<Ref>,
@@ -224,21 +301,38 @@ pub struct Server {
     pub instance_name: Option<String>,
     #[serde(rename = "key_name", deserialize_with = "empty_as_default", default)]
     pub key_pair_name: Option<String>,
+    #[serde(default)]
+    pub locked: Option<bool>,
+    #[serde(deserialize_with = "empty_as_default", default)]
+    pub locked_reason: Option<String>,
+    #[serde(default)]
     pub name: String,
     #[serde(default)]
     pub metadata: HashMap<String, String>,
     pub status: ServerStatus,
     #[serde(rename = "OS-EXT-STS:power_state", default)]
     pub power_state: ServerPowerState,
+    #[serde(default)]
+    pub server_groups: Option<Vec<String>>,
+    #[serde(default)]
+    pub tags: Vec<String>,
     // pub tenant_id: String,
-    #[serde(rename = "updated")]
-    pub updated_at: DateTime<FixedOffset>,
+    #[serde(rename = "updated", default)]
+    pub updated_at: Option<DateTime<FixedOffset>>,
     // pub user_id: String,
 }
 
+/// A server in a short listing, the name is missing for servers in a down cell.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ServerSummaryItem {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct ServersRoot {
-    pub servers: Vec<IdAndName>,
+    pub servers: Vec<ServerSummaryItem>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -259,8 +353,25 @@ pub enum ServerNetwork {
     FixedIp { fixed_ip: Ipv4Addr },
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ServerSecurityGroup {
+    pub name: String,
+}
+
 #[derive(Clone, Debug, Serialize)]
+pub struct Personality {
+    pub path: String,
+    pub contents: String,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
 pub struct ServerCreate {
+    #[serde(rename = "accessIPv4", skip_serializing_if = "Option::is_none")]
+    pub access_ipv4: Option<Ipv4Addr>,
+    #[serde(rename = "accessIPv6", skip_serializing_if = "Option::is_none")]
+    pub access_ipv6: Option<Ipv6Addr>,
+    #[serde(rename = "adminPass", skip_serializing_if = "Option::is_none")]
+    pub admin_pass: Option<String>,
     #[serde(
         rename = "block_device_mapping_v2",
         skip_serializing_if = "Vec::is_empty"
@@ -271,29 +382,729 @@ pub struct ServerCreate {
         serialize_with = "config_drive_string_to_bool"
     )]
     pub config_drive: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
     pub flavorRef: String,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub imageRef: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub key_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_count: Option<u32>,
     #[serde(skip_serializing_if = "HashMap::is_empty")]
     pub metadata: HashMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_count: Option<u32>,
     pub name: String,
     pub networks: Vec<ServerNetwork>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub personality: Vec<Personality>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_reservation_id: Option<bool>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub security_groups: Vec<ServerSecurityGroup>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_data: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub availability_zone: Option<String>,
+    #[serde(skip)]
+    pub scheduler_hints: HashMap<String, Value>,
 }
 
 #[derive(Clone, Debug, Serialize)]
 pub struct ServerCreateRoot {
     pub server: ServerCreate,
+    #[serde(
+        rename = "os:scheduler_hints",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub scheduler_hints: HashMap<String, Value>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CreatedServer {
+    pub id: String,
+    #[serde(rename = "adminPass", default)]
+    pub admin_pass: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum CreatedServerRoot {
+    Server { server: CreatedServer },
+    Reservation { reservation_id: String },
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ServerGroup {
+    pub id: String,
+    #[serde(default)]
+    pub members: Vec<String>,
+    pub name: String,
+    #[serde(default)]
+    pub policies: Vec<ServerGroupPolicy>,
+    #[serde(default)]
+    pub policy: Option<ServerGroupPolicy>,
+    #[serde(default)]
+    pub project_id: Option<String>,
+    #[serde(default)]
+    pub user_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ServerGroupRoot {
+    pub server_group: ServerGroup,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ServerGroupsRoot {
+    pub server_groups: Vec<ServerGroup>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ServerGroupCreate {
+    pub name: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub policies: Vec<ServerGroupPolicy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<ServerGroupPolicy>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ServerGroupCreateRoot {
+    pub server_group: ServerGroupCreate,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Migrate {
+    pub host: String,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct MigrateRoot {
+    pub migrate: Option<Migrate>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct LiveMigrate {
+    pub block_migration: String,
+    pub host: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct LiveMigrateRoot {
+    #[serde(rename = "os-migrateLive")]
+    pub live_migrate: LiveMigrate,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Evacuate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct EvacuateRoot {
+    pub evacuate: Evacuate,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ForceCompleteRoot {
+    pub force_complete: (),
+}
+
+#[cfg(feature = "image")]
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateImage {
+    pub name: String,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub metadata: HashMap<String, String>,
+}
+
+#[cfg(feature = "image")]
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateImageRoot {
+    #[serde(rename = "createImage")]
+    pub create_image: CreateImage,
+}
+
+#[cfg(feature = "image")]
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateBackup {
+    pub name: String,
+    pub backup_type: String,
+    pub rotation: u32,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub metadata: HashMap<String, String>,
+}
+
+#[cfg(feature = "image")]
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateBackupRoot {
+    #[serde(rename = "createBackup")]
+    pub create_backup: CreateBackup,
+}
+
+#[cfg(feature = "image")]
+#[derive(Clone, Debug, Deserialize)]
+pub struct CreatedImage {
+    pub image_id: String,
+}
+
+/// Deserialize a date and time that may come without a timezone.
+///
+/// Nova returns migration timestamps in UTC without specifying a timezone.
+fn deser_naive_datetime<'de, D>(deserializer: D) -> Result<DateTime<FixedOffset>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value: String = Deserialize::deserialize(deserializer)?;
+    match DateTime::parse_from_rfc3339(&value) {
+        Ok(value) => Ok(value),
+        Err(_) => NaiveDateTime::parse_from_str(&value, "%Y-%m-%dT%H:%M:%S%.f")
+            .map(|naive| Utc.from_utc_datetime(&naive).into())
+            .map_err(de::Error::custom),
+    }
+}
+
+fn deser_optional_naive_datetime<'de, D>(
+    deserializer: D,
+) -> Result<Option<DateTime<FixedOffset>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Wrapper(#[serde(deserialize_with = "deser_naive_datetime")] DateTime<FixedOffset>);
+
+    let value: Option<Wrapper> = Deserialize::deserialize(deserializer)?;
+    Ok(value.map(|Wrapper(value)| value))
+}
+
+/// An in-progress migration of a server.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ServerMigration {
+    #[serde(deserialize_with = "deser_naive_datetime")]
+    pub created_at: DateTime<FixedOffset>,
+    #[serde(default)]
+    pub dest_compute: Option<String>,
+    #[serde(default)]
+    pub dest_host: Option<String>,
+    #[serde(default)]
+    pub dest_node: Option<String>,
+    #[serde(default)]
+    pub disk_processed_bytes: Option<u64>,
+    #[serde(default)]
+    pub disk_remaining_bytes: Option<u64>,
+    #[serde(default)]
+    pub disk_total_bytes: Option<u64>,
+    pub id: u64,
+    #[serde(default)]
+    pub memory_processed_bytes: Option<u64>,
+    #[serde(default)]
+    pub memory_remaining_bytes: Option<u64>,
+    #[serde(default)]
+    pub memory_total_bytes: Option<u64>,
+    pub server_uuid: String,
+    #[serde(default)]
+    pub source_compute: Option<String>,
+    #[serde(default)]
+    pub source_node: Option<String>,
+    pub status: ServerMigrationStatus,
+    #[serde(deserialize_with = "deser_optional_naive_datetime", default)]
+    pub updated_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ServerMigrationsRoot {
+    pub migrations: Vec<ServerMigration>,
+}
+
+/// Deserialize an ID that is an integer in older API versions.
+fn deser_id<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Id {
+        Number(u64),
+        String(String),
+    }
+
+    Ok(match Id::deserialize(deserializer)? {
+        Id::Number(value) => value.to_string(),
+        Id::String(value) => value,
+    })
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct HypervisorService {
+    pub host: String,
+    #[serde(deserialize_with = "deser_id")]
+    pub id: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Hypervisor {
+    #[serde(default)]
+    pub current_workload: Option<u64>,
+    #[serde(default)]
+    pub disk_available_least: Option<i64>,
+    #[serde(default)]
+    pub free_disk_gb: Option<i64>,
+    #[serde(default)]
+    pub free_ram_mb: Option<i64>,
+    #[serde(default)]
+    pub host_ip: Option<IpAddr>,
+    pub hypervisor_hostname: String,
+    #[serde(default)]
+    pub hypervisor_type: Option<String>,
+    #[serde(default)]
+    pub hypervisor_version: Option<u64>,
+    #[serde(deserialize_with = "deser_id")]
+    pub id: String,
+    #[serde(default)]
+    pub local_gb: Option<u64>,
+    #[serde(default)]
+    pub local_gb_used: Option<u64>,
+    #[serde(default)]
+    pub memory_mb: Option<u64>,
+    #[serde(default)]
+    pub memory_mb_used: Option<u64>,
+    #[serde(default)]
+    pub running_vms: Option<u64>,
+    #[serde(default)]
+    pub service: Option<HypervisorService>,
+    pub state: ComputeServiceState,
+    pub status: ComputeServiceStatus,
+    #[serde(default)]
+    pub vcpus: Option<u64>,
+    #[serde(default)]
+    pub vcpus_used: Option<u64>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct HypervisorRoot {
+    pub hypervisor: Hypervisor,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct HypervisorsRoot {
+    pub hypervisors: Vec<Hypervisor>,
+}
+
+/// Summary statistics over all hypervisors.
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct HypervisorStatistics {
+    /// Number of hypervisors.
+    pub count: u64,
+    /// Number of tasks currently running.
+    pub current_workload: u64,
+    /// Available disk space in GiB (may be negative on overcommit).
+    pub disk_available_least: i64,
+    /// Free disk space in GiB (may be negative on overcommit).
+    pub free_disk_gb: i64,
+    /// Free RAM in MiB (may be negative on overcommit).
+    pub free_ram_mb: i64,
+    /// Total disk space in GiB.
+    pub local_gb: u64,
+    /// Used disk space in GiB.
+    pub local_gb_used: u64,
+    /// Total RAM in MiB.
+    pub memory_mb: u64,
+    /// Used RAM in MiB.
+    pub memory_mb_used: u64,
+    /// Number of running servers.
+    pub running_vms: u64,
+    /// Total number of virtual CPUs.
+    pub vcpus: u64,
+    /// Number of used virtual CPUs.
+    pub vcpus_used: u64,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct HypervisorStatisticsRoot {
+    pub hypervisor_statistics: HypervisorStatistics,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ComputeService {
+    pub binary: String,
+    #[serde(default)]
+    pub disabled_reason: Option<String>,
+    #[serde(default)]
+    pub forced_down: bool,
+    pub host: String,
+    #[serde(deserialize_with = "deser_id")]
+    pub id: String,
+    pub state: ComputeServiceState,
+    pub status: ComputeServiceStatus,
+    #[serde(deserialize_with = "deser_optional_naive_datetime", default)]
+    pub updated_at: Option<DateTime<FixedOffset>>,
+    pub zone: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ComputeServiceRoot {
+    pub service: ComputeService,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ComputeServicesRoot {
+    pub services: Vec<ComputeService>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ComputeServiceUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forced_down: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ComputeServiceStatus>,
+}
+
+/// State of an availability zone.
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct AvailabilityZoneState {
+    /// Whether the zone is available.
+    pub available: bool,
+}
+
+/// State of a service in an availability zone.
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct AvailabilityZoneService {
+    /// Whether the service is enabled.
+    pub active: bool,
+    /// Whether the service is up.
+    pub available: bool,
+    /// When the service last reported its state.
+    #[serde(deserialize_with = "deser_optional_naive_datetime", default)]
+    pub updated_at: Option<DateTime<FixedOffset>>,
+}
+
+/// An availability zone.
+#[derive(Clone, Debug, Deserialize)]
+pub struct AvailabilityZone {
+    /// Name of the zone.
+    #[serde(rename = "zoneName")]
+    pub name: String,
+    /// State of the zone.
+    #[serde(rename = "zoneState")]
+    pub state: AvailabilityZoneState,
+    /// Services per host in the zone.
+    ///
+    /// Only available to administrators when fetching zones with host details.
+    #[serde(default)]
+    pub hosts: Option<HashMap<String, HashMap<String, AvailabilityZoneService>>>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AvailabilityZonesRoot {
+    #[serde(rename = "availabilityZoneInfo")]
+    pub availability_zones: Vec<AvailabilityZone>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct AbsoluteLimits {
+    #[serde(rename = "maxServerGroupMembers", default = "unlimited")]
+    pub max_server_group_members: i64,
+    #[serde(rename = "maxServerGroups", default = "unlimited")]
+    pub max_server_groups: i64,
+    #[serde(rename = "maxTotalCores", default = "unlimited")]
+    pub max_total_cores: i64,
+    #[serde(rename = "maxTotalInstances", default = "unlimited")]
+    pub max_total_instances: i64,
+    #[serde(rename = "maxTotalKeypairs", default = "unlimited")]
+    pub max_total_keypairs: i64,
+    #[serde(rename = "maxTotalRAMSize", default = "unlimited")]
+    pub max_total_ram_size: i64,
+    #[serde(rename = "totalCoresUsed", default)]
+    pub total_cores_used: u64,
+    #[serde(rename = "totalInstancesUsed", default)]
+    pub total_instances_used: u64,
+    #[serde(rename = "totalRAMUsed", default)]
+    pub total_ram_used: u64,
+    #[serde(rename = "totalServerGroupsUsed", default)]
+    pub total_server_groups_used: u64,
+}
+
+fn unlimited() -> i64 {
+    -1
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Limits {
+    pub absolute: AbsoluteLimits,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct LimitsRoot {
+    pub limits: Limits,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct QuotaSet {
+    pub cores: i64,
+    pub instances: i64,
+    pub key_pairs: i64,
+    pub metadata_items: i64,
+    pub ram: i64,
+    #[serde(default = "unlimited")]
+    pub server_group_members: i64,
+    #[serde(default = "unlimited")]
+    pub server_groups: i64,
 }
 
 #[derive(Clone, Debug, Deserialize)]
-pub struct CreatedServerRoot {
-    pub server: Ref,
+pub struct QuotaSetRoot {
+    pub quota_set: QuotaSet,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct QuotaSetUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cores: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instances: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_pairs: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata_items: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ram: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_group_members: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_groups: Option<i64>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct QuotaSetUpdateRoot {
+    pub quota_set: QuotaSetUpdate,
+}
+
+/// Usage of a server over a period of time.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ServerUsage {
+    /// When the server was deleted (if it was deleted in the period).
+    #[serde(deserialize_with = "deser_optional_naive_datetime", default)]
+    pub ended_at: Option<DateTime<FixedOffset>>,
+    /// Name of the server flavor.
+    pub flavor: String,
+    /// Number of hours the server was running in the period.
+    pub hours: f64,
+    /// Server ID.
+    #[serde(rename = "instance_id")]
+    pub id: String,
+    /// Local disk size of the server in GiB.
+    pub local_gb: u64,
+    /// Memory size of the server in MiB.
+    pub memory_mb: u64,
+    /// Server name.
+    pub name: String,
+    /// When the server was created.
+    #[serde(deserialize_with = "deser_naive_datetime")]
+    pub started_at: DateTime<FixedOffset>,
+    /// Server state, e.g. `active` or `terminated`.
+    pub state: String,
+    /// Uptime of the server in seconds.
+    pub uptime: u64,
+    /// Number of virtual CPUs of the server.
+    pub vcpus: u32,
+}
+
+/// Usage of a project over a period of time.
+#[derive(Clone, Debug, Deserialize)]
+pub struct TenantUsage {
+    /// Usage of individual servers.
+    ///
+    /// Only returned for a single project or when requested.
+    #[serde(default)]
+    pub server_usages: Vec<ServerUsage>,
+    /// Start of the period.
+    #[serde(deserialize_with = "deser_naive_datetime")]
+    pub start: DateTime<FixedOffset>,
+    /// End of the period.
+    #[serde(deserialize_with = "deser_naive_datetime")]
+    pub stop: DateTime<FixedOffset>,
+    /// Project ID.
+    #[serde(rename = "tenant_id")]
+    pub project_id: String,
+    /// Total number of server hours.
+    pub total_hours: f64,
+    /// Total disk usage in GiB-hours.
+    pub total_local_gb_usage: f64,
+    /// Total memory usage in MiB-hours.
+    pub total_memory_mb_usage: f64,
+    /// Total virtual CPU usage in CPU-hours.
+    pub total_vcpus_usage: f64,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct TenantUsageRoot {
+    /// Empty if the project has no usage in the period.
+    #[serde(deserialize_with = "empty_map_as_default")]
+    pub tenant_usage: Option<TenantUsage>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct TenantUsagesRoot {
+    pub tenant_usages: Vec<TenantUsage>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ConsoleOutputRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub length: Option<u32>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ConsoleOutputRequestRoot {
+    #[serde(rename = "os-getConsoleOutput")]
+    pub request: ConsoleOutputRequest,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ConsoleOutput {
+    pub output: String,
+}
+
+/// A remote console of a server.
+#[derive(Clone, Debug, Deserialize)]
+pub struct RemoteConsole {
+    /// Protocol of the console.
+    pub protocol: RemoteConsoleProtocol,
+    /// Type of the console.
+    #[serde(rename = "type")]
+    pub console_type: RemoteConsoleType,
+    /// URL to connect to the console.
+    pub url: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RemoteConsoleRoot {
+    pub remote_console: RemoteConsole,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct RemoteConsoleCreate {
+    pub protocol: RemoteConsoleProtocol,
+    #[serde(rename = "type")]
+    pub console_type: RemoteConsoleType,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct RemoteConsoleCreateRoot {
+    pub remote_console: RemoteConsoleCreate,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ServerUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ServerUpdateRoot {
+    pub server: ServerUpdate,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MetadataRoot {
+    pub metadata: HashMap<String, String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MetadataItemRoot {
+    pub meta: HashMap<String, String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TagsRoot {
+    pub tags: Vec<String>,
+}
+
+/// A volume attached to a server.
+#[derive(Clone, Debug, Deserialize)]
+pub struct VolumeAttachment {
+    /// Device name inside the server (if known).
+    #[serde(default, deserialize_with = "empty_as_default")]
+    pub device: Option<String>,
+    /// ID of the server the volume is attached to.
+    #[serde(rename = "serverId")]
+    pub server_id: String,
+    /// ID of the attached volume.
+    #[serde(rename = "volumeId")]
+    pub volume_id: String,
+}
+
+#[cfg(feature = "block-storage")]
+#[derive(Clone, Debug, Deserialize)]
+pub struct VolumeAttachmentRoot {
+    pub volumeAttachment: VolumeAttachment,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct VolumeAttachmentsRoot {
+    pub volumeAttachments: Vec<VolumeAttachment>,
+}
+
+#[cfg(feature = "block-storage")]
+#[derive(Clone, Debug, Serialize)]
+pub struct VolumeAttachmentCreate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<String>,
+    pub volumeId: String,
+}
+
+#[cfg(feature = "block-storage")]
+#[derive(Clone, Debug, Serialize)]
+pub struct VolumeAttachmentCreateRoot {
+    pub volumeAttachment: VolumeAttachmentCreate,
+}
+
+#[cfg(feature = "network")]
+#[derive(Clone, Debug, Deserialize)]
+pub struct InterfaceAttachment {
+    pub port_id: String,
+}
+
+#[cfg(feature = "network")]
+#[derive(Clone, Debug, Deserialize)]
+pub struct InterfaceAttachmentRoot {
+    #[serde(rename = "interfaceAttachment")]
+    pub interface_attachment: InterfaceAttachment,
+}
+
+#[cfg(feature = "network")]
+#[derive(Clone, Debug, Serialize)]
+pub struct InterfaceFixedIp {
+    pub ip_address: IpAddr,
+}
+
+#[cfg(feature = "network")]
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct InterfaceAttachmentCreate {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fixed_ips: Vec<InterfaceFixedIp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub net_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port_id: Option<String>,
+}
+
+#[cfg(feature = "network")]
+#[derive(Clone, Debug, Serialize)]
+pub struct InterfaceAttachmentCreateRoot {
+    #[serde(rename = "interfaceAttachment")]
+    pub interface_attachment: InterfaceAttachmentCreate,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -319,6 +1130,58 @@ pub struct Flavor {
     pub vcpus: u32,
 }
 
+#[derive(Clone, Debug, Serialize)]
+pub struct FlavorCreate {
+    pub disk: u64,
+    #[serde(
+        rename = "OS-FLV-EXT-DATA:ephemeral",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ephemeral: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(
+        rename = "os-flavor-access:is_public",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub is_public: Option<bool>,
+    pub name: String,
+    pub ram: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rxtx_factor: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub swap: Option<u64>,
+    pub vcpus: u32,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct FlavorCreateRoot {
+    pub flavor: FlavorCreate,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct FlavorAccess {
+    pub tenant_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct FlavorAccessRoot {
+    pub flavor_access: Vec<FlavorAccess>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct FlavorAccessTenant {
+    pub tenant: String,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub enum FlavorAccessAction {
+    #[serde(rename = "addTenantAccess")]
+    Add(FlavorAccessTenant),
+    #[serde(rename = "removeTenantAccess")]
+    Remove(FlavorAccessTenant),
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct FlavorsRoot {
     pub flavors: Vec<IdAndName>,
@@ -343,6 +1206,8 @@ pub struct KeyPair {
     #[serde(default, skip_serializing)]
     pub private_key: Option<String>,
     pub public_key: String,
+    #[serde(default, skip_serializing)]
+    pub user_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -381,7 +1246,7 @@ pub struct KeyPairsRoot {
 
 impl Default for ServerStatus {
     fn default() -> ServerStatus {
-        ServerStatus::Unknown
+        ServerStatus::Unknown(String::from("UNKNOWN"))
     }
 }
 
@@ -395,3 +1260,669 @@ impl Default for ServerPowerState {
 fn default_flavor_is_public() -> bool {
     true
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_unknown_enum_values() {
+        assert_unknown_protocol_enums!(
+            AddressType,
+            ComputeServiceState,
+            ComputeServiceStatus,
+            KeyPairType,
+            RebootType,
+            RemoteConsoleProtocol,
+            RemoteConsoleType,
+            ServerGroupPolicy,
+            ServerMigrationStatus,
+            ServerSortKey,
+            ServerStatus
+        );
+
+        let state: ServerPowerState = serde_json::from_value(serde_json::json!(42)).unwrap();
+        assert_eq!(state, ServerPowerState::Unknown(42));
+        assert_eq!(serde_json::to_value(state).unwrap(), serde_json::json!(42));
+    }
+
+    #[test]
+    fn test_parse_server_microversion_fields() {
+        let root: ServerRoot = serde_json::from_value(serde_json::json!({
+            "server": {
+                "OS-EXT-AZ:availability_zone": "nova",
+                "config_drive": "",
+                "created": "2026-01-12T09:18:34Z",
+                "description": null,
+                "flavor": {"id": "1", "links": []},
+                "id": "4c7a1bd6-77f1-4b3c-9b4c-1f3f1b2c5d6e",
+                "locked": true,
+                "locked_reason": "maintenance",
+                "name": "test",
+                "status": "ACTIVE",
+                "tags": ["web", "prod"],
+                "updated": "2026-01-12T09:20:11Z"
+            }
+        }))
+        .unwrap();
+        assert_eq!(root.server.locked, Some(true));
+        assert_eq!(root.server.locked_reason.as_deref(), Some("maintenance"));
+        assert_eq!(root.server.tags, vec!["web", "prod"]);
+        assert!(root.server.description.is_none());
+
+        let root: ServerRoot = serde_json::from_value(serde_json::json!({
+            "server": {
+                "OS-EXT-AZ:availability_zone": "nova",
+                "config_drive": "True",
+                "created": "2026-01-12T09:18:34Z",
+                "flavor": {"id": "1", "links": []},
+                "id": "4c7a1bd6-77f1-4b3c-9b4c-1f3f1b2c5d6e",
+                "name": "test",
+                "status": "ACTIVE",
+                "updated": "2026-01-12T09:20:11Z"
+            }
+        }))
+        .unwrap();
+        assert!(root.server.locked.is_none());
+        assert!(root.server.locked_reason.is_none());
+        assert!(root.server.tags.is_empty());
+    }
+
+    #[test]
+    fn test_parse_server_in_down_cell() {
+        let root: ServersDetailRoot = serde_json::from_value(serde_json::json!({
+            "servers": [{
+                "created": "2026-01-12T09:18:34Z",
+                "id": "4c7a1bd6-77f1-4b3c-9b4c-1f3f1b2c5d6e",
+                "links": [],
+                "status": "UNKNOWN",
+                "tenant_id": "6f70656e737461636b20342065766572"
+            }]
+        }))
+        .unwrap();
+        let server = &root.servers[0];
+        assert_eq!(server.status, ServerStatus::Unknown("UNKNOWN".into()));
+        assert!(server.name.is_empty());
+        assert!(server.flavor.is_none());
+        assert!(server.updated_at.is_none());
+
+        let root: ServersRoot = serde_json::from_value(serde_json::json!({
+            "servers": [{
+                "id": "4c7a1bd6-77f1-4b3c-9b4c-1f3f1b2c5d6e",
+                "links": [],
+                "status": "UNKNOWN"
+            }]
+        }))
+        .unwrap();
+        assert!(root.servers[0].name.is_empty());
+    }
+
+    #[test]
+    fn test_server_update_serialize() {
+        let update = ServerUpdate {
+            name: Some("new-name".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(ServerUpdateRoot { server: update }).unwrap(),
+            serde_json::json!({"server": {"name": "new-name"}})
+        );
+    }
+
+    #[test]
+    fn test_console_requests() {
+        let body = ConsoleOutputRequestRoot {
+            request: ConsoleOutputRequest { length: Some(50) },
+        };
+        assert_eq!(
+            serde_json::to_value(body).unwrap(),
+            serde_json::json!({"os-getConsoleOutput": {"length": 50}})
+        );
+        let body = ConsoleOutputRequestRoot {
+            request: ConsoleOutputRequest { length: None },
+        };
+        assert_eq!(
+            serde_json::to_value(body).unwrap(),
+            serde_json::json!({"os-getConsoleOutput": {}})
+        );
+
+        let body = RemoteConsoleCreateRoot {
+            remote_console: RemoteConsoleCreate {
+                protocol: RemoteConsoleProtocol::Vnc,
+                console_type: RemoteConsoleType::NoVnc,
+            },
+        };
+        assert_eq!(
+            serde_json::to_value(body).unwrap(),
+            serde_json::json!({"remote_console": {"protocol": "vnc", "type": "novnc"}})
+        );
+        let root: RemoteConsoleRoot = serde_json::from_value(serde_json::json!({
+            "remote_console": {
+                "protocol": "serial",
+                "type": "serial",
+                "url": "ws://example.com:6083/?token=f9906a48"
+            }
+        }))
+        .unwrap();
+        assert_eq!(root.remote_console.protocol, RemoteConsoleProtocol::Serial);
+        assert_eq!(root.remote_console.console_type, RemoteConsoleType::Serial);
+        assert_eq!(
+            root.remote_console.url,
+            "ws://example.com:6083/?token=f9906a48"
+        );
+    }
+
+    #[test]
+    fn test_flavor_requests() {
+        let body = FlavorCreateRoot {
+            flavor: FlavorCreate {
+                disk: 10,
+                ephemeral: None,
+                id: None,
+                is_public: Some(false),
+                name: "private".into(),
+                ram: 2048,
+                rxtx_factor: None,
+                swap: Some(512),
+                vcpus: 2,
+            },
+        };
+        assert_eq!(
+            serde_json::to_value(body).unwrap(),
+            serde_json::json!({
+                "flavor": {
+                    "disk": 10,
+                    "name": "private",
+                    "os-flavor-access:is_public": false,
+                    "ram": 2048,
+                    "swap": 512,
+                    "vcpus": 2
+                }
+            })
+        );
+
+        let action = FlavorAccessAction::Add(FlavorAccessTenant {
+            tenant: "abcd".into(),
+        });
+        assert_eq!(
+            serde_json::to_value(action).unwrap(),
+            serde_json::json!({"addTenantAccess": {"tenant": "abcd"}})
+        );
+        let root: FlavorAccessRoot = serde_json::from_value(serde_json::json!({
+            "flavor_access": [{"flavor_id": "10", "tenant_id": "abcd"}]
+        }))
+        .unwrap();
+        assert_eq!(root.flavor_access[0].tenant_id, "abcd");
+    }
+
+    #[test]
+    fn test_parse_server_group() {
+        let root: ServerGroupRoot = serde_json::from_value(serde_json::json!({
+            "server_group": {
+                "id": "5bbcc3c4-1da2-4437-a48a-66f15b1b13f9",
+                "members": [],
+                "name": "test",
+                "policies": ["anti-affinity"],
+                "metadata": {}
+            }
+        }))
+        .unwrap();
+        assert_eq!(
+            root.server_group.policies,
+            vec![ServerGroupPolicy::AntiAffinity]
+        );
+        assert!(root.server_group.policy.is_none());
+
+        let root: ServerGroupRoot = serde_json::from_value(serde_json::json!({
+            "server_group": {
+                "id": "5bbcc3c4-1da2-4437-a48a-66f15b1b13f9",
+                "members": ["b7a2ba2f-0a5a-4b21-8c2e-4e1d1b2f1a9c"],
+                "name": "test",
+                "policy": "soft-affinity",
+                "rules": {},
+                "project_id": "6f70656e737461636b20342065766572",
+                "user_id": "fake"
+            }
+        }))
+        .unwrap();
+        assert_eq!(
+            root.server_group.policy,
+            Some(ServerGroupPolicy::SoftAffinity)
+        );
+        assert_eq!(root.server_group.members.len(), 1);
+    }
+
+    #[test]
+    fn test_server_create_scheduler_hints() {
+        let mut scheduler_hints = HashMap::new();
+        let _ = scheduler_hints.insert("group".to_string(), Value::from("abcd"));
+        let body = ServerCreateRoot {
+            server: ServerCreate {
+                flavorRef: "1".into(),
+                name: "test".into(),
+                ..ServerCreate::default()
+            },
+            scheduler_hints,
+        };
+        assert_eq!(
+            serde_json::to_value(body).unwrap(),
+            serde_json::json!({
+                "server": {"flavorRef": "1", "name": "test", "networks": []},
+                "os:scheduler_hints": {"group": "abcd"}
+            })
+        );
+    }
+
+    #[test]
+    fn test_server_create_access() {
+        let request = ServerCreate {
+            access_ipv4: Some(Ipv4Addr::new(192, 0, 2, 1)),
+            admin_pass: Some("secret".into()),
+            flavorRef: "1".into(),
+            hostname: Some("web-1".into()),
+            name: "test".into(),
+            personality: vec![Personality {
+                path: "/etc/motd".into(),
+                contents: "aGVsbG8=".into(),
+            }],
+            ..ServerCreate::default()
+        };
+        assert_eq!(
+            serde_json::to_value(request).unwrap(),
+            serde_json::json!({
+                "accessIPv4": "192.0.2.1",
+                "adminPass": "secret",
+                "flavorRef": "1",
+                "hostname": "web-1",
+                "name": "test",
+                "networks": [],
+                "personality": [{"path": "/etc/motd", "contents": "aGVsbG8="}]
+            })
+        );
+
+        let root: CreatedServerRoot = serde_json::from_value(serde_json::json!({
+            "server": {"id": "abcd", "adminPass": "generated", "links": []}
+        }))
+        .unwrap();
+        match root {
+            CreatedServerRoot::Server { server } => {
+                assert_eq!(server.admin_pass.as_deref(), Some("generated"))
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_server_create_batch() {
+        let request = ServerCreate {
+            flavorRef: "1".into(),
+            max_count: Some(5),
+            min_count: Some(2),
+            name: "worker".into(),
+            return_reservation_id: Some(true),
+            ..ServerCreate::default()
+        };
+        assert_eq!(
+            serde_json::to_value(request).unwrap(),
+            serde_json::json!({
+                "flavorRef": "1",
+                "max_count": 5,
+                "min_count": 2,
+                "name": "worker",
+                "networks": [],
+                "return_reservation_id": true
+            })
+        );
+
+        let root: CreatedServerRoot = serde_json::from_value(serde_json::json!({
+            "reservation_id": "r-3fhpjulh"
+        }))
+        .unwrap();
+        match root {
+            CreatedServerRoot::Reservation { reservation_id } => {
+                assert_eq!(reservation_id, "r-3fhpjulh")
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_server_migrations() {
+        let root: ServerMigrationsRoot = serde_json::from_value(serde_json::json!({
+            "migrations": [{
+                "created_at": "2016-01-29T13:42:02.000000",
+                "dest_compute": "compute2",
+                "dest_host": "1.2.3.4",
+                "dest_node": "node2",
+                "id": 4,
+                "server_uuid": "4cfba335-03d8-49b2-8c52-e69043d1e8fe",
+                "source_compute": "compute1",
+                "source_node": "node1",
+                "status": "running",
+                "memory_total_bytes": 123456,
+                "memory_processed_bytes": 12345,
+                "memory_remaining_bytes": 111111,
+                "disk_total_bytes": 234567,
+                "disk_processed_bytes": 23456,
+                "disk_remaining_bytes": 211111,
+                "updated_at": null
+            }]
+        }))
+        .unwrap();
+        let migration = &root.migrations[0];
+        assert_eq!(migration.id, 4);
+        assert_eq!(migration.status, ServerMigrationStatus::Running);
+        assert_eq!(
+            migration.created_at.to_rfc3339(),
+            "2016-01-29T13:42:02+00:00"
+        );
+        assert!(migration.updated_at.is_none());
+        assert_eq!(migration.dest_compute.as_deref(), Some("compute2"));
+    }
+
+    #[test]
+    fn test_migration_actions() {
+        assert_eq!(
+            serde_json::to_value(MigrateRoot { migrate: None }).unwrap(),
+            serde_json::json!({"migrate": null})
+        );
+        assert_eq!(
+            serde_json::to_value(LiveMigrateRoot {
+                live_migrate: LiveMigrate {
+                    block_migration: "auto".into(),
+                    host: None
+                }
+            })
+            .unwrap(),
+            serde_json::json!({"os-migrateLive": {"block_migration": "auto", "host": null}})
+        );
+        assert_eq!(
+            serde_json::to_value(ForceCompleteRoot { force_complete: () }).unwrap(),
+            serde_json::json!({"force_complete": null})
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn test_image_actions() {
+        assert_eq!(
+            serde_json::to_value(CreateImageRoot {
+                create_image: CreateImage {
+                    name: "snapshot".into(),
+                    metadata: HashMap::new(),
+                }
+            })
+            .unwrap(),
+            serde_json::json!({"createImage": {"name": "snapshot"}})
+        );
+        let mut metadata = HashMap::new();
+        let _ = metadata.insert("key".to_string(), "value".to_string());
+        assert_eq!(
+            serde_json::to_value(CreateBackupRoot {
+                create_backup: CreateBackup {
+                    name: "backup".into(),
+                    backup_type: "daily".into(),
+                    rotation: 2,
+                    metadata,
+                }
+            })
+            .unwrap(),
+            serde_json::json!({"createBackup": {
+                "name": "backup",
+                "backup_type": "daily",
+                "rotation": 2,
+                "metadata": {"key": "value"}
+            }})
+        );
+        let created: CreatedImage =
+            serde_json::from_value(serde_json::json!({"image_id": "0e7761dd"})).unwrap();
+        assert_eq!(created.image_id, "0e7761dd");
+    }
+
+    #[test]
+    #[cfg(feature = "network")]
+    fn test_interface_attachments() {
+        assert_eq!(
+            serde_json::to_value(InterfaceAttachmentCreateRoot {
+                interface_attachment: InterfaceAttachmentCreate {
+                    fixed_ips: vec![InterfaceFixedIp {
+                        ip_address: "192.168.1.3".parse().unwrap(),
+                    }],
+                    net_id: Some("3cb9bc59".into()),
+                    port_id: None,
+                }
+            })
+            .unwrap(),
+            serde_json::json!({"interfaceAttachment": {
+                "fixed_ips": [{"ip_address": "192.168.1.3"}],
+                "net_id": "3cb9bc59"
+            }})
+        );
+        let root: InterfaceAttachmentRoot = serde_json::from_value(serde_json::json!({
+            "interfaceAttachment": {
+                "fixed_ips": [{"ip_address": "192.168.1.3", "subnet_id": "f8a6e8f8"}],
+                "mac_addr": "fa:16:3e:4c:2c:30",
+                "net_id": "3cb9bc59",
+                "port_id": "ce531f90",
+                "port_state": "ACTIVE"
+            }
+        }))
+        .unwrap();
+        assert_eq!(root.interface_attachment.port_id, "ce531f90");
+    }
+
+    #[test]
+    fn test_parse_limits() {
+        let root: LimitsRoot = serde_json::from_value(serde_json::json!({
+            "limits": {
+                "absolute": {
+                    "maxImageMeta": 128,
+                    "maxServerGroupMembers": 10,
+                    "maxServerGroups": 10,
+                    "maxServerMeta": 128,
+                    "maxTotalCores": 20,
+                    "maxTotalInstances": -1,
+                    "maxTotalKeypairs": 100,
+                    "maxTotalRAMSize": 51200,
+                    "totalCoresUsed": 4,
+                    "totalInstancesUsed": 2,
+                    "totalRAMUsed": 4096,
+                    "totalServerGroupsUsed": 1
+                },
+                "rate": []
+            }
+        }))
+        .unwrap();
+        let limits = root.limits.absolute;
+        assert_eq!(limits.max_total_cores, 20);
+        assert_eq!(limits.max_total_instances, -1);
+        assert_eq!(limits.total_ram_used, 4096);
+        assert_eq!(limits.total_server_groups_used, 1);
+    }
+
+    #[test]
+    fn test_quota_sets() {
+        let root: QuotaSetRoot = serde_json::from_value(serde_json::json!({
+            "quota_set": {
+                "cores": 20,
+                "id": "fake_tenant",
+                "instances": 10,
+                "key_pairs": 100,
+                "metadata_items": 128,
+                "ram": 51200,
+                "server_group_members": 10,
+                "server_groups": 10
+            }
+        }))
+        .unwrap();
+        assert_eq!(root.quota_set.instances, 10);
+        assert_eq!(
+            serde_json::to_value(QuotaSetUpdateRoot {
+                quota_set: QuotaSetUpdate {
+                    cores: Some(-1),
+                    ..Default::default()
+                }
+            })
+            .unwrap(),
+            serde_json::json!({"quota_set": {"cores": -1}})
+        );
+    }
+
+    #[test]
+    fn test_parse_empty_tenant_usage() {
+        let root: TenantUsageRoot = serde_json::from_value(serde_json::json!({
+            "tenant_usage": {}
+        }))
+        .unwrap();
+        assert!(root.tenant_usage.is_none());
+    }
+
+    #[test]
+    fn test_parse_tenant_usage() {
+        let root: TenantUsageRoot = serde_json::from_value(serde_json::json!({
+            "tenant_usage": {
+                "server_usages": [{
+                    "ended_at": null,
+                    "flavor": "m1.tiny",
+                    "hours": 1.0,
+                    "instance_id": "1f1deceb",
+                    "local_gb": 1,
+                    "memory_mb": 512,
+                    "name": "instance-2",
+                    "started_at": "2018-10-09T11:29:04.166194",
+                    "state": "active",
+                    "tenant_id": "6f70656e",
+                    "uptime": 3600,
+                    "vcpus": 1
+                }],
+                "start": "2018-10-09T11:29:04.166194",
+                "stop": "2018-10-09T12:29:04.166194",
+                "tenant_id": "6f70656e",
+                "total_hours": 1.0,
+                "total_local_gb_usage": 1.0,
+                "total_memory_mb_usage": 512.0,
+                "total_vcpus_usage": 1.0
+            }
+        }))
+        .unwrap();
+        let usage = root.tenant_usage.unwrap();
+        assert_eq!(usage.project_id, "6f70656e");
+        assert_eq!(usage.server_usages.len(), 1);
+        assert_eq!(usage.server_usages[0].id, "1f1deceb");
+        assert!(usage.server_usages[0].ended_at.is_none());
+        assert_eq!(usage.total_memory_mb_usage, 512.0);
+    }
+
+    #[test]
+    fn test_parse_hypervisors() {
+        let root: HypervisorsRoot = serde_json::from_value(serde_json::json!({
+            "hypervisors": [{
+                "cpu_info": {"arch": "x86_64"},
+                "current_workload": 0,
+                "disk_available_least": -10,
+                "free_disk_gb": 1028,
+                "free_ram_mb": 7680,
+                "host_ip": "192.168.1.135",
+                "hypervisor_hostname": "host1",
+                "hypervisor_type": "fake",
+                "hypervisor_version": 1000,
+                "id": 2,
+                "local_gb": 1028,
+                "local_gb_used": 0,
+                "memory_mb": 8192,
+                "memory_mb_used": 512,
+                "running_vms": 0,
+                "service": {"host": "host1", "id": 6, "disabled_reason": null},
+                "state": "up",
+                "status": "enabled",
+                "vcpus": 2,
+                "vcpus_used": 0
+            }, {
+                "hypervisor_hostname": "host2",
+                "id": "b1e43b5f-eec1-44e0-9f10-7b4945c0226d",
+                "state": "down",
+                "status": "disabled"
+            }]
+        }))
+        .unwrap();
+        assert_eq!(root.hypervisors[0].id, "2");
+        assert_eq!(root.hypervisors[0].disk_available_least, Some(-10));
+        assert_eq!(root.hypervisors[0].service.as_ref().unwrap().id, "6");
+        assert_eq!(root.hypervisors[0].state, ComputeServiceState::Up);
+        assert_eq!(
+            root.hypervisors[1].id,
+            "b1e43b5f-eec1-44e0-9f10-7b4945c0226d"
+        );
+        assert_eq!(root.hypervisors[1].status, ComputeServiceStatus::Disabled);
+        assert!(root.hypervisors[1].vcpus.is_none());
+    }
+
+    #[test]
+    fn test_parse_compute_services() {
+        let root: ComputeServicesRoot = serde_json::from_value(serde_json::json!({
+            "services": [{
+                "id": "4c8bcbed-3127-4f43-9a07-0e7a5e4c2c1f",
+                "binary": "nova-compute",
+                "disabled_reason": "test2",
+                "host": "host1",
+                "state": "down",
+                "status": "disabled",
+                "updated_at": "2012-10-29T13:42:05.000000",
+                "forced_down": false,
+                "zone": "nova"
+            }]
+        }))
+        .unwrap();
+        let service = &root.services[0];
+        assert_eq!(service.disabled_reason.as_deref(), Some("test2"));
+        assert_eq!(service.status, ComputeServiceStatus::Disabled);
+        assert!(service.updated_at.is_some());
+
+        assert_eq!(
+            serde_json::to_value(ComputeServiceUpdate {
+                disabled_reason: Some("maintenance".into()),
+                status: Some(ComputeServiceStatus::Disabled),
+                ..ComputeServiceUpdate::default()
+            })
+            .unwrap(),
+            serde_json::json!({"disabled_reason": "maintenance", "status": "disabled"})
+        );
+    }
+
+    #[test]
+    fn test_parse_availability_zones() {
+        let root: AvailabilityZonesRoot = serde_json::from_value(serde_json::json!({
+            "availabilityZoneInfo": [{
+                "zoneName": "internal",
+                "zoneState": {"available": true},
+                "hosts": {
+                    "host1": {
+                        "nova-scheduler": {
+                            "active": true,
+                            "available": true,
+                            "updated_at": "2012-12-26T14:45:25.000000"
+                        }
+                    }
+                }
+            }, {
+                "zoneName": "nova",
+                "zoneState": {"available": false},
+                "hosts": null
+            }]
+        }))
+        .unwrap();
+        let zones = root.availability_zones;
+        assert_eq!(zones[0].name, "internal");
+        assert!(zones[0].hosts.as_ref().unwrap()["host1"]["nova-scheduler"].active);
+        assert!(!zones[1].state.available);
+        assert!(zones[1].hosts.is_none());
+    }
+
+    #[test]
+    fn test_server_status_unknown() {
+        let status: ServerStatus = serde_json::from_value(serde_json::json!("UNKNOWN")).unwrap();
+        assert_eq!(status, ServerStatus::default());
+        let status: ServerStatus = serde_json::from_value(serde_json::json!("ACTIVE")).unwrap();
+        assert_eq!(status, ServerStatus::Active);
+    }
+}
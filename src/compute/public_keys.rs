@@ -0,0 +1,207 @@
+// Copyright 2026 The rust-openstack Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Public key material for key pairs.
+
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use md5::{Digest, Md5};
+
+use super::super::{Error, ErrorKind, Result};
+use super::protocol::KeyPairType;
+
+/// An OpenSSH public key.
+///
+/// The key is checked to be well-formed when parsing, which allows detecting invalid key
+/// files before uploading them.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use openstack;
+///
+/// # async fn async_wrapper() {
+/// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+/// let public_key = openstack::compute::PublicKey::from_file("/home/user/.ssh/id_ed25519.pub")
+///     .await
+///     .expect("Invalid public key file");
+/// let keypair = os
+///     .new_keypair("my-key")
+///     .with_ssh_public_key(public_key)
+///     .create()
+///     .await
+///     .expect("Unable to import a key pair");
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicKey {
+    algorithm: String,
+    data: Vec<u8>,
+    comment: Option<String>,
+}
+
+fn invalid<S: Into<String>>(message: S) -> Error {
+    Error::new(ErrorKind::InvalidInput, message)
+}
+
+impl PublicKey {
+    /// Parse a public key in the OpenSSH format (`<algorithm> <base64 data> [comment]`).
+    pub fn parse(value: &str) -> Result<PublicKey> {
+        let mut lines = value
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'));
+        let line = lines.next().ok_or_else(|| invalid("Public key is empty"))?;
+        if lines.next().is_some() {
+            return Err(invalid("Expected exactly one public key"));
+        }
+
+        let mut parts = line.splitn(3, char::is_whitespace);
+        let algorithm = parts.next().unwrap_or_default();
+        let encoded = parts
+            .next()
+            .ok_or_else(|| invalid("Public key data is missing"))?;
+        let comment = parts
+            .next()
+            .map(str::trim)
+            .filter(|comment| !comment.is_empty())
+            .map(ToString::to_string);
+
+        let data = STANDARD
+            .decode(encoded)
+            .map_err(|err| invalid(format!("Public key data is not valid base64: {}", err)))?;
+        // The data starts with the algorithm name as a length-prefixed string.
+        let embedded = data
+            .get(..4)
+            .map(|len| u32::from_be_bytes([len[0], len[1], len[2], len[3]]) as usize)
+            .and_then(|len| data.get(4..4 + len));
+        if embedded != Some(algorithm.as_bytes()) {
+            return Err(invalid(format!(
+                "Public key data does not match algorithm {}",
+                algorithm
+            )));
+        }
+
+        Ok(PublicKey {
+            algorithm: algorithm.to_string(),
+            data,
+            comment,
+        })
+    }
+
+    /// Read and parse an OpenSSH public key file (e.g. `~/.ssh/id_rsa.pub`).
+    pub async fn from_file<P: AsRef<Path>>(path: P) -> Result<PublicKey> {
+        let path = path.as_ref();
+        let contents = tokio::fs::read_to_string(path).await.map_err(|err| {
+            invalid(format!(
+                "Cannot read public key from {}: {}",
+                path.display(),
+                err
+            ))
+        })?;
+        PublicKey::parse(&contents)
+    }
+
+    /// Key algorithm, e.g. `ssh-ed25519`.
+    #[inline]
+    pub fn algorithm(&self) -> &str {
+        &self.algorithm
+    }
+
+    /// Comment following the key (if any).
+    #[inline]
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+
+    /// MD5 fingerprint of the key in the form reported by the Compute service.
+    pub fn fingerprint(&self) -> String {
+        Md5::digest(&self.data)
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<Vec<_>>()
+            .join(":")
+    }
+
+    /// Key pair type to use with this key.
+    #[inline]
+    pub fn key_type(&self) -> KeyPairType {
+        KeyPairType::SSH
+    }
+}
+
+impl fmt::Display for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.algorithm, STANDARD.encode(&self.data))?;
+        if let Some(ref comment) = self.comment {
+            write!(f, " {}", comment)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for PublicKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<PublicKey> {
+        PublicKey::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::ErrorKind;
+    use super::PublicKey;
+
+    const KEY: &str = "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAILNuentY0S8aJWI8AMKVg9nEfVLrrmLds56md7uKk4bX user@host\n";
+
+    #[test]
+    fn test_parse_public_key() {
+        let key = PublicKey::parse(KEY).unwrap();
+        assert_eq!(key.algorithm(), "ssh-ed25519");
+        assert_eq!(key.comment(), Some("user@host"));
+        assert_eq!(key.to_string(), KEY.trim_end());
+        assert_eq!(
+            key.fingerprint(),
+            "67:6f:43:35:6e:7a:0d:2e:a1:be:59:98:f1:a3:12:c9"
+        );
+    }
+
+    #[test]
+    fn test_parse_public_key_without_comment() {
+        let key: PublicKey =
+            "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAILNuentY0S8aJWI8AMKVg9nEfVLrrmLds56md7uKk4bX"
+                .parse()
+                .unwrap();
+        assert_eq!(key.comment(), None);
+    }
+
+    #[test]
+    fn test_parse_invalid_public_key() {
+        for value in &[
+            "",
+            "ssh-ed25519",
+            "ssh-ed25519 not-base64!",
+            "ssh-rsa AAAAC3NzaC1lZDI1NTE5AAAAILNuentY0S8aJWI8AMKVg9nEfVLrrmLds56md7uKk4bX",
+            "ssh-ed25519 AAAA",
+        ] {
+            let err = PublicKey::parse(value).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidInput, "{}", value);
+        }
+    }
+}
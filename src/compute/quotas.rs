@@ -0,0 +1,367 @@
+// Copyright 2026 The rust-openstack Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compute quotas, limits and usage.
+
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset};
+
+use super::super::common::{ProjectRef, Refresh};
+use super::super::session::Session;
+use super::super::{ErrorKind, Result};
+use super::validation::{self, ServerProblem};
+use super::{api, protocol, KeyPairQuery};
+
+/// An absolute limit with its current usage.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ComputeLimit {
+    /// Maximum allowed value, `None` if unlimited.
+    pub max: Option<u64>,
+    /// Currently used value.
+    pub used: u64,
+}
+
+/// Absolute limits and current usage of the project.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ComputeLimits {
+    /// Number of virtual CPUs.
+    pub cores: ComputeLimit,
+    /// Number of servers.
+    pub instances: ComputeLimit,
+    /// Number of key pairs of the current user.
+    pub key_pairs: ComputeLimit,
+    /// RAM in MiB.
+    pub ram: ComputeLimit,
+    /// Maximum number of members in a server group, `None` if unlimited.
+    pub server_group_members: Option<u64>,
+    /// Number of server groups.
+    pub server_groups: ComputeLimit,
+}
+
+/// Compute quotas of a project.
+///
+/// Only available to administrators. A value of `-1` means no limit.
+#[derive(Clone, Debug)]
+pub struct QuotaSet {
+    session: Session,
+    project_id: String,
+    inner: protocol::QuotaSet,
+    dirty: HashSet<&'static str>,
+}
+
+fn limit(max: i64) -> Option<u64> {
+    if max < 0 {
+        None
+    } else {
+        Some(max as u64)
+    }
+}
+
+impl ComputeLimit {
+    fn new(max: i64, used: u64) -> ComputeLimit {
+        ComputeLimit {
+            max: limit(max),
+            used,
+        }
+    }
+
+    /// How much is left, `None` if unlimited.
+    pub fn remaining(&self) -> Option<u64> {
+        self.max.map(|max| max.saturating_sub(self.used))
+    }
+}
+
+impl ComputeLimits {
+    /// Fetch limits and usage of the current project.
+    pub(crate) async fn load(session: &Session) -> Result<ComputeLimits> {
+        let absolute = api::get_limits(session).await?;
+        // Nova does not report key pair usage, count them instead.
+        let key_pairs = KeyPairQuery::new(session.clone()).all().await?.len() as u64;
+        Ok(ComputeLimits::from_absolute(absolute, key_pairs))
+    }
+
+    /// Fetch limits and usage of the current project without counting key pairs.
+    ///
+    /// Enough for checking new servers, the usage of key pairs is left at zero.
+    pub(crate) async fn load_absolute(session: &Session) -> Result<ComputeLimits> {
+        let absolute = api::get_limits(session).await?;
+        Ok(ComputeLimits::from_absolute(absolute, 0))
+    }
+
+    /// Check that `count` servers of the given flavor fit into the limits.
+    ///
+    /// Fails with `AccessDenied` listing every exhausted limit.
+    pub(crate) fn check_servers(&self, flavor: &protocol::Flavor, count: u64) -> Result<()> {
+        validation::into_result(ErrorKind::AccessDenied, self.shortfalls(flavor, count))
+    }
+
+    /// List limits that `count` servers of the given flavor would exceed.
+    pub(crate) fn shortfalls(&self, flavor: &protocol::Flavor, count: u64) -> Vec<ServerProblem> {
+        [
+            ("instances", &self.instances, count),
+            ("cores", &self.cores, u64::from(flavor.vcpus) * count),
+            ("RAM (MiB)", &self.ram, flavor.ram * count),
+        ]
+        .iter()
+        .filter_map(|(resource, limit, requested)| match limit.remaining() {
+            Some(remaining) if remaining < *requested => Some(ServerProblem::QuotaExceeded {
+                resource,
+                requested: *requested,
+                remaining,
+            }),
+            _ => None,
+        })
+        .collect()
+    }
+
+    fn from_absolute(absolute: protocol::AbsoluteLimits, key_pairs: u64) -> ComputeLimits {
+        ComputeLimits {
+            cores: ComputeLimit::new(absolute.max_total_cores, absolute.total_cores_used),
+            instances: ComputeLimit::new(
+                absolute.max_total_instances,
+                absolute.total_instances_used,
+            ),
+            key_pairs: ComputeLimit::new(absolute.max_total_keypairs, key_pairs),
+            ram: ComputeLimit::new(absolute.max_total_ram_size, absolute.total_ram_used),
+            server_group_members: limit(absolute.max_server_group_members),
+            server_groups: ComputeLimit::new(
+                absolute.max_server_groups,
+                absolute.total_server_groups_used,
+            ),
+        }
+    }
+}
+
+impl QuotaSet {
+    /// Load quotas of a project.
+    pub(crate) async fn load<P: Into<ProjectRef>>(
+        session: Session,
+        project: P,
+    ) -> Result<QuotaSet> {
+        let project_id: String = project.into().into_verified(&session).await?.into();
+        let inner = api::get_quota_set(&session, &project_id).await?;
+        Ok(QuotaSet {
+            session,
+            project_id,
+            inner,
+            dirty: HashSet::new(),
+        })
+    }
+
+    transparent_property! {
+        #[doc = "Number of virtual CPUs."]
+        cores: i64
+    }
+
+    update_field! {
+        #[doc = "Update the number of virtual CPUs."]
+        set_cores, with_cores -> cores: i64
+    }
+
+    transparent_property! {
+        #[doc = "Number of servers."]
+        instances: i64
+    }
+
+    update_field! {
+        #[doc = "Update the number of servers."]
+        set_instances, with_instances -> instances: i64
+    }
+
+    transparent_property! {
+        #[doc = "Number of key pairs per user."]
+        key_pairs: i64
+    }
+
+    update_field! {
+        #[doc = "Update the number of key pairs per user."]
+        set_key_pairs, with_key_pairs -> key_pairs: i64
+    }
+
+    transparent_property! {
+        #[doc = "Number of metadata items per server."]
+        metadata_items: i64
+    }
+
+    update_field! {
+        #[doc = "Update the number of metadata items per server."]
+        set_metadata_items, with_metadata_items -> metadata_items: i64
+    }
+
+    /// ID of the project.
+    #[inline]
+    pub fn project_id(&self) -> &String {
+        &self.project_id
+    }
+
+    transparent_property! {
+        #[doc = "RAM in MiB."]
+        ram: i64
+    }
+
+    update_field! {
+        #[doc = "Update RAM in MiB."]
+        set_ram, with_ram -> ram: i64
+    }
+
+    transparent_property! {
+        #[doc = "Number of members per server group."]
+        server_group_members: i64
+    }
+
+    update_field! {
+        #[doc = "Update the number of members per server group."]
+        set_server_group_members, with_server_group_members -> server_group_members: i64
+    }
+
+    transparent_property! {
+        #[doc = "Number of server groups."]
+        server_groups: i64
+    }
+
+    update_field! {
+        #[doc = "Update the number of server groups."]
+        set_server_groups, with_server_groups -> server_groups: i64
+    }
+
+    /// Whether the quotas are modified.
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Save the changes to the quotas.
+    pub async fn save(&mut self) -> Result<()> {
+        let mut update = protocol::QuotaSetUpdate::default();
+        save_fields! {
+            self -> update: cores instances key_pairs metadata_items ram
+                server_group_members server_groups
+        };
+        self.inner = api::update_quota_set(&self.session, &self.project_id, update).await?;
+        self.dirty.clear();
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Refresh for QuotaSet {
+    /// Refresh the quotas.
+    async fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_quota_set(&self.session, &self.project_id).await?;
+        self.dirty.clear();
+        Ok(())
+    }
+}
+
+impl protocol::TenantUsage {
+    /// Load usage of a project over a period of time.
+    pub(crate) async fn load<P: Into<ProjectRef>>(
+        session: &Session,
+        project: P,
+        start: DateTime<FixedOffset>,
+        end: DateTime<FixedOffset>,
+    ) -> Result<protocol::TenantUsage> {
+        let project_id: String = project.into().into_verified(session).await?.into();
+        api::get_tenant_usage(session, project_id, start, end).await
+    }
+
+    /// Load usage of all projects over a period of time.
+    pub(crate) async fn load_all(
+        session: &Session,
+        start: DateTime<FixedOffset>,
+        end: DateTime<FixedOffset>,
+    ) -> Result<Vec<protocol::TenantUsage>> {
+        api::list_tenant_usages(session, start, end, true).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::ErrorKind;
+    use super::super::protocol;
+    use super::{ComputeLimit, ComputeLimits};
+
+    #[test]
+    fn test_limits_from_absolute() {
+        let absolute: protocol::AbsoluteLimits = serde_json::from_value(serde_json::json!({
+            "maxTotalCores": 20,
+            "maxTotalInstances": -1,
+            "maxTotalKeypairs": 100,
+            "maxTotalRAMSize": 51200,
+            "totalCoresUsed": 24,
+            "totalInstancesUsed": 2,
+            "totalRAMUsed": 4096
+        }))
+        .unwrap();
+        let limits = ComputeLimits::from_absolute(absolute, 3);
+        assert_eq!(limits.cores.remaining(), Some(0));
+        assert_eq!(limits.instances.max, None);
+        assert_eq!(limits.instances.remaining(), None);
+        assert_eq!(
+            limits.key_pairs,
+            ComputeLimit {
+                max: Some(100),
+                used: 3
+            }
+        );
+        assert_eq!(limits.ram.remaining(), Some(47104));
+        assert_eq!(limits.server_groups.max, None);
+        assert_eq!(limits.server_group_members, None);
+    }
+
+    #[test]
+    fn test_check_servers() {
+        let limits = ComputeLimits {
+            cores: ComputeLimit {
+                max: Some(20),
+                used: 16,
+            },
+            instances: ComputeLimit { max: None, used: 8 },
+            key_pairs: ComputeLimit {
+                max: Some(100),
+                used: 0,
+            },
+            ram: ComputeLimit {
+                max: Some(16384),
+                used: 8192,
+            },
+            server_group_members: None,
+            server_groups: ComputeLimit {
+                max: Some(10),
+                used: 0,
+            },
+        };
+        let flavor: protocol::Flavor = serde_json::from_value(serde_json::json!({
+            "disk": 10,
+            "id": "1",
+            "name": "m1.small",
+            "ram": 2048,
+            "rxtx_factor": 1.0,
+            "swap": "",
+            "vcpus": 2
+        }))
+        .unwrap();
+        assert!(limits.check_servers(&flavor, 2).is_ok());
+        let err = limits.check_servers(&flavor, 5).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::AccessDenied);
+        assert!(err
+            .to_string()
+            .contains("not enough cores quota: 10 requested, 4 remaining"));
+        assert!(err
+            .to_string()
+            .contains("not enough RAM (MiB) quota: 10240 requested, 8192 remaining"));
+        assert!(!err.to_string().contains("instances"));
+    }
+}
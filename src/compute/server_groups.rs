@@ -0,0 +1,241 @@
+// Copyright 2026 The rust-openstack Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Server group management via Compute API.
+
+use async_trait::async_trait;
+use futures::stream::{Stream, TryStreamExt};
+
+use super::super::common::{Refresh, ResourceIterator, ResourceQuery, ServerGroupRef};
+use super::super::session::Session;
+use super::super::utils::Query;
+use super::super::Result;
+use super::{api, protocol, Server};
+
+/// A query to server group list.
+#[derive(Clone, Debug)]
+pub struct ServerGroupQuery {
+    session: Session,
+    query: Query,
+}
+
+/// Structure representing a server group.
+#[derive(Clone, Debug)]
+pub struct ServerGroup {
+    session: Session,
+    inner: protocol::ServerGroup,
+}
+
+/// A request to create a server group.
+#[derive(Clone, Debug)]
+pub struct NewServerGroup {
+    session: Session,
+    inner: protocol::ServerGroupCreate,
+}
+
+impl ServerGroup {
+    /// Create a server group object.
+    pub(crate) fn from_inner(session: Session, inner: protocol::ServerGroup) -> ServerGroup {
+        ServerGroup { session, inner }
+    }
+
+    /// Load a ServerGroup object.
+    pub(crate) async fn new<Id: AsRef<str>>(session: Session, id: Id) -> Result<ServerGroup> {
+        let inner = api::get_server_group(&session, id).await?;
+        Ok(ServerGroup::from_inner(session, inner))
+    }
+
+    transparent_property! {
+        #[doc = "Unique ID."]
+        id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "IDs of servers in the group."]
+        members: ref Vec<String>
+    }
+
+    transparent_property! {
+        #[doc = "Server group name."]
+        name: ref String
+    }
+
+    /// Policy of the server group.
+    ///
+    /// May be `None` if the cloud reports no policy.
+    pub fn policy(&self) -> Option<protocol::ServerGroupPolicy> {
+        self.inner
+            .policy
+            .clone()
+            .or_else(|| self.inner.policies.first().cloned())
+    }
+
+    transparent_property! {
+        #[doc = "ID of the project the group belongs to (if known)."]
+        project_id: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "ID of the user that created the group (if known)."]
+        user_id: ref Option<String>
+    }
+
+    /// Delete the server group.
+    pub async fn delete(self) -> Result<()> {
+        api::delete_server_group(&self.session, &self.inner.id).await
+    }
+
+    /// Fetch the servers in the group.
+    pub async fn servers(&self) -> Result<Vec<Server>> {
+        let mut result = Vec::with_capacity(self.inner.members.len());
+        for id in &self.inner.members {
+            result.push(Server::load(self.session.clone(), id).await?);
+        }
+        Ok(result)
+    }
+}
+
+#[async_trait]
+impl Refresh for ServerGroup {
+    /// Refresh the server group.
+    async fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_server_group_by_id(&self.session, &self.inner.id).await?;
+        Ok(())
+    }
+}
+
+impl ServerGroupQuery {
+    pub(crate) fn new(session: Session) -> ServerGroupQuery {
+        ServerGroupQuery {
+            session,
+            query: Query::new(),
+        }
+    }
+
+    /// Return server groups from all projects (admin only).
+    pub fn all_projects(mut self) -> Self {
+        self.query.push("all_projects", true);
+        self
+    }
+
+    /// Convert this query into a stream executing the request.
+    ///
+    /// Returns a `TryStream`, which is a stream with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_stream(self) -> impl Stream<Item = Result<ServerGroup>> {
+        debug!("Fetching server groups with {:?}", self.query);
+        ResourceIterator::new(self).into_stream()
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_stream().try_collect().await`.
+    pub async fn all(self) -> Result<Vec<ServerGroup>> {
+        self.into_stream().try_collect().await
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub async fn one(self) -> Result<ServerGroup> {
+        debug!("Fetching one server group with {:?}", self.query);
+        ResourceIterator::new(self).one().await
+    }
+}
+
+#[async_trait]
+impl ResourceQuery for ServerGroupQuery {
+    type Item = ServerGroup;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    async fn can_paginate(&self) -> Result<bool> {
+        // Server groups are paginated with offsets rather than markers.
+        Ok(false)
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.id().clone()
+    }
+
+    async fn fetch_chunk(
+        &self,
+        _limit: Option<usize>,
+        _marker: Option<String>,
+    ) -> Result<Vec<Self::Item>> {
+        Ok(api::list_server_groups(&self.session, &self.query)
+            .await?
+            .into_iter()
+            .map(|item| ServerGroup::from_inner(self.session.clone(), item))
+            .collect())
+    }
+}
+
+impl NewServerGroup {
+    /// Start creating a server group.
+    pub(crate) fn new(
+        session: Session,
+        name: String,
+        policy: protocol::ServerGroupPolicy,
+    ) -> NewServerGroup {
+        NewServerGroup {
+            session,
+            inner: protocol::ServerGroupCreate {
+                name,
+                policies: Vec::new(),
+                policy: Some(policy),
+            },
+        }
+    }
+
+    /// Request creation of the server group.
+    ///
+    /// Fails with `IncompatibleApiVersion` for soft policies if the cloud does not support
+    /// compute API version 2.15.
+    pub async fn create(self) -> Result<ServerGroup> {
+        let inner = api::create_server_group(&self.session, self.inner).await?;
+        Ok(ServerGroup::from_inner(self.session, inner))
+    }
+
+    creation_inner_field! {
+        #[doc = "Set name of the server group."]
+        set_name, with_name -> name: String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set policy of the server group."]
+        set_policy, with_policy -> policy: optional protocol::ServerGroupPolicy
+    }
+}
+
+impl From<ServerGroup> for ServerGroupRef {
+    fn from(value: ServerGroup) -> ServerGroupRef {
+        ServerGroupRef::new_verified(value.inner.id)
+    }
+}
+
+#[cfg(feature = "compute")]
+impl ServerGroupRef {
+    /// Verify this reference and convert to an ID, if possible.
+    pub(crate) async fn into_verified(self, session: &Session) -> Result<ServerGroupRef> {
+        Ok(if self.verified {
+            self
+        } else {
+            ServerGroupRef::new_verified(api::get_server_group(session, &self.value).await?.id)
+        })
+    }
+}
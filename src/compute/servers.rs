@@ -14,34 +14,51 @@
 
 //! Server management via Compute API.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::time::Duration;
 
+use async_stream::try_stream;
 use async_trait::async_trait;
 use chrono::{DateTime, FixedOffset};
 use futures::stream::{Stream, TryStreamExt};
 use osauth::common::IdAndName;
 use serde::Serialize;
+use serde_json::Value;
+use tokio::time::{sleep, Instant};
 
+#[cfg(feature = "block-storage")]
+use super::super::block_storage::{Volume, VolumeStatus, VolumeStatusWaiter};
 use super::super::common::{
-    FlavorRef, ImageRef, KeyPairRef, NetworkRef, PortRef, ProjectRef, Refresh, ResourceIterator,
-    ResourceQuery, UserRef, VolumeRef,
+    ApiVersion, FlavorRef, ImageRef, KeyPairRef, NetworkRef, PortRef, ProjectRef, Refresh,
+    ResourceIterator, ResourceQuery, SecurityGroupRef, ServerGroupRef, UserRef, VolumeRef,
 };
 #[cfg(feature = "image")]
-use super::super::image::Image;
+use super::super::image::{Image, ImageCreationWaiter, ImageStatus};
+#[cfg(feature = "network")]
+use super::super::network::{Port, PortAttachmentWaiter, PortDetachmentWaiter, PortQuery};
 use super::super::session::Session;
 use super::super::utils::{unit_to_null, Query};
 use super::super::waiter::{DeletionWaiter, Waiter};
 use super::super::{Error, ErrorKind, Result, Sort};
-use super::{api, protocol, BlockDevice, KeyPair};
+use super::user_data::{self, MultipartUserData};
+#[cfg(feature = "image")]
+use super::validation::BootImage;
+use super::validation::{self, ServerProblem};
+use super::{api, protocol, BlockDevice, ComputeLimits, KeyPair, ServerGroup, ServerMigration};
+#[cfg(feature = "image")]
+use super::{BlockDeviceDestinationType, BlockDeviceSource};
 
 /// A query to server list.
+///
+/// Tag filters require compute API version 2.26.
 #[derive(Clone, Debug)]
 pub struct ServerQuery {
     session: Session,
     query: Query,
     can_paginate: bool,
+    tag_filters: bool,
+    api_version: Option<ApiVersion>,
 }
 
 /// A detailed query to server list.
@@ -52,11 +69,15 @@ pub struct DetailedServerQuery {
     inner: ServerQuery,
 }
 
+/// Number of last console lines fetched at once when streaming the console.
+const CONSOLE_STREAM_LINES: u32 = 1000;
+
 /// Structure representing a single server.
 #[derive(Clone, Debug)]
 pub struct Server {
     session: Session,
     inner: protocol::Server,
+    dirty: HashSet<&'static str>,
 }
 
 /// Structure representing a summary of a single server.
@@ -70,7 +91,33 @@ pub struct ServerSummary {
 #[derive(Debug)]
 pub struct ServerStatusWaiter<'server> {
     server: &'server mut Server,
+    progress: StatusProgress,
+}
+
+/// Progress of a server towards a status.
+#[derive(Debug)]
+struct StatusProgress {
+    target: protocol::ServerStatus,
+    alternative: Option<protocol::ServerStatus>,
+    transition: Option<protocol::ServerStatus>,
+}
+
+/// Waiter for a server to be moved to another host.
+///
+/// Yields the new host of the server if it is known.
+#[derive(Debug)]
+pub struct ServerMigrationWaiter<'server> {
+    server: &'server mut Server,
+    progress: MigrationProgress,
+}
+
+/// Progress of a server being moved to another host.
+#[derive(Debug)]
+struct MigrationProgress {
+    source_host: Option<String>,
     target: protocol::ServerStatus,
+    alternative: Option<protocol::ServerStatus>,
+    started: bool,
 }
 
 /// A virtual NIC of a new server.
@@ -95,15 +142,40 @@ pub struct NewServer {
     name: String,
     nics: Vec<ServerNIC>,
     block_devices: Vec<BlockDevice>,
+    security_groups: Vec<SecurityGroupRef>,
     user_data: Option<String>,
     config_drive: Option<bool>,
     availability_zone: Option<String>,
+    description: Option<String>,
+    tags: Vec<String>,
+    server_group: Option<ServerGroupRef>,
+    scheduler_hints: HashMap<String, Value>,
+    access_ipv4: Option<Ipv4Addr>,
+    access_ipv6: Option<Ipv6Addr>,
+    admin_password: Option<String>,
+    hostname: Option<String>,
+    personality: Vec<protocol::Personality>,
+    min_count: Option<u32>,
+    max_count: Option<u32>,
+    return_reservation_id: Option<bool>,
+    quota_check: bool,
+    validation: bool,
 }
 
 /// Waiter for server to be created.
 #[derive(Debug)]
 pub struct ServerCreationWaiter {
     server: Server,
+    admin_password: Option<String>,
+}
+
+/// Waiter for a batch of servers to be created.
+#[derive(Debug)]
+pub struct ServerBatchCreationWaiter {
+    session: Session,
+    reservation_id: String,
+    min_count: usize,
+    servers: Vec<Server>,
 }
 
 #[async_trait]
@@ -111,6 +183,7 @@ impl Refresh for Server {
     /// Refresh the server.
     async fn refresh(&mut self) -> Result<()> {
         self.inner = api::get_server_by_id(&self.session, &self.inner.id).await?;
+        self.dirty.clear();
         Ok(())
     }
 }
@@ -118,7 +191,11 @@ impl Refresh for Server {
 impl Server {
     /// Create a new Server object.
     pub(crate) fn new(session: Session, inner: protocol::Server) -> Result<Server> {
-        Ok(Server { session, inner })
+        Ok(Server {
+            session,
+            inner,
+            dirty: HashSet::new(),
+        })
     }
 
     /// Load a Server object.
@@ -157,13 +234,21 @@ impl Server {
         description: ref Option<String>
     }
 
+    update_field! {
+        #[doc = "Update the description."]
+        #[doc = ""]
+        #[doc = "Saving fails with `IncompatibleApiVersion` if the cloud does not support"]
+        #[doc = "compute API version 2.19."]
+        set_description, with_description -> description: optional String
+    }
+
     /// Identifier of the flavor used to create this server.
     ///
     /// This is only known in old API versions, and the flavor is not guaranteed to exist any more.
     #[inline]
     pub fn flavor_id(&self) -> Option<&String> {
         match self.inner.flavor {
-            protocol::AnyFlavor::Old(ref flavor) => Some(&flavor.id),
+            Some(protocol::AnyFlavor::Old(ref flavor)) => Some(&flavor.id),
             _ => None,
         }
     }
@@ -172,10 +257,12 @@ impl Server {
     ///
     /// It may not possible to reconstruct a real Flavor object out of a Server, so this call
     /// returns the corresponding information instead.
+    ///
+    /// Fails with `ResourceNotFound` if the server is in a cell that is down.
     #[inline]
     pub async fn flavor(&self) -> Result<protocol::ServerFlavor> {
         match self.inner.flavor {
-            protocol::AnyFlavor::Old(ref flavor) => {
+            Some(protocol::AnyFlavor::Old(ref flavor)) => {
                 let flavor = api::get_flavor(&self.session, &flavor.id).await?;
                 Ok(protocol::ServerFlavor {
                     ephemeral_size: flavor.ephemeral,
@@ -187,7 +274,11 @@ impl Server {
                     vcpu_count: flavor.vcpus,
                 })
             }
-            protocol::AnyFlavor::New(ref flavor) => Ok(flavor.clone()),
+            Some(protocol::AnyFlavor::New(ref flavor)) => Ok(flavor.clone()),
+            None => Err(Error::new(
+                ErrorKind::ResourceNotFound,
+                format!("Flavor of server {} is not known", self.inner.id),
+            )),
         }
     }
 
@@ -246,11 +337,25 @@ impl Server {
         }
     }
 
+    transparent_property! {
+        #[doc = "Compute host of the server (only available to administrators)."]
+        host: ref Option<String>
+    }
+
     transparent_property! {
         #[doc = "Instance name."]
         instance_name: ref Option<String>
     }
 
+    /// Fetch the ports attached to the server.
+    #[cfg(feature = "network")]
+    pub async fn interfaces(&self) -> Result<Vec<Port>> {
+        PortQuery::new(self.session.clone())
+            .with_device_id(self.inner.id.clone())
+            .all()
+            .await
+    }
+
     /// Fetch the key pair used for the server.
     pub async fn key_pair(&self) -> Result<KeyPair> {
         match self.inner.key_pair_name {
@@ -267,16 +372,40 @@ impl Server {
         key_pair_name: ref Option<String>
     }
 
+    transparent_property! {
+        #[doc = "Whether the server is locked (if known)."]
+        #[doc = ""]
+        #[doc = "Requires compute API version 2.9."]
+        locked: Option<bool>
+    }
+
+    transparent_property! {
+        #[doc = "Reason the server was locked with (if any)."]
+        #[doc = ""]
+        #[doc = "Requires compute API version 2.73."]
+        locked_reason: ref Option<String>
+    }
+
     transparent_property! {
         #[doc = "Server name."]
         name: ref String
     }
 
+    update_field! {
+        #[doc = "Update the name."]
+        set_name, with_name -> name
+    }
+
     transparent_property! {
         #[doc = "Metadata associated with the server."]
         metadata: ref HashMap<String, String>
     }
 
+    update_field_mut! {
+        #[doc = "Update the metadata (replaces all existing metadata on save)."]
+        metadata_mut, set_metadata, with_metadata -> metadata: HashMap<String, String>
+    }
+
     transparent_property! {
         #[doc = "Server power state."]
         power_state: protocol::ServerPowerState
@@ -284,12 +413,22 @@ impl Server {
 
     transparent_property! {
         #[doc = "Server status."]
-        status: protocol::ServerStatus
+        status: clone protocol::ServerStatus
     }
 
     transparent_property! {
-        #[doc = "Last update date and time."]
-        updated_at: DateTime<FixedOffset>
+        #[doc = "Tags of the server."]
+        #[doc = ""]
+        #[doc = "Always empty if the cloud does not support compute API version 2.26."]
+        tags: ref Vec<String>
+    }
+
+    /// Last update date and time.
+    ///
+    /// The creation time is returned for servers in a cell that is down.
+    #[inline]
+    pub fn updated_at(&self) -> DateTime<FixedOffset> {
+        self.inner.updated_at.unwrap_or(self.inner.created_at)
     }
 
     /// Delete the server.
@@ -302,40 +441,611 @@ impl Server {
         ))
     }
 
+    /// Add a tag to the server.
+    ///
+    /// Fails with `IncompatibleApiVersion` if the cloud does not support compute API
+    /// version 2.26.
+    pub async fn add_tag<S: Into<String>>(&mut self, tag: S) -> Result<()> {
+        let tag = tag.into();
+        api::add_server_tag(&self.session, &self.inner.id, &tag).await?;
+        if !self.inner.tags.contains(&tag) {
+            self.inner.tags.push(tag);
+        }
+        Ok(())
+    }
+
+    /// Attach an existing port to the server, optionally wait for the attachment to finish.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack::waiter::Waiter;
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// let server = os.get_server("web-1").await.expect("Unable to get a server");
+    /// let port = server
+    ///     .attach_interface("web-1-storage")
+    ///     .await
+    ///     .expect("Unable to request attaching a port")
+    ///     .wait()
+    ///     .await
+    ///     .expect("Port was not attached");
+    /// println!("Attached port {} to server {}", port.id(), server.id());
+    /// # }
+    /// ```
+    #[cfg(feature = "network")]
+    pub async fn attach_interface<P>(&self, port: P) -> Result<PortAttachmentWaiter>
+    where
+        P: Into<PortRef>,
+    {
+        let request = protocol::InterfaceAttachmentCreate {
+            port_id: Some(port.into().into_verified(&self.session).await?.into()),
+            ..Default::default()
+        };
+        self.attach_interface_with(request).await
+    }
+
+    /// Attach a new port on a network to the server, optionally wait for the attachment
+    /// to finish.
+    ///
+    /// The port is created by the Compute service, optionally with the given fixed IP.
+    /// It is deleted when detached.
+    #[cfg(feature = "network")]
+    pub async fn attach_network_interface<N>(
+        &self,
+        network: N,
+        fixed_ip: Option<IpAddr>,
+    ) -> Result<PortAttachmentWaiter>
+    where
+        N: Into<NetworkRef>,
+    {
+        let request = protocol::InterfaceAttachmentCreate {
+            fixed_ips: fixed_ip
+                .into_iter()
+                .map(|ip_address| protocol::InterfaceFixedIp { ip_address })
+                .collect(),
+            net_id: Some(network.into().into_verified(&self.session).await?.into()),
+            ..Default::default()
+        };
+        self.attach_interface_with(request).await
+    }
+
+    #[cfg(feature = "network")]
+    async fn attach_interface_with(
+        &self,
+        request: protocol::InterfaceAttachmentCreate,
+    ) -> Result<PortAttachmentWaiter> {
+        let attachment =
+            api::create_interface_attachment(&self.session, &self.inner.id, request).await?;
+        let port = Port::load(self.session.clone(), attachment.port_id).await?;
+        Ok(PortAttachmentWaiter::new(port, self.inner.id.clone()))
+    }
+
+    /// Attach a volume to the server, optionally wait for it to be in use.
+    ///
+    /// The device name is only a hint, the actual name may differ depending
+    /// on the hypervisor.
+    #[cfg(feature = "block-storage")]
+    pub async fn attach_volume<V>(
+        &self,
+        volume: V,
+        device: Option<String>,
+    ) -> Result<VolumeStatusWaiter>
+    where
+        V: Into<VolumeRef>,
+    {
+        let volume_id: String = volume.into().into_verified(&self.session).await?.into();
+        let request = protocol::VolumeAttachmentCreate {
+            device,
+            volumeId: volume_id.clone(),
+        };
+        let _ = api::create_volume_attachment(&self.session, &self.inner.id, request).await?;
+        let volume = Volume::new(self.session.clone(), volume_id).await?;
+        Ok(VolumeStatusWaiter::new(volume, VolumeStatus::InUse))
+    }
+
+    /// Create a backup image of the server, optionally wait for it to become active.
+    ///
+    /// Only `rotation` most recent backups of the given type (e.g. `daily`) are kept, older
+    /// ones are deleted. Returns `None` if no image is created, e.g. when `rotation` is zero.
+    #[cfg(feature = "image")]
+    pub async fn backup<S1, S2>(
+        &self,
+        name: S1,
+        backup_type: S2,
+        rotation: u32,
+    ) -> Result<Option<ImageCreationWaiter>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let request = protocol::CreateBackup {
+            name: name.into(),
+            backup_type: backup_type.into(),
+            rotation,
+            metadata: HashMap::new(),
+        };
+        match api::create_server_backup(&self.session, &self.inner.id, request).await? {
+            Some(image_id) => {
+                let image = Image::new(self.session.clone(), image_id).await?;
+                Ok(Some(ImageCreationWaiter::new(image)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Confirm a pending resize, optionally wait for it to finish.
+    ///
+    /// A server that was stopped before resizing goes back to `ShutOff`.
+    pub async fn confirm_resize(&mut self) -> Result<ServerStatusWaiter<'_>> {
+        self.action(ServerAction::ConfirmResize).await?;
+        Ok(self.status_waiter(
+            protocol::ServerStatus::Active,
+            Some(protocol::ServerStatus::ShutOff),
+        ))
+    }
+
+    /// Get the console output of the server.
+    ///
+    /// If `lines` is provided, only this number of the last lines is returned.
+    pub async fn console_output(&self, lines: Option<u32>) -> Result<String> {
+        api::get_console_output(&self.session, &self.inner.id, lines).await
+    }
+
+    /// Create an image (snapshot) of the server, optionally wait for it to become active.
+    ///
+    /// The metadata is added to the image properties.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::collections::HashMap;
+    ///
+    /// use openstack::waiter::Waiter;
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// let server = os.get_server("web-1").await.expect("Unable to get a server");
+    /// let image = server
+    ///     .create_image("web-1-snapshot", HashMap::new())
+    ///     .await
+    ///     .expect("Unable to request a snapshot")
+    ///     .wait()
+    ///     .await
+    ///     .expect("Snapshot failed");
+    /// println!("Created image {}", image.id());
+    /// # }
+    /// ```
+    #[cfg(feature = "image")]
+    pub async fn create_image<S: Into<String>>(
+        &self,
+        name: S,
+        metadata: HashMap<String, String>,
+    ) -> Result<ImageCreationWaiter> {
+        let request = protocol::CreateImage {
+            name: name.into(),
+            metadata,
+        };
+        let image_id = api::create_server_image(&self.session, &self.inner.id, request)
+            .await?
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidResponse,
+                    "Missing image ID in the response",
+                )
+            })?;
+        let image = Image::new(self.session.clone(), image_id).await?;
+        Ok(ImageCreationWaiter::new(image))
+    }
+
+    /// Delete a metadata item from the server.
+    pub async fn delete_metadata_item<S: AsRef<str>>(&mut self, key: S) -> Result<()> {
+        api::delete_server_metadata_item(&self.session, &self.inner.id, key.as_ref()).await?;
+        let _ = self.inner.metadata.remove(key.as_ref());
+        Ok(())
+    }
+
+    /// Detach a port from the server, optionally wait for the detachment to finish.
+    ///
+    /// Ports created by [attach_network_interface](#method.attach_network_interface)
+    /// are deleted, in this case the waiter returns `None`.
+    #[cfg(feature = "network")]
+    pub async fn detach_interface<P>(&self, port: P) -> Result<PortDetachmentWaiter>
+    where
+        P: Into<PortRef>,
+    {
+        let port_id: String = port.into().into_verified(&self.session).await?.into();
+        let port = Port::load(self.session.clone(), &port_id).await?;
+        api::delete_interface_attachment(&self.session, &self.inner.id, port_id).await?;
+        Ok(PortDetachmentWaiter::new(port, self.inner.id.clone()))
+    }
+
+    /// Detach a volume from the server, optionally wait for it to be available.
+    #[cfg(feature = "block-storage")]
+    pub async fn detach_volume<V>(&self, volume: V) -> Result<VolumeStatusWaiter>
+    where
+        V: Into<VolumeRef>,
+    {
+        let volume_id: String = volume.into().into_verified(&self.session).await?.into();
+        api::delete_volume_attachment(&self.session, &self.inner.id, &volume_id).await?;
+        let volume = Volume::new(self.session.clone(), volume_id).await?;
+        Ok(VolumeStatusWaiter::new(volume, VolumeStatus::Available))
+    }
+
+    /// Evacuate the server from a failed host, optionally wait for it to be rebuilt.
+    ///
+    /// The host is picked by the scheduler unless provided. A server that was stopped stays in
+    /// `ShutOff`. Only available to administrators, requires compute API version 2.14.
+    pub async fn evacuate(&mut self, host: Option<String>) -> Result<ServerMigrationWaiter<'_>> {
+        api::evacuate_server(&self.session, &self.inner.id, host).await?;
+        Ok(self.migration_waiter(
+            protocol::ServerStatus::Active,
+            Some(protocol::ServerStatus::ShutOff),
+        ))
+    }
+
+    /// Whether the server is modified.
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// List volumes attached to the server.
+    pub async fn list_volume_attachments(&self) -> Result<Vec<protocol::VolumeAttachment>> {
+        api::list_volume_attachments(&self.session, &self.inner.id).await
+    }
+
+    /// Live-migrate the server, optionally wait for the migration to finish.
+    ///
+    /// The host is picked by the scheduler unless provided, the cloud decides whether block
+    /// migration is needed. Only available to administrators, requires compute API
+    /// version 2.25.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    /// use openstack::waiter::Waiter;
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// let mut server = os.get_server("web-1").await.expect("Unable to get a server");
+    /// let host = server
+    ///     .live_migrate(None)
+    ///     .await
+    ///     .expect("Unable to request live migration")
+    ///     .wait()
+    ///     .await
+    ///     .expect("Live migration failed");
+    /// println!("Server is now on {:?}", host);
+    /// # }
+    /// ```
+    pub async fn live_migrate(
+        &mut self,
+        host: Option<String>,
+    ) -> Result<ServerMigrationWaiter<'_>> {
+        api::live_migrate_server(&self.session, &self.inner.id, host).await?;
+        Ok(self.migration_waiter(protocol::ServerStatus::Active, None))
+    }
+
+    /// Lock the server, preventing non-admin users from running actions on it.
+    ///
+    /// Locking does not change the server status, thus there is nothing to wait for.
+    pub async fn lock(&mut self) -> Result<()> {
+        self.action(ServerAction::Lock).await
+    }
+
+    /// Cold-migrate the server, optionally wait for the migration to finish.
+    ///
+    /// The resulting waiter finishes when the server reaches `VerifyingResize`, after which
+    /// the migration has to be confirmed with [confirm_resize](#method.confirm_resize) or
+    /// reverted with [revert_resize](#method.revert_resize).
+    ///
+    /// The host is picked by the scheduler unless provided. Only available to administrators,
+    /// providing a host requires compute API version 2.56.
+    pub async fn migrate(&mut self, host: Option<String>) -> Result<ServerMigrationWaiter<'_>> {
+        api::migrate_server(&self.session, &self.inner.id, host).await?;
+        Ok(self.migration_waiter(protocol::ServerStatus::VerifyingResize, None))
+    }
+
+    /// List in-progress live migrations of the server.
+    ///
+    /// Only available to administrators, requires compute API version 2.23.
+    pub async fn migrations(&self) -> Result<Vec<ServerMigration>> {
+        Ok(api::list_server_migrations(&self.session, &self.inner.id)
+            .await?
+            .into_iter()
+            .map(|item| ServerMigration::new(self.session.clone(), item))
+            .collect())
+    }
+
+    /// Pause the server, optionally wait for it to be paused.
+    pub async fn pause(&mut self) -> Result<ServerStatusWaiter<'_>> {
+        self.action(ServerAction::Pause).await?;
+        Ok(self.status_waiter(protocol::ServerStatus::Paused, None))
+    }
+
+    /// Rebuild the server with a new image and metadata.
+    ///
+    /// The metadata replaces all existing metadata of the server. A server that was stopped
+    /// before the rebuild stays in `ShutOff`. The returned waiter only finishes after the
+    /// server has been seen in `Rebuild`.
+    pub async fn rebuild<I>(
+        &mut self,
+        image: I,
+        metadata: HashMap<String, String>,
+    ) -> Result<ServerStatusWaiter<'_>>
+    where
+        I: Into<ImageRef>,
+    {
+        let image = image.into().into_verified(&self.session).await?;
+        self.action(ServerAction::Rebuild { image, metadata })
+            .await?;
+        let mut waiter = self.status_waiter(
+            protocol::ServerStatus::Active,
+            Some(protocol::ServerStatus::ShutOff),
+        );
+        waiter.progress.transition = Some(protocol::ServerStatus::Rebuild);
+        Ok(waiter)
+    }
+
     /// Reboot the server.
     pub async fn reboot(
         &mut self,
         reboot_type: protocol::RebootType,
     ) -> Result<ServerStatusWaiter<'_>> {
         self.action(ServerAction::Reboot { reboot_type }).await?;
-        Ok(ServerStatusWaiter {
-            server: self,
-            target: protocol::ServerStatus::Active,
+        Ok(self.status_waiter(protocol::ServerStatus::Active, None))
+    }
+
+    /// Remove a tag from the server.
+    ///
+    /// Fails with `IncompatibleApiVersion` if the cloud does not support compute API
+    /// version 2.26.
+    pub async fn remove_tag<S: AsRef<str>>(&mut self, tag: S) -> Result<()> {
+        api::delete_server_tag(&self.session, &self.inner.id, tag.as_ref()).await?;
+        self.inner.tags.retain(|item| item != tag.as_ref());
+        Ok(())
+    }
+
+    /// Get a URL of a remote console of the server.
+    ///
+    /// Fails with `IncompatibleApiVersion` if the cloud does not support compute API
+    /// version 2.6 (2.8 for the `Mks` protocol).
+    pub async fn remote_console(
+        &self,
+        protocol: protocol::RemoteConsoleProtocol,
+        console_type: protocol::RemoteConsoleType,
+    ) -> Result<protocol::RemoteConsole> {
+        let request = protocol::RemoteConsoleCreate {
+            protocol,
+            console_type,
+        };
+        api::create_remote_console(&self.session, &self.inner.id, request).await
+    }
+
+    /// Replace all tags of the server.
+    ///
+    /// Fails with `IncompatibleApiVersion` if the cloud does not support compute API
+    /// version 2.26.
+    pub async fn replace_tags(&mut self, tags: Vec<String>) -> Result<()> {
+        self.inner.tags = api::replace_server_tags(&self.session, &self.inner.id, tags).await?;
+        Ok(())
+    }
+
+    /// Put the server into rescue mode, optionally wait for it to be rescued.
+    ///
+    /// The server image is used for rescue unless `rescue_image` is provided.
+    pub async fn rescue(
+        &mut self,
+        rescue_image: Option<ImageRef>,
+    ) -> Result<ServerStatusWaiter<'_>> {
+        let rescue_image = match rescue_image {
+            Some(image) => Some(image.into_verified(&self.session).await?),
+            None => None,
+        };
+        self.action(ServerAction::Rescue {
+            admin_pass: None,
+            rescue_image,
         })
+        .await?;
+        Ok(self.status_waiter(protocol::ServerStatus::Rescuing, None))
+    }
+
+    /// Resize the server to a new flavor.
+    ///
+    /// The resulting waiter finishes when the server reaches `VerifyingResize`, after which
+    /// the resize has to be confirmed with [confirm_resize](#method.confirm_resize) or
+    /// reverted with [revert_resize](#method.revert_resize).
+    pub async fn resize<F>(&mut self, flavor: F) -> Result<ServerStatusWaiter<'_>>
+    where
+        F: Into<FlavorRef>,
+    {
+        let flavor = flavor.into().into_verified(&self.session).await?;
+        self.action(ServerAction::Resize { flavor }).await?;
+        Ok(self.status_waiter(protocol::ServerStatus::VerifyingResize, None))
+    }
+
+    /// Resume a suspended server, optionally wait for it to be active.
+    pub async fn resume(&mut self) -> Result<ServerStatusWaiter<'_>> {
+        self.action(ServerAction::Resume).await?;
+        Ok(self.status_waiter(protocol::ServerStatus::Active, None))
+    }
+
+    /// Revert a pending resize, optionally wait for it to finish.
+    ///
+    /// A server that was stopped before resizing goes back to `ShutOff`.
+    pub async fn revert_resize(&mut self) -> Result<ServerStatusWaiter<'_>> {
+        self.action(ServerAction::RevertResize).await?;
+        Ok(self.status_waiter(
+            protocol::ServerStatus::Active,
+            Some(protocol::ServerStatus::ShutOff),
+        ))
+    }
+
+    /// Save the changes to the server.
+    ///
+    /// Metadata, if modified, replaces all existing metadata of the server.
+    #[allow(clippy::field_reassign_with_default)]
+    pub async fn save(&mut self) -> Result<()> {
+        if self.dirty.contains("metadata") {
+            let _ = api::replace_server_metadata(
+                &self.session,
+                &self.inner.id,
+                self.inner.metadata.clone(),
+            )
+            .await?;
+        }
+
+        let mut update = protocol::ServerUpdate::default();
+        save_fields! {
+            self -> update: name
+        };
+        save_option_fields! {
+            self -> update: description
+        };
+        if update.name.is_some() || update.description.is_some() {
+            api::update_server(&self.session, &self.inner.id, update).await?;
+        }
+
+        // Only forget the changes once all of them are saved.
+        self.dirty.clear();
+        self.inner = api::get_server_by_id(&self.session, &self.inner.id).await?;
+        Ok(())
+    }
+
+    /// Set a metadata item on the server.
+    ///
+    /// Unlike `metadata_mut` followed by `save`, this does not affect other metadata items.
+    pub async fn set_metadata_item<S1, S2>(&mut self, key: S1, value: S2) -> Result<()>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let key = key.into();
+        let value = value.into();
+        api::set_server_metadata_item(&self.session, &self.inner.id, key.clone(), value.clone())
+            .await?;
+        let _ = self.inner.metadata.insert(key, value);
+        Ok(())
+    }
+
+    /// Fetch the server groups this server belongs to.
+    ///
+    /// Uses the group IDs reported with compute API version 2.71 when available, otherwise
+    /// searches the server groups of the current project.
+    pub async fn server_groups(&self) -> Result<Vec<ServerGroup>> {
+        if let Some(ref ids) = self.inner.server_groups {
+            let mut result = Vec::with_capacity(ids.len());
+            for id in ids {
+                let inner = api::get_server_group_by_id(&self.session, id).await?;
+                result.push(ServerGroup::from_inner(self.session.clone(), inner));
+            }
+            Ok(result)
+        } else {
+            Ok(api::list_server_groups(&self.session, &Query::new())
+                .await?
+                .into_iter()
+                .filter(|item| item.members.contains(&self.inner.id))
+                .map(|item| ServerGroup::from_inner(self.session.clone(), item))
+                .collect())
+        }
+    }
+
+    /// Shelve the server, optionally wait for it to be shelved.
+    ///
+    /// Depending on the cloud configuration, the server may be offloaded right away, so
+    /// `ShelvedOffloaded` is also accepted as a final state.
+    pub async fn shelve(&mut self) -> Result<ServerStatusWaiter<'_>> {
+        self.action(ServerAction::Shelve).await?;
+        Ok(self.status_waiter(
+            protocol::ServerStatus::Shelved,
+            Some(protocol::ServerStatus::ShelvedOffloaded),
+        ))
+    }
+
+    /// Offload a shelved server, optionally wait for it to be offloaded.
+    pub async fn shelve_offload(&mut self) -> Result<ServerStatusWaiter<'_>> {
+        self.action(ServerAction::ShelveOffload).await?;
+        Ok(self.status_waiter(protocol::ServerStatus::ShelvedOffloaded, None))
     }
 
     /// Start the server, optionally wait for it to be active.
     pub async fn start(&mut self) -> Result<ServerStatusWaiter<'_>> {
         self.action(ServerAction::Start).await?;
-        Ok(ServerStatusWaiter {
-            server: self,
-            target: protocol::ServerStatus::Active,
-        })
+        Ok(self.status_waiter(protocol::ServerStatus::Active, None))
     }
 
     /// Stop the server, optionally wait for it to be powered off.
     pub async fn stop(&mut self) -> Result<ServerStatusWaiter<'_>> {
         self.action(ServerAction::Stop).await?;
-        Ok(ServerStatusWaiter {
-            server: self,
-            target: protocol::ServerStatus::ShutOff,
-        })
+        Ok(self.status_waiter(protocol::ServerStatus::ShutOff, None))
+    }
+
+    /// Suspend the server, optionally wait for it to be suspended.
+    pub async fn suspend(&mut self) -> Result<ServerStatusWaiter<'_>> {
+        self.action(ServerAction::Suspend).await?;
+        Ok(self.status_waiter(protocol::ServerStatus::Suspended, None))
+    }
+
+    /// Unlock a locked server.
+    pub async fn unlock(&mut self) -> Result<()> {
+        self.action(ServerAction::Unlock).await
+    }
+
+    /// Unpause a paused server, optionally wait for it to be active.
+    pub async fn unpause(&mut self) -> Result<ServerStatusWaiter<'_>> {
+        self.action(ServerAction::Unpause).await?;
+        Ok(self.status_waiter(protocol::ServerStatus::Active, None))
+    }
+
+    /// Get the server out of rescue mode, optionally wait for it to be active.
+    pub async fn unrescue(&mut self) -> Result<ServerStatusWaiter<'_>> {
+        self.action(ServerAction::Unrescue).await?;
+        Ok(self.status_waiter(protocol::ServerStatus::Active, None))
+    }
+
+    /// Unshelve a shelved server, optionally wait for it to be active.
+    pub async fn unshelve(&mut self) -> Result<ServerStatusWaiter<'_>> {
+        self.action(ServerAction::Unshelve).await?;
+        Ok(self.status_waiter(protocol::ServerStatus::Active, None))
     }
 
     /// Run an action on the server.
     pub async fn action(&mut self, action: ServerAction) -> Result<()> {
         api::server_action_with_args(&self.session, &self.inner.id, action).await
     }
+
+    fn migration_waiter(
+        &mut self,
+        target: protocol::ServerStatus,
+        alternative: Option<protocol::ServerStatus>,
+    ) -> ServerMigrationWaiter<'_> {
+        ServerMigrationWaiter {
+            progress: MigrationProgress {
+                source_host: self.inner.host.clone(),
+                target,
+                alternative,
+                started: false,
+            },
+            server: self,
+        }
+    }
+
+    fn status_waiter(
+        &mut self,
+        target: protocol::ServerStatus,
+        alternative: Option<protocol::ServerStatus>,
+    ) -> ServerStatusWaiter<'_> {
+        ServerStatusWaiter {
+            server: self,
+            progress: StatusProgress {
+                target,
+                alternative,
+                transition: None,
+            },
+        }
+    }
 }
 
 /// An action to perform on a server.
@@ -343,6 +1053,25 @@ impl Server {
 #[non_exhaustive]
 #[allow(missing_copy_implementations)]
 pub enum ServerAction {
+    /// Confirms a pending resize.
+    #[serde(rename = "confirmResize", serialize_with = "unit_to_null")]
+    ConfirmResize,
+    /// Locks a server.
+    #[serde(rename = "lock", serialize_with = "unit_to_null")]
+    Lock,
+    /// Pauses a server.
+    #[serde(rename = "pause", serialize_with = "unit_to_null")]
+    Pause,
+    /// Rebuilds a server with a new image.
+    #[serde(rename = "rebuild")]
+    Rebuild {
+        /// The image to rebuild the server with.
+        #[serde(rename = "imageRef")]
+        image: ImageRef,
+        /// New metadata of the server (replaces the existing metadata).
+        #[serde(skip_serializing_if = "HashMap::is_empty")]
+        metadata: HashMap<String, String>,
+    },
     /// Reboots a server.
     #[serde(rename = "reboot")]
     Reboot {
@@ -350,12 +1079,56 @@ pub enum ServerAction {
         #[serde(rename = "type")]
         reboot_type: protocol::RebootType,
     },
+    /// Puts a server into rescue mode.
+    #[serde(rename = "rescue")]
+    Rescue {
+        /// Password for the rescued server (generated if not provided).
+        #[serde(rename = "adminPass", skip_serializing_if = "Option::is_none")]
+        admin_pass: Option<String>,
+        /// The image to rescue the server with (the server's image if not provided).
+        #[serde(rename = "rescue_image_ref", skip_serializing_if = "Option::is_none")]
+        rescue_image: Option<ImageRef>,
+    },
+    /// Resizes a server to a new flavor.
+    #[serde(rename = "resize")]
+    Resize {
+        /// The new flavor.
+        #[serde(rename = "flavorRef")]
+        flavor: FlavorRef,
+    },
+    /// Resumes a suspended server.
+    #[serde(rename = "resume", serialize_with = "unit_to_null")]
+    Resume,
+    /// Reverts a pending resize.
+    #[serde(rename = "revertResize", serialize_with = "unit_to_null")]
+    RevertResize,
+    /// Shelves a server.
+    #[serde(rename = "shelve", serialize_with = "unit_to_null")]
+    Shelve,
+    /// Offloads a shelved server.
+    #[serde(rename = "shelveOffload", serialize_with = "unit_to_null")]
+    ShelveOffload,
     /// Starts a stopped server.
     #[serde(rename = "os-start", serialize_with = "unit_to_null")]
     Start,
     /// Stops a running server.
     #[serde(rename = "os-stop", serialize_with = "unit_to_null")]
     Stop,
+    /// Suspends a server.
+    #[serde(rename = "suspend", serialize_with = "unit_to_null")]
+    Suspend,
+    /// Unlocks a locked server.
+    #[serde(rename = "unlock", serialize_with = "unit_to_null")]
+    Unlock,
+    /// Unpauses a paused server.
+    #[serde(rename = "unpause", serialize_with = "unit_to_null")]
+    Unpause,
+    /// Gets a server out of rescue mode.
+    #[serde(rename = "unrescue", serialize_with = "unit_to_null")]
+    Unrescue,
+    /// Unshelves a shelved server.
+    #[serde(rename = "unshelve", serialize_with = "unit_to_null")]
+    Unshelve,
 }
 
 #[async_trait]
@@ -375,32 +1148,47 @@ impl<'server> Waiter<(), Error> for ServerStatusWaiter<'server> {
             format!(
                 "Timeout waiting for server {} to reach state {}",
                 self.server.id(),
-                self.target
+                self.progress.target
             ),
         )
     }
 
     async fn poll(&mut self) -> Result<Option<()>> {
         self.server.refresh().await?;
-        if self.server.status() == self.target {
-            debug!("Server {} reached state {}", self.server.id(), self.target);
-            Ok(Some(()))
-        } else if self.server.status() == protocol::ServerStatus::Error {
-            debug!(
+        self.progress.update(self.server.id(), self.server.status())
+    }
+}
+
+impl StatusProgress {
+    /// Update the progress with the current status of the server.
+    ///
+    /// If a transition status is set, the target only counts once it has been seen, since
+    /// the server may already be in the target status before the operation starts.
+    fn update(&mut self, id: &str, status: protocol::ServerStatus) -> Result<Option<()>> {
+        if self.transition.as_ref() == Some(&status) {
+            self.transition = None;
+        }
+
+        if self.transition.is_none()
+            && (status == self.target || self.alternative.as_ref() == Some(&status))
+        {
+            debug!("Server {} reached state {}", id, status);
+            Ok(Some(()))
+        } else if status == protocol::ServerStatus::Error {
+            debug!(
                 "Failed to move server {} to {} - status is ERROR",
-                self.server.id(),
-                self.target
+                id, self.target
             );
             Err(Error::new(
                 ErrorKind::OperationFailed,
-                format!("Server {} got into ERROR state", self.server.id()),
+                format!("Server {} got into ERROR state", id),
             ))
         } else {
             trace!(
                 "Still waiting for server {} to get to state {}, current is {}",
-                self.server.id(),
+                id,
                 self.target,
-                self.server.status()
+                status
             );
             Ok(None)
         }
@@ -414,6 +1202,110 @@ impl<'server> ServerStatusWaiter<'server> {
     }
 }
 
+#[async_trait]
+impl<'server> Waiter<Option<String>, Error> for ServerMigrationWaiter<'server> {
+    fn default_wait_timeout(&self) -> Option<Duration> {
+        Some(Duration::new(1800, 0))
+    }
+
+    fn default_delay(&self) -> Duration {
+        Duration::new(5, 0)
+    }
+
+    fn timeout_error(&self) -> Error {
+        Error::new(
+            ErrorKind::OperationTimedOut,
+            format!(
+                "Timeout waiting for server {} to be moved, current state is {}",
+                self.server.id(),
+                self.server.status()
+            ),
+        )
+    }
+
+    async fn poll(&mut self) -> Result<Option<Option<String>>> {
+        self.server.refresh().await?;
+        self.progress.update(
+            self.server.id(),
+            self.server.status(),
+            self.server.host().clone(),
+        )
+    }
+}
+
+impl MigrationProgress {
+    /// Update the progress with the current state of the server.
+    ///
+    /// The server is still in its original state right after the request, so the target
+    /// state only counts once the move has been seen in progress or the host has changed.
+    fn update(
+        &mut self,
+        id: &str,
+        status: protocol::ServerStatus,
+        host: Option<String>,
+    ) -> Result<Option<Option<String>>> {
+        if status == protocol::ServerStatus::Error {
+            debug!("Failed to move server {} - status is ERROR", id);
+            return Err(Error::new(
+                ErrorKind::OperationFailed,
+                format!("Server {} got into ERROR state", id),
+            ));
+        }
+
+        let moved = host.is_some() && self.source_host.is_some() && host != self.source_host;
+        if moved
+            || matches!(
+                status,
+                protocol::ServerStatus::Migrating
+                    | protocol::ServerStatus::Rebuild
+                    | protocol::ServerStatus::Resizing
+            )
+        {
+            self.started = true;
+        }
+
+        if self.started && (status == self.target || self.alternative.as_ref() == Some(&status)) {
+            if host.is_some() && host == self.source_host {
+                // Failed migrations put the server back to its original state.
+                debug!(
+                    "Server {} reached state {} but is still on host {:?}",
+                    id, status, host
+                );
+                return Err(Error::new(
+                    ErrorKind::OperationFailed,
+                    format!(
+                        "Server {} is still on host {} after the migration",
+                        id,
+                        host.unwrap_or_default()
+                    ),
+                ));
+            }
+            debug!("Server {} reached state {} on host {:?}", id, status, host);
+            Ok(Some(host))
+        } else {
+            trace!(
+                "Still waiting for server {} to get to state {}, current is {}",
+                id,
+                self.target,
+                status
+            );
+            Ok(None)
+        }
+    }
+}
+
+impl<'server> ServerMigrationWaiter<'server> {
+    /// Current state of the server.
+    pub fn current_state(&self) -> &Server {
+        self.server
+    }
+
+    /// Host of the server before the migration (if known).
+    pub fn source_host(&self) -> Option<&String> {
+        self.progress.source_host.as_ref()
+    }
+}
+
 impl ServerSummary {
     transparent_property! {
         #[doc = "Server unique ID."]
@@ -443,6 +1335,8 @@ impl ServerQuery {
             session,
             query: Query::new(),
             can_paginate: true,
+            tag_filters: false,
+            api_version: None,
         }
     }
 
@@ -478,6 +1372,15 @@ impl ServerQuery {
         self
     }
 
+    /// Pin the compute API version instead of negotiating it.
+    ///
+    /// Fails with `IncompatibleApiVersion` if the cloud does not support the version or if
+    /// it is too old for the filters used.
+    pub fn with_api_version(mut self, version: ApiVersion) -> Self {
+        self.api_version = Some(version);
+        self
+    }
+
     query_filter! {
         #[doc = "Filter by IPv4 address that should be used to access the server."]
         set_access_ip_v4, with_access_ip_v4 -> access_ip_v4: Ipv4Addr
@@ -528,6 +1431,11 @@ impl ServerQuery {
         set_project, with_project -> project_id: ProjectRef
     }
 
+    query_filter! {
+        #[doc = "Filter by reservation ID (shared by servers created in one request)."]
+        set_reservation_id, with_reservation_id -> reservation_id: String
+    }
+
     query_filter! {
         #[doc = "Filter by server status."]
         set_status, with_status -> status: protocol::ServerStatus
@@ -538,6 +1446,58 @@ impl ServerQuery {
         set_user, with_user -> user_id: UserRef
     }
 
+    /// Filter by comma-separated tags (servers must have all of them).
+    pub fn set_tags<T: Into<String>>(&mut self, value: T) {
+        self.tag_filters = true;
+        self.query.push_str("tags", value);
+    }
+
+    /// Filter by comma-separated tags (servers must have all of them).
+    #[inline]
+    pub fn with_tags<T: Into<String>>(mut self, value: T) -> Self {
+        self.set_tags(value);
+        self
+    }
+
+    /// Filter by comma-separated tags (servers must have at least one of them).
+    pub fn set_tags_any<T: Into<String>>(&mut self, value: T) {
+        self.tag_filters = true;
+        self.query.push_str("tags-any", value);
+    }
+
+    /// Filter by comma-separated tags (servers must have at least one of them).
+    #[inline]
+    pub fn with_tags_any<T: Into<String>>(mut self, value: T) -> Self {
+        self.set_tags_any(value);
+        self
+    }
+
+    /// Exclude servers that have all of the comma-separated tags.
+    pub fn set_not_tags<T: Into<String>>(&mut self, value: T) {
+        self.tag_filters = true;
+        self.query.push_str("not-tags", value);
+    }
+
+    /// Exclude servers that have all of the comma-separated tags.
+    #[inline]
+    pub fn with_not_tags<T: Into<String>>(mut self, value: T) -> Self {
+        self.set_not_tags(value);
+        self
+    }
+
+    /// Exclude servers that have any of the comma-separated tags.
+    pub fn set_not_tags_any<T: Into<String>>(&mut self, value: T) {
+        self.tag_filters = true;
+        self.query.push_str("not-tags-any", value);
+    }
+
+    /// Exclude servers that have any of the comma-separated tags.
+    #[inline]
+    pub fn with_not_tags_any<T: Into<String>>(mut self, value: T) -> Self {
+        self.set_not_tags_any(value);
+        self
+    }
+
     /// Convert this query into a detailed query.
     ///
     /// Detailed queries return full `Server` objects instead of just `ServerSummary`.
@@ -604,8 +1564,11 @@ impl ResourceQuery for ServerQuery {
         limit: Option<usize>,
         marker: Option<String>,
     ) -> Result<Vec<Self::Item>> {
+        if self.tag_filters {
+            api::require_server_tag_filters(&self.session, self.api_version).await?;
+        }
         let query = self.query.with_marker_and_limit(limit, marker);
-        Ok(api::list_servers(&self.session, &query)
+        Ok(api::list_servers(&self.session, &query, self.api_version)
             .await?
             .into_iter()
             .map(|srv| ServerSummary {
@@ -658,8 +1621,12 @@ impl ResourceQuery for DetailedServerQuery {
         limit: Option<usize>,
         marker: Option<String>,
     ) -> Result<Vec<Self::Item>> {
+        if self.inner.tag_filters {
+            api::require_server_tag_filters(&self.inner.session, self.inner.api_version).await?;
+        }
         let query = self.inner.query.with_marker_and_limit(limit, marker);
-        let servers = api::list_servers_detail(&self.inner.session, &query).await?;
+        let servers =
+            api::list_servers_detail(&self.inner.session, &query, self.inner.api_version).await?;
         let mut result = Vec::with_capacity(servers.len());
         for srv in servers {
             result.push(Server::new(self.inner.session.clone(), srv)?);
@@ -680,6 +1647,42 @@ impl From<ServerQuery> for DetailedServerQuery {
     }
 }
 
+/// Check that the requested availability zone exists and is available.
+///
+/// The zone may be followed by a host and a node (`zone:host:node`), only the zone
+/// itself is checked. The check is skipped if the zones cannot be listed.
+async fn check_availability_zone(session: &Session, availability_zone: &str) -> Result<()> {
+    let name = availability_zone.split(':').next().unwrap_or_default();
+    if name.is_empty() {
+        // Empty zone with a host means "any zone" for administrators.
+        return Ok(());
+    }
+
+    let zones = match api::list_availability_zones(session, false).await {
+        Ok(zones) => zones,
+        Err(err) if err.kind() == ErrorKind::AccessDenied => {
+            debug!(
+                "Cannot list availability zones, skipping validation: {}",
+                err
+            );
+            return Ok(());
+        }
+        Err(err) => return Err(err),
+    };
+
+    match zones.into_iter().find(|zone| zone.name == name) {
+        Some(zone) if zone.state.available => Ok(()),
+        Some(_) => Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("Availability zone {} is not available", name),
+        )),
+        None => Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("Availability zone {} does not exist", name),
+        )),
+    }
+}
+
 async fn convert_networks(
     session: &Session,
     networks: Vec<ServerNIC>,
@@ -711,22 +1714,328 @@ impl NewServer {
             name,
             nics: Vec::new(),
             block_devices: Vec::new(),
+            security_groups: Vec::new(),
             user_data: None,
             config_drive: None,
             availability_zone: None,
+            description: None,
+            tags: Vec::new(),
+            server_group: None,
+            scheduler_hints: HashMap::new(),
+            access_ipv4: None,
+            access_ipv6: None,
+            admin_password: None,
+            hostname: None,
+            personality: Vec::new(),
+            min_count: None,
+            max_count: None,
+            return_reservation_id: None,
+            quota_check: false,
+            validation: false,
+        }
+    }
+
+    /// Check that enough compute quota is left for the new servers.
+    ///
+    /// Checks the number of instances, cores and RAM for the minimum number of requested
+    /// servers. Fails with `AccessDenied` listing every exhausted limit.
+    pub async fn check_quota(&self) -> Result<()> {
+        let flavor = api::get_flavor(&self.session, &self.flavor).await?;
+        let count = self.min_count.or(self.max_count).unwrap_or(1);
+        ComputeLimits::load_absolute(&self.session)
+            .await?
+            .check_servers(&flavor, u64::from(count))
+    }
+
+    /// Find problems that would prevent the new servers from being created.
+    ///
+    /// Resolves the flavor, the image (including the image of the boot volume), the key pair
+    /// and the networks, then checks the image against the flavor, the size of the boot
+    /// volume and the compute quota. Only fails if the cloud cannot be queried.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// let problems = os
+    ///     .new_server("web-1", "m1.small")
+    ///     .with_image("centos7")
+    ///     .with_keypair("default")
+    ///     .find_problems()
+    ///     .await
+    ///     .expect("Unable to validate the server");
+    /// for problem in problems {
+    ///     eprintln!("{}", problem);
+    /// }
+    /// # }
+    /// ```
+    pub async fn find_problems(&self) -> Result<Vec<ServerProblem>> {
+        let mut problems = Vec::new();
+
+        let flavor = validation::not_found(api::get_flavor(&self.session, &self.flavor).await)?;
+        if flavor.is_none() {
+            problems.push(ServerProblem::FlavorNotFound(self.flavor.to_string()));
+        }
+
+        #[cfg(feature = "image")]
+        {
+            let boot_device = self
+                .block_devices
+                .iter()
+                .find(|bd| bd.boot_index == Some(0));
+            let (image, from_volume, volume_gib) = match boot_device {
+                Some(bd) => {
+                    let from_volume = bd.destination_type == BlockDeviceDestinationType::Volume;
+                    match bd.source {
+                        Some(BlockDeviceSource::Image(ref image)) => {
+                            (Some(image), from_volume, bd.size_gib)
+                        }
+                        _ => (self.image.as_ref(), from_volume, None),
+                    }
+                }
+                None => (self.image.as_ref(), false, None),
+            };
+            if let Some(image_ref) = image {
+                match validation::not_found(Image::new(self.session.clone(), image_ref).await)? {
+                    Some(image) => {
+                        let boot_image = BootImage {
+                            id: image.id().clone(),
+                            status: if image.status() == ImageStatus::Active {
+                                None
+                            } else {
+                                Some(image.status().to_string())
+                            },
+                            min_disk: image.minimum_required_disk(),
+                            min_ram: image.minimum_required_ram(),
+                            size: image.size(),
+                        };
+                        problems.extend(validation::check_boot_image(
+                            &boot_image,
+                            flavor.as_ref(),
+                            from_volume,
+                            volume_gib,
+                        ));
+                    }
+                    None => problems.push(ServerProblem::ImageNotFound(image_ref.to_string())),
+                }
+            }
+        }
+
+        if let Some(ref keypair) = self.keypair {
+            if validation::not_found(keypair.clone().into_verified(&self.session).await)?.is_none()
+            {
+                problems.push(ServerProblem::KeyPairNotFound(keypair.to_string()));
+            }
+        }
+
+        for nic in &self.nics {
+            match nic {
+                ServerNIC::FromNetwork(network) => {
+                    if validation::not_found(network.clone().into_verified(&self.session).await)?
+                        .is_none()
+                    {
+                        problems.push(ServerProblem::NetworkNotFound(network.to_string()));
+                    }
+                }
+                ServerNIC::WithPort(port) => {
+                    if validation::not_found(port.clone().into_verified(&self.session).await)?
+                        .is_none()
+                    {
+                        problems.push(ServerProblem::PortNotFound(port.to_string()));
+                    }
+                }
+                ServerNIC::WithFixedIp(..) => {}
+            }
+        }
+
+        if let Some(ref flavor) = flavor {
+            let count = self.min_count.or(self.max_count).unwrap_or(1);
+            problems.extend(
+                ComputeLimits::load_absolute(&self.session)
+                    .await?
+                    .shortfalls(flavor, u64::from(count)),
+            );
         }
+
+        Ok(problems)
+    }
+
+    /// Validate the new servers before creating them.
+    ///
+    /// Fails with `InvalidInput` listing every problem found by
+    /// [find_problems](#method.find_problems).
+    pub async fn validate(&self) -> Result<()> {
+        validation::into_result(ErrorKind::InvalidInput, self.find_problems().await?)
     }
 
     /// Request creation of the server.
+    ///
+    /// If several servers are requested with [with_count](#method.with_count), the waiter
+    /// only tracks the first of them, use [create_batch](#method.create_batch) to wait for
+    /// all of them.
+    ///
+    /// Fails with `InvalidInput` if the user data exceeds the size accepted by the cloud
+    /// or if the requested availability zone does not exist or is not available. With
+    /// [with_quota_check](#method.with_quota_check) also fails with `AccessDenied` if not
+    /// enough compute quota is left, with [with_validation](#method.with_validation) fails
+    /// with `InvalidInput` if [validate](#method.validate) finds any problems.
     pub async fn create(self) -> Result<ServerCreationWaiter> {
+        let session = self.session.clone();
+        match self.send().await? {
+            protocol::CreatedServerRoot::Server { server } => Ok(ServerCreationWaiter {
+                server: Server::load(session, server.id).await?,
+                admin_password: server.admin_pass,
+            }),
+            protocol::CreatedServerRoot::Reservation { reservation_id } => {
+                let server = ServerQuery::new(session)
+                    .with_reservation_id(reservation_id.clone())
+                    .sort_by(Sort::Asc(protocol::ServerSortKey::LaunchIndex))
+                    .detailed()
+                    .all()
+                    .await?
+                    .into_iter()
+                    .next()
+                    .ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::ResourceNotFound,
+                            format!("No servers found for reservation {}", reservation_id),
+                        )
+                    })?;
+                Ok(ServerCreationWaiter {
+                    server,
+                    admin_password: None,
+                })
+            }
+        }
+    }
+
+    /// Request creation of a batch of servers.
+    ///
+    /// The returned waiter finds all servers by their reservation ID and waits for all of
+    /// them to become `Active`. Use [with_count](#method.with_count) to set the number of
+    /// servers.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    /// use openstack::waiter::Waiter;
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// let waiter = os
+    ///     .new_server("worker", "m1.small")
+    ///     .with_image("centos7")
+    ///     .with_count(10, 10)
+    ///     .create_batch()
+    ///     .await
+    ///     .expect("Unable to request servers");
+    /// let reservation_id = waiter.reservation_id().clone();
+    /// if let Err(err) = waiter.wait().await {
+    ///     eprintln!("Failed to create workers: {}", err);
+    ///     // Clean up the whole batch.
+    ///     for server in os
+    ///         .find_servers()
+    ///         .with_reservation_id(reservation_id)
+    ///         .all()
+    ///         .await
+    ///         .expect("Unable to list servers")
+    ///     {
+    ///         server.delete().await.expect("Unable to delete a server");
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn create_batch(mut self) -> Result<ServerBatchCreationWaiter> {
+        self.return_reservation_id = Some(true);
+        let min_count = self.min_count.unwrap_or(1) as usize;
+        let session = self.session.clone();
+        match self.send().await? {
+            protocol::CreatedServerRoot::Reservation { reservation_id } => {
+                debug!("Requested servers with reservation {}", reservation_id);
+                Ok(ServerBatchCreationWaiter {
+                    session,
+                    reservation_id,
+                    min_count,
+                    servers: Vec::new(),
+                })
+            }
+            protocol::CreatedServerRoot::Server { server } => Err(Error::new(
+                ErrorKind::InvalidResponse,
+                format!(
+                    "Expected a reservation ID, got server {} instead",
+                    server.id
+                ),
+            )),
+        }
+    }
+
+    async fn send(self) -> Result<protocol::CreatedServerRoot> {
+        if let Some(ref data) = self.user_data {
+            user_data::check_size(data)?;
+        }
+
+        match (self.min_count, self.max_count) {
+            (Some(0), _) | (_, Some(0)) => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "Number of servers must be positive",
+                ));
+            }
+            (Some(min), Some(max)) if min > max => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!(
+                        "Minimum number of servers {} is greater than maximum {}",
+                        min, max
+                    ),
+                ));
+            }
+            _ => {}
+        }
+
+        if self.validation {
+            self.validate().await?;
+        } else if self.quota_check {
+            self.check_quota().await?;
+        }
+
+        if let Some(ref availability_zone) = self.availability_zone {
+            check_availability_zone(&self.session, availability_zone).await?;
+        }
+
         let mut block_devices = Vec::with_capacity(self.block_devices.len());
         for bd in self.block_devices {
             block_devices.push(bd.into_verified(&self.session).await?);
         }
 
+        let mut security_groups = Vec::with_capacity(self.security_groups.len());
+        for sg in self.security_groups {
+            security_groups.push(protocol::ServerSecurityGroup {
+                // Nova accepts both names and IDs here.
+                name: sg.into_verified(&self.session).await?.into(),
+            });
+        }
+
+        let mut scheduler_hints = self.scheduler_hints;
+        if let Some(group) = self.server_group {
+            let _ = scheduler_hints.insert(
+                "group".to_string(),
+                Value::String(group.into_verified(&self.session).await?.into()),
+            );
+        }
+
         let request = protocol::ServerCreate {
+            access_ipv4: self.access_ipv4,
+            access_ipv6: self.access_ipv6,
+            admin_pass: self.admin_password,
             block_devices,
+            description: self.description,
             flavorRef: self.flavor.into_verified(&self.session).await?.into(),
+            hostname: self.hostname,
             imageRef: match self.image {
                 Some(img) => Some(img.into_verified(&self.session).await?.into()),
                 None => None,
@@ -735,18 +2044,22 @@ impl NewServer {
                 Some(item) => Some(item.into_verified(&self.session).await?.into()),
                 None => None,
             },
+            max_count: self.max_count,
             metadata: self.metadata,
+            min_count: self.min_count,
             name: self.name,
             networks: convert_networks(&self.session, self.nics).await?,
+            personality: self.personality,
+            return_reservation_id: self.return_reservation_id,
+            security_groups,
+            tags: self.tags,
             user_data: self.user_data,
             config_drive: self.config_drive,
             availability_zone: self.availability_zone,
+            scheduler_hints,
         };
 
-        let server_ref = api::create_server(&self.session, request).await?;
-        Ok(ServerCreationWaiter {
-            server: Server::load(self.session, server_ref.id).await?,
-        })
+        api::create_server(&self.session, request).await
     }
 
     /// Add a virtual NIC with given fixed IP to the new server.
@@ -773,6 +2086,44 @@ impl NewServer {
         self.nics.push(ServerNIC::WithPort(port.into()));
     }
 
+    /// Inject a file into the new server.
+    ///
+    /// Personality files are deprecated in favor of user data and cannot be combined with
+    /// options that require compute API version 2.57 or newer (e.g. a hostname).
+    pub fn add_personality_file<P, B>(&mut self, path: P, contents: B)
+    where
+        P: Into<String>,
+        B: AsRef<[u8]>,
+    {
+        self.personality.push(protocol::Personality {
+            path: path.into(),
+            contents: user_data::encode(contents),
+        });
+    }
+
+    /// Add an arbitrary scheduler hint.
+    ///
+    /// Use [set_server_group](#method.set_server_group) for the `group` hint.
+    pub fn add_scheduler_hint<K, V>(&mut self, key: K, value: V)
+    where
+        K: Into<String>,
+        V: Into<Value>,
+    {
+        let _ = self.scheduler_hints.insert(key.into(), value.into());
+    }
+
+    /// Apply this security group to the new server.
+    ///
+    /// Only affects NICs created from networks, ports keep their own
+    /// security groups.
+    #[inline]
+    pub fn add_security_group<G>(&mut self, security_group: G)
+    where
+        G: Into<SecurityGroupRef>,
+    {
+        self.security_groups.push(security_group.into());
+    }
+
     /// Metadata assigned to this server.
     #[inline]
     pub fn metadata(&mut self) -> &mut HashMap<String, String> {
@@ -791,6 +2142,21 @@ impl NewServer {
         &mut self.block_devices
     }
 
+    /// Security groups to apply to the server.
+    #[inline]
+    pub fn security_groups(&mut self) -> &mut Vec<SecurityGroupRef> {
+        &mut self.security_groups
+    }
+
+    /// Request a number of identical servers.
+    ///
+    /// Creation fails unless at least `min` servers can be scheduled, at most `max` servers
+    /// are created.
+    pub fn set_count(&mut self, min: u32, max: u32) {
+        self.min_count = Some(min);
+        self.max_count = Some(max);
+    }
+
     /// Use this image as a source for the new server.
     pub fn set_image<I>(&mut self, image: I)
     where
@@ -807,7 +2173,36 @@ impl NewServer {
         self.keypair = Some(keypair.into());
     }
 
+    /// Schedule the new server according to this server group.
+    pub fn set_server_group<G>(&mut self, server_group: G)
+    where
+        G: Into<ServerGroupRef>,
+    {
+        self.server_group = Some(server_group.into());
+    }
+
+    /// Use this cloud-config YAML document as user data for the new server.
+    ///
+    /// The `#cloud-config` header is added if it is missing.
+    pub fn set_cloud_config<S: AsRef<str>>(&mut self, document: S) {
+        self.set_user_data_bytes(user_data::cloud_config(document.as_ref()));
+    }
+
+    /// Use this multipart document as user data for the new server.
+    pub fn set_multipart_user_data(&mut self, data: MultipartUserData) {
+        self.set_user_data_bytes(data.render());
+    }
+
+    /// Use these raw bytes as user data for the new server.
+    ///
+    /// The data is base64-encoded automatically.
+    pub fn set_user_data_bytes<B: AsRef<[u8]>>(&mut self, data: B) {
+        self.user_data = Some(user_data::encode(data));
+    }
+
     /// Use this availability_zone for the new server.
+    ///
+    /// The zone is checked against the zones reported by the cloud before the request is sent.
     pub fn set_availability_zone<A>(&mut self, availability_zone: A)
     where
         A: Into<String>,
@@ -831,6 +2226,25 @@ impl NewServer {
         self.with_block_device(BlockDevice::from_volume(volume, true))
     }
 
+    /// Use this cloud-config YAML document as user data for the new server.
+    ///
+    /// The `#cloud-config` header is added if it is missing.
+    #[inline]
+    pub fn with_cloud_config<S: AsRef<str>>(mut self, document: S) -> NewServer {
+        self.set_cloud_config(document);
+        self
+    }
+
+    /// Request a number of identical servers.
+    ///
+    /// Creation fails unless at least `min` servers can be scheduled, at most `max` servers
+    /// are created.
+    #[inline]
+    pub fn with_count(mut self, min: u32, max: u32) -> NewServer {
+        self.set_count(min, max);
+        self
+    }
+
     /// Add a virtual NIC with given fixed IP to the new server.
     #[inline]
     pub fn with_fixed_ip(mut self, fixed_ip: Ipv4Addr) -> NewServer {
@@ -878,6 +2292,13 @@ impl NewServer {
         self
     }
 
+    /// Use this multipart document as user data for the new server.
+    #[inline]
+    pub fn with_multipart_user_data(mut self, data: MultipartUserData) -> NewServer {
+        self.set_multipart_user_data(data);
+        self
+    }
+
     /// Add a virtual NIC from this network to the new server.
     #[inline]
     pub fn with_network<N>(mut self, network: N) -> NewServer
@@ -897,6 +2318,20 @@ impl NewServer {
         self.with_block_device(BlockDevice::from_new_volume(image, size_gib, true))
     }
 
+    /// Inject a file into the new server.
+    ///
+    /// Personality files are deprecated in favor of user data and cannot be combined with
+    /// options that require compute API version 2.57 or newer (e.g. a hostname).
+    #[inline]
+    pub fn with_personality_file<P, B>(mut self, path: P, contents: B) -> NewServer
+    where
+        P: Into<String>,
+        B: AsRef<[u8]>,
+    {
+        self.add_personality_file(path, contents);
+        self
+    }
+
     /// Add a virtual NIC with this port to the new server.
     #[inline]
     pub fn with_port<P>(mut self, port: P) -> NewServer
@@ -907,15 +2342,125 @@ impl NewServer {
         self
     }
 
+    /// Add an arbitrary scheduler hint.
+    ///
+    /// Use [with_server_group](#method.with_server_group) for the `group` hint.
+    #[inline]
+    pub fn with_scheduler_hint<K, V>(mut self, key: K, value: V) -> NewServer
+    where
+        K: Into<String>,
+        V: Into<Value>,
+    {
+        self.add_scheduler_hint(key, value);
+        self
+    }
+
+    /// Apply this security group to the new server.
+    ///
+    /// Only affects NICs created from networks, ports keep their own
+    /// security groups.
+    #[inline]
+    pub fn with_security_group<G>(mut self, security_group: G) -> NewServer
+    where
+        G: Into<SecurityGroupRef>,
+    {
+        self.add_security_group(security_group);
+        self
+    }
+
+    /// Schedule the new server according to this server group.
+    #[inline]
+    pub fn with_server_group<G>(mut self, server_group: G) -> NewServer
+    where
+        G: Into<ServerGroupRef>,
+    {
+        self.set_server_group(server_group);
+        self
+    }
+
+    /// Use these raw bytes as user data for the new server.
+    ///
+    /// The data is base64-encoded automatically.
+    #[inline]
+    pub fn with_user_data_bytes<B: AsRef<[u8]>>(mut self, data: B) -> NewServer {
+        self.set_user_data_bytes(data);
+        self
+    }
+
+    creation_field! {
+        #[doc = "Set IPv4 address that should be used to access the new server."]
+        set_access_ipv4, with_access_ipv4 -> access_ipv4: optional Ipv4Addr
+    }
+
+    creation_field! {
+        #[doc = "Set IPv6 address that should be used to access the new server."]
+        set_access_ipv6, with_access_ipv6 -> access_ipv6: optional Ipv6Addr
+    }
+
     creation_field! {
-        #[doc = "Use this user-data for the new server."]
+        #[doc = "Set the administrative password of the new server."]
+        #[doc = ""]
+        #[doc = "A password is generated by the cloud if not provided."]
+        set_admin_password, with_admin_password -> admin_password: optional String
+    }
+
+    creation_field! {
+        #[doc = "Return a reservation ID instead of the first server."]
+        #[doc = ""]
+        #[doc = "Only relevant to [create](#method.create), which then looks up a server"]
+        #[doc = "by the reservation ID. [create_batch](#method.create_batch) always sets it."]
+        set_return_reservation_id, with_return_reservation_id -> return_reservation_id: optional bool
+    }
+
+    creation_field! {
+        #[doc = "Set the hostname of the new server."]
+        #[doc = ""]
+        #[doc = "Creation fails with `IncompatibleApiVersion` if the cloud does not support"]
+        #[doc = "compute API version 2.90."]
+        set_hostname, with_hostname -> hostname: optional String
+    }
+
+    creation_field! {
+        #[doc = "Use this base64-encoded user-data for the new server."]
+        #[doc = ""]
+        #[doc = "See [with_user_data_bytes](#method.with_user_data_bytes) for raw data."]
         set_user_data, with_user_data -> user_data: optional String
     }
 
+    creation_field! {
+        #[doc = "Enable/disable checking the compute quota before creating the server."]
+        #[doc = ""]
+        #[doc = "See [check_quota](#method.check_quota) for details."]
+        set_quota_check, with_quota_check -> quota_check: bool
+    }
+
+    creation_field! {
+        #[doc = "Enable/disable validating the new server before creating it."]
+        #[doc = ""]
+        #[doc = "See [validate](#method.validate) for details."]
+        set_validation, with_validation -> validation: bool
+    }
+
     creation_field! {
         #[doc = "Enable/disable config-drive for the new server."]
         set_config_drive, with_config_drive -> config_drive: optional bool
     }
+
+    creation_field! {
+        #[doc = "Set a description of the new server."]
+        #[doc = ""]
+        #[doc = "Creation fails with `IncompatibleApiVersion` if the cloud does not support"]
+        #[doc = "compute API version 2.19."]
+        set_description, with_description -> description: optional String
+    }
+
+    creation_field! {
+        #[doc = "Set tags of the new server."]
+        #[doc = ""]
+        #[doc = "Creation fails with `IncompatibleApiVersion` if the cloud does not support"]
+        #[doc = "compute API version 2.52."]
+        set_tags, with_tags -> tags: Vec<String>
+    }
 }
 
 #[async_trait]
@@ -965,16 +2510,349 @@ impl Waiter<Server, Error> for ServerCreationWaiter {
 }
 
 impl ServerCreationWaiter {
+    /// Administrative password of the new server.
+    ///
+    /// This is the generated password if none was requested. It is `None` if the cloud
+    /// does not report passwords (e.g. with `enable_instance_password` disabled).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    /// use openstack::waiter::Waiter;
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// let waiter = os
+    ///     .new_server("web-1", "m1.small")
+    ///     .with_image("centos7")
+    ///     .create()
+    ///     .await
+    ///     .expect("Unable to request a server");
+    /// let password = waiter.admin_password().cloned();
+    /// let server = waiter.wait().await.expect("Server was not created");
+    /// # }
+    /// ```
+    pub fn admin_password(&self) -> Option<&String> {
+        self.admin_password.as_ref()
+    }
+
     /// Current state of the waiter.
     pub fn current_state(&self) -> &Server {
         &self.server
     }
+
+    /// Stream new lines of the console output while waiting for the server.
+    ///
+    /// The stream ends when the server becomes `Active`, after which `wait` returns
+    /// immediately. It fails if the server gets into `Error` or the default wait timeout
+    /// is exceeded.
+    ///
+    /// Only the tail of the console output is fetched on every poll, so lines may be skipped
+    /// if the server writes more than 1000 lines between two polls.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures::TryStreamExt;
+    /// use openstack::waiter::Waiter;
+    ///
+    /// # async fn async_wrapper() {
+    /// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+    /// let mut waiter = os
+    ///     .new_server("test-server", "x-large")
+    ///     .with_image("centos7")
+    ///     .create()
+    ///     .await
+    ///     .expect("Unable to request server creation");
+    /// {
+    ///     let lines = waiter.console_stream();
+    ///     futures::pin_mut!(lines);
+    ///     while let Some(line) = lines.try_next().await.expect("Server creation failed") {
+    ///         println!("{}", line);
+    ///     }
+    /// }
+    /// let server = waiter.wait().await.expect("Server creation failed");
+    /// # }
+    /// ```
+    pub fn console_stream(&mut self) -> impl Stream<Item = Result<String>> + '_ {
+        try_stream! {
+            let deadline = self.default_wait_timeout().map(|timeout| Instant::now() + timeout);
+            let delay = self.default_delay();
+            let mut previous = Vec::new();
+            loop {
+                let finished = self.poll().await?.is_some();
+
+                let output = match self.server.console_output(Some(CONSOLE_STREAM_LINES)).await {
+                    Ok(output) => output,
+                    // The console is not available before the server is scheduled.
+                    Err(err)
+                        if err.kind() == ErrorKind::Conflict
+                            || err.kind() == ErrorKind::ResourceNotFound =>
+                    {
+                        trace!("Console of server {} is not available yet", self.server.id());
+                        String::new()
+                    }
+                    Err(err) => Err(err)?,
+                };
+                let mut lines = output.lines().collect::<Vec<_>>();
+                if !finished && !output.ends_with('\n') {
+                    // The last line may still be incomplete.
+                    let _ = lines.pop();
+                }
+                for line in &lines[console_overlap(&previous, &lines)..] {
+                    yield line.to_string();
+                }
+                if !lines.is_empty() {
+                    previous = lines.into_iter().map(String::from).collect();
+                }
+
+                if finished {
+                    break;
+                }
+                if deadline.map(|value| Instant::now() >= value).unwrap_or(false) {
+                    Err(self.timeout_error())?;
+                }
+                sleep(delay).await;
+            }
+        }
+    }
+}
+
+/// Find how many lines at the start of the console output were already seen.
+///
+/// The console output is only fetched up to its tail, so the already seen lines are the
+/// longest suffix of the previous output that is also a prefix of the current one.
+fn console_overlap<S: AsRef<str>>(previous: &[S], lines: &[&str]) -> usize {
+    (0..=previous.len().min(lines.len()))
+        .rev()
+        .find(|&count| {
+            previous[previous.len() - count..]
+                .iter()
+                .map(AsRef::as_ref)
+                .eq(lines[..count].iter().copied())
+        })
+        .unwrap_or(0)
+}
+
+#[async_trait]
+impl Waiter<Vec<Server>, Error> for ServerBatchCreationWaiter {
+    fn default_wait_timeout(&self) -> Option<Duration> {
+        Some(Duration::new(1800, 0))
+    }
+
+    fn default_delay(&self) -> Duration {
+        Duration::new(5, 0)
+    }
+
+    fn timeout_error(&self) -> Error {
+        let pending = self
+            .servers
+            .iter()
+            .filter(|srv| srv.status() != protocol::ServerStatus::Active)
+            .map(|srv| srv.id().as_str())
+            .collect::<Vec<_>>();
+        Error::new(
+            ErrorKind::OperationTimedOut,
+            format!(
+                "Timeout waiting for servers of reservation {} to become ACTIVE, \
+                 still pending: {}",
+                self.reservation_id,
+                pending.join(", ")
+            ),
+        )
+    }
+
+    async fn poll(&mut self) -> Result<Option<Vec<Server>>> {
+        self.servers = ServerQuery::new(self.session.clone())
+            .with_reservation_id(self.reservation_id.clone())
+            .detailed()
+            .all()
+            .await?;
+
+        let failed = self
+            .failed_servers()
+            .map(|srv| srv.id().as_str())
+            .collect::<Vec<_>>();
+        if !failed.is_empty() {
+            debug!(
+                "Failed to create servers {:?} of reservation {}",
+                failed, self.reservation_id
+            );
+            return Err(Error::new(
+                ErrorKind::OperationFailed,
+                format!(
+                    "Servers of reservation {} got into ERROR state: {}",
+                    self.reservation_id,
+                    failed.join(", ")
+                ),
+            ));
+        }
+
+        let active = self
+            .servers
+            .iter()
+            .filter(|srv| srv.status() == protocol::ServerStatus::Active)
+            .count();
+        if active == self.servers.len() && active >= self.min_count {
+            debug!(
+                "All {} servers of reservation {} successfully created",
+                active, self.reservation_id
+            );
+            Ok(Some(self.servers.clone()))
+        } else {
+            trace!(
+                "Still waiting for servers of reservation {}, {} of {} are ACTIVE",
+                self.reservation_id,
+                active,
+                self.servers.len()
+            );
+            Ok(None)
+        }
+    }
+}
+
+impl ServerBatchCreationWaiter {
+    /// Current state of the servers in the batch.
+    pub fn current_state(&self) -> &[Server] {
+        &self.servers
+    }
+
+    /// Servers of the batch that got into `Error` state.
+    pub fn failed_servers(&self) -> impl Iterator<Item = &Server> {
+        self.servers
+            .iter()
+            .filter(|srv| srv.status() == protocol::ServerStatus::Error)
+    }
+
+    /// Reservation ID shared by all servers in the batch.
+    ///
+    /// Use it with [ServerQuery::with_reservation_id](struct.ServerQuery.html#method.with_reservation_id)
+    /// to find the servers, e.g. to clean them up after a failure.
+    pub fn reservation_id(&self) -> &String {
+        &self.reservation_id
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[tokio::test]
+    async fn test_pinned_version_too_old_for_tags() {
+        let session = Session::new(osauth::NoAuth::new("http://127.0.0.1:9").unwrap())
+            .await
+            .unwrap();
+        let err = ServerQuery::new(session)
+            .with_tags("a,b")
+            .with_api_version(ApiVersion(2, 1))
+            .all()
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::IncompatibleApiVersion);
+    }
+
+    #[test]
+    fn test_console_overlap() {
+        let empty: &[&str] = &[];
+        assert_eq!(console_overlap(empty, &["a", "b"]), 0);
+        assert_eq!(console_overlap(&["a", "b"], &["a", "b", "c"]), 2);
+        // Only the tail is fetched, so old lines disappear from the start.
+        assert_eq!(console_overlap(&["a", "b", "c"], &["b", "c", "d"]), 2);
+        assert_eq!(console_overlap(&["a", "b", "c"], &["a", "b", "c"]), 3);
+        assert_eq!(console_overlap(&["x", "x"], &["x", "x", "x"]), 2);
+        assert_eq!(console_overlap(&["a", "b"], &["c", "d"]), 0);
+    }
+
+    #[test]
+    fn test_status_progress() {
+        use protocol::ServerStatus::*;
+
+        let mut progress = StatusProgress {
+            target: Active,
+            alternative: None,
+            transition: None,
+        };
+        assert_eq!(progress.update("srv", Building).unwrap(), None);
+        assert_eq!(progress.update("srv", Active).unwrap(), Some(()));
+
+        // A rebuilt server is already active right after the request.
+        let mut progress = StatusProgress {
+            target: Active,
+            alternative: Some(ShutOff),
+            transition: Some(Rebuild),
+        };
+        assert_eq!(progress.update("srv", ShutOff).unwrap(), None);
+        assert_eq!(progress.update("srv", Rebuild).unwrap(), None);
+        assert_eq!(progress.update("srv", ShutOff).unwrap(), Some(()));
+
+        let err = progress.update("srv", Error).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::OperationFailed);
+    }
+
+    #[test]
+    fn test_migration_progress() {
+        use protocol::ServerStatus::*;
+
+        let mut progress = MigrationProgress {
+            source_host: Some("compute-1".into()),
+            target: Active,
+            alternative: None,
+            started: false,
+        };
+        // Right after the request the server is still active on the source host.
+        assert_eq!(
+            progress
+                .update("srv", Active, Some("compute-1".into()))
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            progress
+                .update("srv", Migrating, Some("compute-1".into()))
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            progress
+                .update("srv", Active, Some("compute-2".into()))
+                .unwrap(),
+            Some(Some("compute-2".into()))
+        );
+
+        // A failed migration returns the server to the source host.
+        let mut progress = MigrationProgress {
+            source_host: Some("compute-1".into()),
+            target: Active,
+            alternative: None,
+            started: false,
+        };
+        assert_eq!(
+            progress
+                .update("srv", Migrating, Some("compute-1".into()))
+                .unwrap(),
+            None
+        );
+        let err = progress
+            .update("srv", Active, Some("compute-1".into()))
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::OperationFailed);
+
+        // Without admin rights the host is hidden.
+        let mut progress = MigrationProgress {
+            source_host: None,
+            target: Active,
+            alternative: Some(ShutOff),
+            started: false,
+        };
+        assert_eq!(progress.update("srv", ShutOff, None).unwrap(), None);
+        assert_eq!(progress.update("srv", Rebuild, None).unwrap(), None);
+        assert_eq!(progress.update("srv", ShutOff, None).unwrap(), Some(None));
+
+        let err = progress.update("srv", Error, None).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::OperationFailed);
+    }
+
     #[test]
     fn test_action_json() {
         assert_eq!(
@@ -992,5 +2870,62 @@ mod test {
             .unwrap(),
             "{\"reboot\":{\"type\":\"HARD\"}}"
         );
+        assert_eq!(
+            serde_json::to_string(&ServerAction::ShelveOffload).unwrap(),
+            "{\"shelveOffload\":null}"
+        );
+        assert_eq!(
+            serde_json::to_string(&ServerAction::Resize {
+                flavor: FlavorRef::from("m1.small")
+            })
+            .unwrap(),
+            "{\"resize\":{\"flavorRef\":\"m1.small\"}}"
+        );
+        assert_eq!(
+            serde_json::to_string(&ServerAction::Rescue {
+                admin_pass: None,
+                rescue_image: None
+            })
+            .unwrap(),
+            "{\"rescue\":{}}"
+        );
+        assert_eq!(
+            serde_json::to_string(&ServerAction::Rebuild {
+                image: ImageRef::from("cirros"),
+                metadata: HashMap::new()
+            })
+            .unwrap(),
+            "{\"rebuild\":{\"imageRef\":\"cirros\"}}"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "block-storage")]
+    fn test_volume_attachment_json() {
+        let request = protocol::VolumeAttachmentCreateRoot {
+            volumeAttachment: protocol::VolumeAttachmentCreate {
+                device: None,
+                volumeId: "a26887c6-c47b-4654-abb5-dfadf7d3f803".into(),
+            },
+        };
+        assert_eq!(
+            serde_json::to_string(&request).unwrap(),
+            "{\"volumeAttachment\":{\"volumeId\":\"a26887c6-c47b-4654-abb5-dfadf7d3f803\"}}"
+        );
+
+        let root: protocol::VolumeAttachmentsRoot = serde_json::from_str(
+            r#"{"volumeAttachments": [{
+                "device": "/dev/sdb",
+                "id": "a26887c6-c47b-4654-abb5-dfadf7d3f803",
+                "serverId": "2390fb4d-1693-45d7-b309-e29c4af16538",
+                "volumeId": "a26887c6-c47b-4654-abb5-dfadf7d3f803"
+            }]}"#,
+        )
+        .unwrap();
+        assert_eq!(root.volumeAttachments.len(), 1);
+        let attachment = &root.volumeAttachments[0];
+        assert_eq!(attachment.device.as_deref(), Some("/dev/sdb"));
+        assert_eq!(attachment.server_id, "2390fb4d-1693-45d7-b309-e29c4af16538");
+        assert_eq!(attachment.volume_id, "a26887c6-c47b-4654-abb5-dfadf7d3f803");
     }
 }
@@ -0,0 +1,211 @@
+// Copyright 2026 The rust-openstack Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compute service management via Compute API.
+
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset};
+use futures::stream::{Stream, TryStreamExt};
+
+use super::super::common::{ResourceIterator, ResourceQuery};
+use super::super::session::Session;
+use super::super::utils::Query;
+use super::super::Result;
+use super::{api, protocol};
+
+/// Structure representing a compute service, e.g. `nova-compute` on a host.
+///
+/// Only available to administrators.
+#[derive(Clone, Debug)]
+pub struct ComputeService {
+    session: Session,
+    inner: protocol::ComputeService,
+}
+
+/// A query to compute service list.
+#[derive(Clone, Debug)]
+pub struct ComputeServiceQuery {
+    session: Session,
+    query: Query,
+}
+
+impl ComputeService {
+    /// Create a compute service object.
+    pub(crate) fn from_inner(session: Session, inner: protocol::ComputeService) -> ComputeService {
+        ComputeService { session, inner }
+    }
+
+    transparent_property! {
+        #[doc = "Binary name of the service, e.g. `nova-compute`."]
+        binary: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Reason the service is disabled (if any)."]
+        disabled_reason: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "Whether the service is forced down."]
+        forced_down: bool
+    }
+
+    transparent_property! {
+        #[doc = "Host the service runs on."]
+        host: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Unique ID (an integer before compute API version 2.53)."]
+        id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Whether the service is up or down."]
+        state: clone protocol::ComputeServiceState
+    }
+
+    transparent_property! {
+        #[doc = "Whether the service is enabled or disabled."]
+        status: clone protocol::ComputeServiceStatus
+    }
+
+    transparent_property! {
+        #[doc = "When the service last reported its state (if ever)."]
+        updated_at: Option<DateTime<FixedOffset>>
+    }
+
+    transparent_property! {
+        #[doc = "Availability zone of the service."]
+        zone: ref String
+    }
+
+    /// Disable the service, optionally recording a reason.
+    ///
+    /// Disabled compute services are not considered by the scheduler.
+    /// Requires compute API version 2.53.
+    pub async fn disable(&mut self, reason: Option<String>) -> Result<()> {
+        let update = protocol::ComputeServiceUpdate {
+            disabled_reason: reason,
+            status: Some(protocol::ComputeServiceStatus::Disabled),
+            ..Default::default()
+        };
+        self.update(update).await
+    }
+
+    /// Enable the service.
+    ///
+    /// Requires compute API version 2.53.
+    pub async fn enable(&mut self) -> Result<()> {
+        let update = protocol::ComputeServiceUpdate {
+            status: Some(protocol::ComputeServiceStatus::Enabled),
+            ..Default::default()
+        };
+        self.update(update).await
+    }
+
+    /// Mark the service as forced down (or clear the mark).
+    ///
+    /// Forcing a service down is required before evacuating servers from its host
+    /// without waiting for the service to time out.
+    /// Requires compute API version 2.53.
+    pub async fn force_down(&mut self, value: bool) -> Result<()> {
+        let update = protocol::ComputeServiceUpdate {
+            forced_down: Some(value),
+            ..Default::default()
+        };
+        self.update(update).await
+    }
+
+    async fn update(&mut self, update: protocol::ComputeServiceUpdate) -> Result<()> {
+        self.inner = api::update_compute_service(&self.session, &self.inner.id, update).await?;
+        Ok(())
+    }
+}
+
+impl ComputeServiceQuery {
+    pub(crate) fn new(session: Session) -> ComputeServiceQuery {
+        ComputeServiceQuery {
+            session,
+            query: Query::new(),
+        }
+    }
+
+    /// Filter by binary name, e.g. `nova-compute`.
+    pub fn with_binary<T: Into<String>>(mut self, binary: T) -> Self {
+        self.query.push_str("binary", binary);
+        self
+    }
+
+    /// Filter by host name.
+    pub fn with_host<T: Into<String>>(mut self, host: T) -> Self {
+        self.query.push_str("host", host);
+        self
+    }
+
+    /// Convert this query into a stream executing the request.
+    ///
+    /// Returns a `TryStream`, which is a stream with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_stream(self) -> impl Stream<Item = Result<ComputeService>> {
+        debug!("Fetching compute services with {:?}", self.query);
+        ResourceIterator::new(self).into_stream()
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_stream().try_collect().await`.
+    pub async fn all(self) -> Result<Vec<ComputeService>> {
+        self.into_stream().try_collect().await
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub async fn one(self) -> Result<ComputeService> {
+        debug!("Fetching one compute service with {:?}", self.query);
+        ResourceIterator::new(self).one().await
+    }
+}
+
+#[async_trait]
+impl ResourceQuery for ComputeServiceQuery {
+    type Item = ComputeService;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    async fn can_paginate(&self) -> Result<bool> {
+        // Compute services are never paginated.
+        Ok(false)
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.id().clone()
+    }
+
+    async fn fetch_chunk(
+        &self,
+        _limit: Option<usize>,
+        _marker: Option<String>,
+    ) -> Result<Vec<Self::Item>> {
+        Ok(api::list_compute_services(&self.session, &self.query)
+            .await?
+            .into_iter()
+            .map(|item| ComputeService::from_inner(self.session.clone(), item))
+            .collect())
+    }
+}
@@ -0,0 +1,269 @@
+// Copyright 2026 The rust-openstack Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! User data for new servers.
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+use super::super::{Error, ErrorKind, Result};
+
+/// Maximum size of base64-encoded user data accepted by Nova.
+const MAX_USER_DATA_SIZE: usize = 65535;
+
+const CLOUD_CONFIG_HEADER: &str = "#cloud-config";
+
+const BOUNDARY: &str = "==========rust-openstack-user-data==";
+
+/// A part of a multipart user data document.
+#[derive(Clone, Debug)]
+struct Part {
+    content_type: String,
+    filename: Option<String>,
+    content: String,
+}
+
+/// A MIME multipart user data document.
+///
+/// cloud-init processes every part according to its content type, which allows mixing
+/// cloud-config documents with shell scripts and other supported formats.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use openstack;
+///
+/// # async fn async_wrapper() {
+/// let os = openstack::Cloud::from_env().await.expect("Unable to authenticate");
+/// let user_data = openstack::compute::MultipartUserData::new()
+///     .with_cloud_config("packages:\n  - nginx\n")
+///     .with_shell_script("#!/bin/sh\nsystemctl enable --now nginx\n");
+/// let waiter = os
+///     .new_server("web-1", "m1.small")
+///     .with_image("centos7")
+///     .with_multipart_user_data(user_data)
+///     .create()
+///     .await
+///     .expect("Unable to request a server");
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MultipartUserData {
+    parts: Vec<Part>,
+}
+
+impl MultipartUserData {
+    /// Create an empty multipart document.
+    pub fn new() -> MultipartUserData {
+        MultipartUserData::default()
+    }
+
+    /// Add a part with the given content type (e.g. `text/x-shellscript`).
+    pub fn add_part<S1, S2>(&mut self, content_type: S1, content: S2)
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        self.parts.push(Part {
+            content_type: content_type.into(),
+            filename: None,
+            content: content.into(),
+        });
+    }
+
+    /// Add a part with the given content type and file name.
+    pub fn add_named_part<S1, S2, S3>(&mut self, content_type: S1, filename: S2, content: S3)
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<String>,
+    {
+        self.parts.push(Part {
+            content_type: content_type.into(),
+            filename: Some(filename.into()),
+            content: content.into(),
+        });
+    }
+
+    /// Add a part with the given content type.
+    #[inline]
+    pub fn with_part<S1, S2>(mut self, content_type: S1, content: S2) -> Self
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        self.add_part(content_type, content);
+        self
+    }
+
+    /// Add a part with the given content type and file name.
+    #[inline]
+    pub fn with_named_part<S1, S2, S3>(
+        mut self,
+        content_type: S1,
+        filename: S2,
+        content: S3,
+    ) -> Self
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<String>,
+    {
+        self.add_named_part(content_type, filename, content);
+        self
+    }
+
+    /// Add a cloud-config YAML document.
+    ///
+    /// The `#cloud-config` header is added if it is missing.
+    #[inline]
+    pub fn with_cloud_config<S: AsRef<str>>(self, document: S) -> Self {
+        self.with_part("text/cloud-config", cloud_config(document.as_ref()))
+    }
+
+    /// Add a shell script.
+    #[inline]
+    pub fn with_shell_script<S: Into<String>>(self, script: S) -> Self {
+        self.with_part("text/x-shellscript", script)
+    }
+
+    /// Whether the document has no parts.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.parts.is_empty()
+    }
+
+    /// Render the document as a MIME message.
+    pub fn render(&self) -> String {
+        // The boundary must not appear in any of the parts.
+        let mut boundary = BOUNDARY.to_string();
+        let mut counter = 0;
+        while self.parts.iter().any(|p| p.content.contains(&boundary)) {
+            counter += 1;
+            boundary = format!("{}{}", BOUNDARY, counter);
+        }
+
+        let mut result = format!(
+            "Content-Type: multipart/mixed; boundary=\"{}\"\nMIME-Version: 1.0\n\n",
+            boundary
+        );
+        for part in &self.parts {
+            result.push_str(&format!(
+                "--{}\nContent-Type: {}; charset=\"utf-8\"\nMIME-Version: 1.0\n",
+                boundary, part.content_type
+            ));
+            if let Some(ref filename) = part.filename {
+                result.push_str(&format!(
+                    "Content-Disposition: attachment; filename=\"{}\"\n",
+                    filename
+                ));
+            }
+            result.push('\n');
+            result.push_str(&part.content);
+            if !part.content.ends_with('\n') {
+                result.push('\n');
+            }
+        }
+        result.push_str(&format!("--{}--\n", boundary));
+        result
+    }
+}
+
+/// Prepend the cloud-config header if needed.
+pub(crate) fn cloud_config(document: &str) -> String {
+    if document.starts_with(CLOUD_CONFIG_HEADER) {
+        document.to_string()
+    } else {
+        format!("{}\n{}", CLOUD_CONFIG_HEADER, document)
+    }
+}
+
+/// Encode user data with base64.
+pub(crate) fn encode<B: AsRef<[u8]>>(data: B) -> String {
+    STANDARD.encode(data)
+}
+
+/// Check that encoded user data fits into the Nova limit.
+pub(crate) fn check_size(encoded: &str) -> Result<()> {
+    if encoded.len() > MAX_USER_DATA_SIZE {
+        Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "User data is {} bytes after base64 encoding, the maximum is {}",
+                encoded.len(),
+                MAX_USER_DATA_SIZE
+            ),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::ErrorKind;
+    use super::{check_size, cloud_config, encode, MultipartUserData};
+
+    #[test]
+    fn test_cloud_config_header() {
+        assert_eq!(
+            cloud_config("packages: []\n"),
+            "#cloud-config\npackages: []\n"
+        );
+        assert_eq!(
+            cloud_config("#cloud-config\npackages: []\n"),
+            "#cloud-config\npackages: []\n"
+        );
+    }
+
+    #[test]
+    fn test_encode_and_check_size() {
+        assert_eq!(encode("hello"), "aGVsbG8=");
+        assert!(check_size(&encode(vec![0u8; 1024])).is_ok());
+        let err = check_size(&encode(vec![0u8; 65536])).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_multipart_render() {
+        let rendered = MultipartUserData::new()
+            .with_cloud_config("packages: []")
+            .with_named_part("text/x-shellscript", "setup.sh", "#!/bin/sh\ntrue\n")
+            .render();
+        assert_eq!(
+            rendered,
+            "Content-Type: multipart/mixed; boundary=\"==========rust-openstack-user-data==\"\n\
+             MIME-Version: 1.0\n\n\
+             --==========rust-openstack-user-data==\n\
+             Content-Type: text/cloud-config; charset=\"utf-8\"\n\
+             MIME-Version: 1.0\n\n\
+             #cloud-config\npackages: []\n\
+             --==========rust-openstack-user-data==\n\
+             Content-Type: text/x-shellscript; charset=\"utf-8\"\n\
+             MIME-Version: 1.0\n\
+             Content-Disposition: attachment; filename=\"setup.sh\"\n\n\
+             #!/bin/sh\ntrue\n\
+             --==========rust-openstack-user-data==--\n"
+        );
+    }
+
+    #[test]
+    fn test_multipart_boundary_collision() {
+        let rendered = MultipartUserData::new()
+            .with_shell_script("echo ==========rust-openstack-user-data==")
+            .render();
+        assert!(rendered.contains("boundary=\"==========rust-openstack-user-data==1\""));
+        assert!(rendered.ends_with("--==========rust-openstack-user-data==1--\n"));
+    }
+}
@@ -0,0 +1,323 @@
+// Copyright 2026 The rust-openstack Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pre-flight validation of new servers.
+
+use std::fmt;
+
+use super::super::{Error, ErrorKind, Result};
+#[cfg(feature = "image")]
+use super::protocol;
+
+#[cfg(feature = "image")]
+const GIB: u64 = 1024 * 1024 * 1024;
+
+/// A problem that would prevent a new server from being created.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ServerProblem {
+    /// The flavor does not exist.
+    FlavorNotFound(String),
+    /// The image does not exist.
+    ImageNotFound(String),
+    /// The image is not in the `active` status.
+    ImageNotActive {
+        /// Image ID.
+        image: String,
+        /// Current status of the image.
+        status: String,
+    },
+    /// The root disk of the flavor is too small for the image.
+    ImageTooBig {
+        /// Image ID.
+        image: String,
+        /// Disk size required by the image (in GiB).
+        required_gib: u64,
+        /// Root disk size of the flavor (in GiB).
+        flavor_gib: u64,
+    },
+    /// The flavor does not provide the RAM required by the image.
+    NotEnoughRam {
+        /// Image ID.
+        image: String,
+        /// RAM required by the image (in MiB).
+        required_mib: u64,
+        /// RAM of the flavor (in MiB).
+        flavor_mib: u64,
+    },
+    /// The boot volume is too small for the image.
+    BootVolumeTooSmall {
+        /// Image ID.
+        image: String,
+        /// Disk size required by the image (in GiB).
+        required_gib: u64,
+        /// Requested size of the volume (in GiB).
+        size_gib: u64,
+    },
+    /// The key pair does not exist.
+    KeyPairNotFound(String),
+    /// The network does not exist.
+    NetworkNotFound(String),
+    /// The port does not exist.
+    PortNotFound(String),
+    /// Not enough compute quota is left.
+    QuotaExceeded {
+        /// Name of the exhausted resource.
+        resource: &'static str,
+        /// Requested amount.
+        requested: u64,
+        /// Remaining amount.
+        remaining: u64,
+    },
+}
+
+/// Properties of the image a new server boots from.
+#[cfg(feature = "image")]
+#[derive(Clone, Debug)]
+pub(crate) struct BootImage {
+    pub id: String,
+    pub status: Option<String>,
+    pub min_disk: u32,
+    pub min_ram: u32,
+    pub size: Option<u64>,
+}
+
+impl fmt::Display for ServerProblem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ServerProblem::FlavorNotFound(flavor) => write!(f, "flavor {} not found", flavor),
+            ServerProblem::ImageNotFound(image) => write!(f, "image {} not found", image),
+            ServerProblem::ImageNotActive { image, status } => {
+                write!(f, "image {} is not active (status {})", image, status)
+            }
+            ServerProblem::ImageTooBig {
+                image,
+                required_gib,
+                flavor_gib,
+            } => write!(
+                f,
+                "image {} requires {} GiB of disk, the flavor only has {} GiB",
+                image, required_gib, flavor_gib
+            ),
+            ServerProblem::NotEnoughRam {
+                image,
+                required_mib,
+                flavor_mib,
+            } => write!(
+                f,
+                "image {} requires {} MiB of RAM, the flavor only has {} MiB",
+                image, required_mib, flavor_mib
+            ),
+            ServerProblem::BootVolumeTooSmall {
+                image,
+                required_gib,
+                size_gib,
+            } => write!(
+                f,
+                "image {} requires {} GiB of disk, the boot volume only has {} GiB",
+                image, required_gib, size_gib
+            ),
+            ServerProblem::KeyPairNotFound(keypair) => {
+                write!(f, "key pair {} not found", keypair)
+            }
+            ServerProblem::NetworkNotFound(network) => {
+                write!(f, "network {} not found", network)
+            }
+            ServerProblem::PortNotFound(port) => write!(f, "port {} not found", port),
+            ServerProblem::QuotaExceeded {
+                resource,
+                requested,
+                remaining,
+            } => write!(
+                f,
+                "not enough {} quota: {} requested, {} remaining",
+                resource, requested, remaining
+            ),
+        }
+    }
+}
+
+/// Convert a `ResourceNotFound` error into `None`.
+pub(crate) fn not_found<T>(result: Result<T>) -> Result<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(err) if err.kind() == ErrorKind::ResourceNotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Build an error listing all problems, if any.
+pub(crate) fn into_result(kind: ErrorKind, problems: Vec<ServerProblem>) -> Result<()> {
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::new(
+            kind,
+            format!(
+                "Cannot create server: {}",
+                problems
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            ),
+        ))
+    }
+}
+
+/// Check the boot image against the flavor and the boot volume.
+///
+/// The root disk of the flavor is ignored when booting from a volume.
+#[cfg(feature = "image")]
+pub(crate) fn check_boot_image(
+    image: &BootImage,
+    flavor: Option<&protocol::Flavor>,
+    from_volume: bool,
+    volume_gib: Option<u32>,
+) -> Vec<ServerProblem> {
+    let mut problems = Vec::new();
+    if let Some(ref status) = image.status {
+        problems.push(ServerProblem::ImageNotActive {
+            image: image.id.clone(),
+            status: status.clone(),
+        });
+    }
+
+    let required_gib = image
+        .size
+        .map(|size| (size + GIB - 1) / GIB)
+        .unwrap_or(0)
+        .max(u64::from(image.min_disk));
+
+    if let Some(flavor) = flavor {
+        if u64::from(image.min_ram) > flavor.ram {
+            problems.push(ServerProblem::NotEnoughRam {
+                image: image.id.clone(),
+                required_mib: u64::from(image.min_ram),
+                flavor_mib: flavor.ram,
+            });
+        }
+        // Zero root disk means that the disk is sized after the image.
+        if !from_volume && flavor.disk > 0 && required_gib > flavor.disk {
+            problems.push(ServerProblem::ImageTooBig {
+                image: image.id.clone(),
+                required_gib,
+                flavor_gib: flavor.disk,
+            });
+        }
+    }
+
+    if let Some(size_gib) = volume_gib {
+        if required_gib > u64::from(size_gib) {
+            problems.push(ServerProblem::BootVolumeTooSmall {
+                image: image.id.clone(),
+                required_gib,
+                size_gib: u64::from(size_gib),
+            });
+        }
+    }
+
+    problems
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::ErrorKind;
+    #[cfg(feature = "image")]
+    use super::super::protocol;
+    #[cfg(feature = "image")]
+    use super::{check_boot_image, BootImage};
+    use super::{into_result, ServerProblem};
+
+    #[cfg(feature = "image")]
+    fn flavor(ram: u64, disk: u64) -> protocol::Flavor {
+        serde_json::from_value(serde_json::json!({
+            "disk": disk,
+            "id": "1",
+            "name": "m1.small",
+            "ram": ram,
+            "rxtx_factor": 1.0,
+            "swap": "",
+            "vcpus": 1
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn test_check_boot_image() {
+        let image = BootImage {
+            id: "cirros".into(),
+            status: None,
+            min_disk: 1,
+            min_ram: 512,
+            size: Some(5 * 1024 * 1024 * 1024 + 1),
+        };
+        assert!(check_boot_image(&image, Some(&flavor(1024, 10)), false, None).is_empty());
+        assert!(check_boot_image(&image, Some(&flavor(1024, 0)), false, None).is_empty());
+        assert_eq!(
+            check_boot_image(&image, Some(&flavor(256, 5)), false, None),
+            vec![
+                ServerProblem::NotEnoughRam {
+                    image: "cirros".into(),
+                    required_mib: 512,
+                    flavor_mib: 256
+                },
+                ServerProblem::ImageTooBig {
+                    image: "cirros".into(),
+                    required_gib: 6,
+                    flavor_gib: 5
+                },
+            ]
+        );
+        assert_eq!(
+            check_boot_image(&image, Some(&flavor(1024, 5)), true, Some(4)),
+            vec![ServerProblem::BootVolumeTooSmall {
+                image: "cirros".into(),
+                required_gib: 6,
+                size_gib: 4
+            }]
+        );
+
+        let image = BootImage {
+            status: Some("queued".into()),
+            size: None,
+            ..image
+        };
+        assert_eq!(
+            check_boot_image(&image, None, false, None),
+            vec![ServerProblem::ImageNotActive {
+                image: "cirros".into(),
+                status: "queued".into()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_into_result() {
+        assert!(into_result(ErrorKind::InvalidInput, Vec::new()).is_ok());
+        let err = into_result(
+            ErrorKind::InvalidInput,
+            vec![
+                ServerProblem::FlavorNotFound("m1.huge".into()),
+                ServerProblem::KeyPairNotFound("default".into()),
+            ],
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        assert!(err
+            .to_string()
+            .contains("flavor m1.huge not found; key pair default not found"));
+    }
+}
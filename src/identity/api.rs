@@ -0,0 +1,905 @@
+// Copyright 2026 The rust-openstack Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Foundation bits exposing the Identity API.
+
+use std::fmt::Debug;
+
+use osauth::{ApiVersion, Error, ErrorKind};
+use reqwest::{Method, Url};
+use serde::Serialize;
+
+use super::super::session::{ServiceType, Session};
+use super::super::utils;
+use super::super::Result;
+use super::protocol::*;
+
+/// Identity service (v3).
+///
+/// Keystone reports its versions in a format that the generic version
+/// discovery does not understand, so the catalog endpoint is used as it is.
+#[derive(Copy, Clone, Debug)]
+struct IdentityService;
+
+impl ServiceType for IdentityService {
+    fn catalog_type(&self) -> &'static str {
+        "identity"
+    }
+
+    fn major_version_supported(&self, version: ApiVersion) -> bool {
+        version.0 == 3
+    }
+
+    fn version_discovery_supported(&self) -> bool {
+        false
+    }
+}
+
+const IDENTITY: IdentityService = IdentityService;
+
+/// Build a path relative to the identity endpoint.
+///
+/// The catalog may contain either a versioned (`.../v3`) or an unversioned
+/// endpoint; in the latter case the version is prepended to the path.
+async fn path<'a>(session: &Session, path: &[&'a str]) -> Result<Vec<&'a str>> {
+    let endpoint = session
+        .get_endpoint(IDENTITY, std::iter::empty::<&str>())
+        .await?;
+    let versioned = endpoint
+        .path_segments()
+        .and_then(|mut segments| segments.rfind(|item| !item.is_empty()))
+        == Some("v3");
+    let mut result = Vec::with_capacity(path.len() + 1);
+    if !versioned {
+        result.push("v3");
+    }
+    result.extend_from_slice(path);
+    Ok(result)
+}
+
+/// Get the Identity endpoint suitable for authentication.
+pub async fn get_auth_url(session: &Session) -> Result<Url> {
+    session
+        .get_endpoint(IDENTITY, std::iter::empty::<&str>())
+        .await
+}
+
+/// Get the ID of the currently authenticated user.
+///
+/// This is done by validating the token the session is using.
+pub async fn get_current_user_id(session: &Session) -> Result<String> {
+    let client = session.client();
+    let request = client
+        .auth_type()
+        .authenticate(
+            client.inner(),
+            client.inner().get(get_auth_url(session).await?),
+        )
+        .await?
+        .build()?;
+    let token = request
+        .headers()
+        .get("x-auth-token")
+        .and_then(|value| value.to_str().ok())
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                "The authentication method in use does not provide a token",
+            )
+        })?;
+    let root: TokenRoot = session
+        .get(IDENTITY, path(session, &["auth", "tokens"]).await?)
+        .header("x-subject-token", token)
+        .fetch()
+        .await?;
+    trace!("Current user is {}", root.token.user.id);
+    Ok(root.token.user.id)
+}
+
+/// Add a user to a group.
+pub async fn add_group_user<S1, S2>(session: &Session, id: S1, user_id: S2) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    trace!("Adding user {} to group {}", user_id.as_ref(), id.as_ref());
+    let path = path(session, &["groups", id.as_ref(), "users", user_id.as_ref()]).await?;
+    let _ = session.put(IDENTITY, path).send().await?;
+    debug!("Added user {} to group {}", user_id.as_ref(), id.as_ref());
+    Ok(())
+}
+
+/// Create an application credential.
+pub async fn create_application_credential<S: AsRef<str>>(
+    session: &Session,
+    user_id: S,
+    request: ApplicationCredentialCreate,
+) -> Result<ApplicationCredential> {
+    // Do not log the request, it may contain a secret.
+    debug!(
+        "Creating a new application credential {} for user {}",
+        request.name,
+        user_id.as_ref()
+    );
+    let body = ApplicationCredentialCreateRoot {
+        application_credential: request,
+    };
+    let path = path(
+        session,
+        &["users", user_id.as_ref(), "application_credentials"],
+    )
+    .await?;
+    let root: ApplicationCredentialRoot = session.post(IDENTITY, path).json(&body).fetch().await?;
+    debug!(
+        "Created application credential {}",
+        root.application_credential.id
+    );
+    Ok(root.application_credential)
+}
+
+/// Create a domain.
+pub async fn create_domain(session: &Session, request: DomainCreate) -> Result<Domain> {
+    debug!("Creating a new domain with {:?}", request);
+    let body = DomainCreateRoot { domain: request };
+    let root: DomainRoot = session
+        .post(IDENTITY, path(session, &["domains"]).await?)
+        .json(&body)
+        .fetch()
+        .await?;
+    debug!("Created domain {:?}", root.domain);
+    Ok(root.domain)
+}
+
+/// Create a group.
+pub async fn create_group(session: &Session, request: GroupCreate) -> Result<Group> {
+    debug!("Creating a new group with {:?}", request);
+    let body = GroupCreateRoot { group: request };
+    let root: GroupRoot = session
+        .post(IDENTITY, path(session, &["groups"]).await?)
+        .json(&body)
+        .fetch()
+        .await?;
+    debug!("Created group {:?}", root.group);
+    Ok(root.group)
+}
+
+/// Create a project.
+pub async fn create_project(session: &Session, request: ProjectCreate) -> Result<Project> {
+    debug!("Creating a new project with {:?}", request);
+    let body = ProjectCreateRoot { project: request };
+    let root: ProjectRoot = session
+        .post(IDENTITY, path(session, &["projects"]).await?)
+        .json(&body)
+        .fetch()
+        .await?;
+    debug!("Created project {:?}", root.project);
+    Ok(root.project)
+}
+
+/// Create a role.
+pub async fn create_role(session: &Session, request: RoleCreate) -> Result<Role> {
+    debug!("Creating a new role with {:?}", request);
+    let body = RoleCreateRoot { role: request };
+    let root: RoleRoot = session
+        .post(IDENTITY, path(session, &["roles"]).await?)
+        .json(&body)
+        .fetch()
+        .await?;
+    debug!("Created role {:?}", root.role);
+    Ok(root.role)
+}
+
+/// Create a user.
+pub async fn create_user(session: &Session, request: UserCreate) -> Result<User> {
+    // Do not log the request, it may contain a password.
+    debug!("Creating a new user {}", request.name);
+    let body = UserCreateRoot { user: request };
+    let root: UserRoot = session
+        .post(IDENTITY, path(session, &["users"]).await?)
+        .json(&body)
+        .fetch()
+        .await?;
+    debug!("Created user {:?}", root.user);
+    Ok(root.user)
+}
+
+/// Delete an application credential.
+pub async fn delete_application_credential<S1, S2>(
+    session: &Session,
+    user_id: S1,
+    id: S2,
+) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    trace!("Deleting application credential {}", id.as_ref());
+    let path = path(
+        session,
+        &[
+            "users",
+            user_id.as_ref(),
+            "application_credentials",
+            id.as_ref(),
+        ],
+    )
+    .await?;
+    let _ = session.delete(IDENTITY, path).send().await?;
+    debug!(
+        "Successfully deleted application credential {}",
+        id.as_ref()
+    );
+    Ok(())
+}
+
+/// Delete a domain.
+pub async fn delete_domain<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
+    trace!("Deleting domain {}", id.as_ref());
+    let _ = session
+        .delete(IDENTITY, path(session, &["domains", id.as_ref()]).await?)
+        .send()
+        .await?;
+    debug!("Successfully deleted domain {}", id.as_ref());
+    Ok(())
+}
+
+/// Delete a group.
+pub async fn delete_group<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
+    trace!("Deleting group {}", id.as_ref());
+    let _ = session
+        .delete(IDENTITY, path(session, &["groups", id.as_ref()]).await?)
+        .send()
+        .await?;
+    debug!("Successfully deleted group {}", id.as_ref());
+    Ok(())
+}
+
+/// Delete a project.
+pub async fn delete_project<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
+    trace!("Deleting project {}", id.as_ref());
+    let _ = session
+        .delete(IDENTITY, path(session, &["projects", id.as_ref()]).await?)
+        .send()
+        .await?;
+    debug!("Successfully deleted project {}", id.as_ref());
+    Ok(())
+}
+
+/// Delete a role.
+pub async fn delete_role<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
+    trace!("Deleting role {}", id.as_ref());
+    let _ = session
+        .delete(IDENTITY, path(session, &["roles", id.as_ref()]).await?)
+        .send()
+        .await?;
+    debug!("Successfully deleted role {}", id.as_ref());
+    Ok(())
+}
+
+/// Delete a user.
+pub async fn delete_user<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
+    trace!("Deleting user {}", id.as_ref());
+    let _ = session
+        .delete(IDENTITY, path(session, &["users", id.as_ref()]).await?)
+        .send()
+        .await?;
+    debug!("Successfully deleted user {}", id.as_ref());
+    Ok(())
+}
+
+/// Get an application credential.
+pub async fn get_application_credential<S1, S2>(
+    session: &Session,
+    user_id: S1,
+    id_or_name: S2,
+) -> Result<ApplicationCredential>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    let s = id_or_name.as_ref();
+    match get_application_credential_by_id(session, user_id.as_ref(), s).await {
+        Ok(value) => Ok(value),
+        Err(err) if err.kind() == ErrorKind::ResourceNotFound => {
+            get_application_credential_by_name(session, user_id, s).await
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Get an application credential by its ID.
+pub async fn get_application_credential_by_id<S1, S2>(
+    session: &Session,
+    user_id: S1,
+    id: S2,
+) -> Result<ApplicationCredential>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    trace!("Fetching application credential {}", id.as_ref());
+    let path = path(
+        session,
+        &[
+            "users",
+            user_id.as_ref(),
+            "application_credentials",
+            id.as_ref(),
+        ],
+    )
+    .await?;
+    let root: ApplicationCredentialRoot = session.get(IDENTITY, path).fetch().await?;
+    trace!("Received {:?}", root.application_credential);
+    Ok(root.application_credential)
+}
+
+/// Get an application credential by its name.
+pub async fn get_application_credential_by_name<S1, S2>(
+    session: &Session,
+    user_id: S1,
+    name: S2,
+) -> Result<ApplicationCredential>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    trace!("Get application credential by name {}", name.as_ref());
+    let items = list_application_credentials(session, user_id, &[("name", name.as_ref())]).await?;
+    let result = utils::one(
+        items,
+        "Application credential with given name or ID not found",
+        "Too many application credentials found with given name",
+    )?;
+    trace!("Received {:?}", result);
+    Ok(result)
+}
+
+/// Get a domain.
+pub async fn get_domain<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<Domain> {
+    let s = id_or_name.as_ref();
+    match get_domain_by_id(session, s).await {
+        Ok(value) => Ok(value),
+        Err(err) if err.kind() == ErrorKind::ResourceNotFound => {
+            get_domain_by_name(session, s).await
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Get a domain by its ID.
+pub async fn get_domain_by_id<S: AsRef<str>>(session: &Session, id: S) -> Result<Domain> {
+    trace!("Fetching domain {}", id.as_ref());
+    let root: DomainRoot = session
+        .get(IDENTITY, path(session, &["domains", id.as_ref()]).await?)
+        .fetch()
+        .await?;
+    trace!("Received {:?}", root.domain);
+    Ok(root.domain)
+}
+
+/// Get a domain by its name.
+pub async fn get_domain_by_name<S: AsRef<str>>(session: &Session, name: S) -> Result<Domain> {
+    trace!("Get domain by name {}", name.as_ref());
+    let root: DomainsRoot = session
+        .get(IDENTITY, path(session, &["domains"]).await?)
+        .query(&[("name", name.as_ref())])
+        .fetch()
+        .await?;
+    let result = utils::one(
+        root.domains,
+        "Domain with given name or ID not found",
+        "Too many domains found with given name",
+    )?;
+    trace!("Received {:?}", result);
+    Ok(result)
+}
+
+/// Get a group.
+pub async fn get_group<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<Group> {
+    let s = id_or_name.as_ref();
+    match get_group_by_id(session, s).await {
+        Ok(value) => Ok(value),
+        Err(err) if err.kind() == ErrorKind::ResourceNotFound => {
+            get_group_by_name(session, s).await
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Get a group by its ID.
+pub async fn get_group_by_id<S: AsRef<str>>(session: &Session, id: S) -> Result<Group> {
+    trace!("Fetching group {}", id.as_ref());
+    let root: GroupRoot = session
+        .get(IDENTITY, path(session, &["groups", id.as_ref()]).await?)
+        .fetch()
+        .await?;
+    trace!("Received {:?}", root.group);
+    Ok(root.group)
+}
+
+/// Get a group by its name.
+///
+/// Group names are only unique within a domain, so this fails with
+/// `TooManyItems` if several domains contain a group with this name.
+pub async fn get_group_by_name<S: AsRef<str>>(session: &Session, name: S) -> Result<Group> {
+    trace!("Get group by name {}", name.as_ref());
+    let root: GroupsRoot = session
+        .get(IDENTITY, path(session, &["groups"]).await?)
+        .query(&[("name", name.as_ref())])
+        .fetch()
+        .await?;
+    let result = utils::one(
+        root.groups,
+        "Group with given name or ID not found",
+        "Too many groups found with given name",
+    )?;
+    trace!("Received {:?}", result);
+    Ok(result)
+}
+
+/// Get a project.
+pub async fn get_project<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<Project> {
+    let s = id_or_name.as_ref();
+    match get_project_by_id(session, s).await {
+        Ok(value) => Ok(value),
+        Err(err) if err.kind() == ErrorKind::ResourceNotFound => {
+            get_project_by_name(session, s).await
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Get a project by its ID.
+pub async fn get_project_by_id<S: AsRef<str>>(session: &Session, id: S) -> Result<Project> {
+    trace!("Fetching project {}", id.as_ref());
+    let root: ProjectRoot = session
+        .get(IDENTITY, path(session, &["projects", id.as_ref()]).await?)
+        .fetch()
+        .await?;
+    trace!("Received {:?}", root.project);
+    Ok(root.project)
+}
+
+/// Get a project by its name.
+///
+/// Project names are only unique within a domain, so this fails with
+/// `TooManyItems` if several domains contain a project with this name.
+pub async fn get_project_by_name<S: AsRef<str>>(session: &Session, name: S) -> Result<Project> {
+    trace!("Get project by name {}", name.as_ref());
+    let root: ProjectsRoot = session
+        .get(IDENTITY, path(session, &["projects"]).await?)
+        .query(&[("name", name.as_ref())])
+        .fetch()
+        .await?;
+    let result = utils::one(
+        root.projects,
+        "Project with given name or ID not found",
+        "Too many projects found with given name",
+    )?;
+    trace!("Received {:?}", result);
+    Ok(result)
+}
+
+/// Get a role.
+pub async fn get_role<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<Role> {
+    let s = id_or_name.as_ref();
+    match get_role_by_id(session, s).await {
+        Ok(value) => Ok(value),
+        Err(err) if err.kind() == ErrorKind::ResourceNotFound => get_role_by_name(session, s).await,
+        Err(err) => Err(err),
+    }
+}
+
+/// Get a role by its ID.
+pub async fn get_role_by_id<S: AsRef<str>>(session: &Session, id: S) -> Result<Role> {
+    trace!("Fetching role {}", id.as_ref());
+    let root: RoleRoot = session
+        .get(IDENTITY, path(session, &["roles", id.as_ref()]).await?)
+        .fetch()
+        .await?;
+    trace!("Received {:?}", root.role);
+    Ok(root.role)
+}
+
+/// Get a role by its name.
+pub async fn get_role_by_name<S: AsRef<str>>(session: &Session, name: S) -> Result<Role> {
+    trace!("Get role by name {}", name.as_ref());
+    let root: RolesRoot = session
+        .get(IDENTITY, path(session, &["roles"]).await?)
+        .query(&[("name", name.as_ref())])
+        .fetch()
+        .await?;
+    let result = utils::one(
+        root.roles,
+        "Role with given name or ID not found",
+        "Too many roles found with given name",
+    )?;
+    trace!("Received {:?}", result);
+    Ok(result)
+}
+
+/// Get a user.
+pub async fn get_user<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<User> {
+    let s = id_or_name.as_ref();
+    match get_user_by_id(session, s).await {
+        Ok(value) => Ok(value),
+        Err(err) if err.kind() == ErrorKind::ResourceNotFound => get_user_by_name(session, s).await,
+        Err(err) => Err(err),
+    }
+}
+
+/// Get a user by its ID.
+pub async fn get_user_by_id<S: AsRef<str>>(session: &Session, id: S) -> Result<User> {
+    trace!("Fetching user {}", id.as_ref());
+    let root: UserRoot = session
+        .get(IDENTITY, path(session, &["users", id.as_ref()]).await?)
+        .fetch()
+        .await?;
+    trace!("Received {:?}", root.user);
+    Ok(root.user)
+}
+
+/// Get a user by its name.
+///
+/// User names are only unique within a domain, so this fails with
+/// `TooManyItems` if several domains contain a user with this name.
+pub async fn get_user_by_name<S: AsRef<str>>(session: &Session, name: S) -> Result<User> {
+    trace!("Get user by name {}", name.as_ref());
+    let root: UsersRoot = session
+        .get(IDENTITY, path(session, &["users"]).await?)
+        .query(&[("name", name.as_ref())])
+        .fetch()
+        .await?;
+    let result = utils::one(
+        root.users,
+        "User with given name or ID not found",
+        "Too many users found with given name",
+    )?;
+    trace!("Received {:?}", result);
+    Ok(result)
+}
+
+/// Grant a role to a user or a group on a project or a domain.
+///
+/// `target` is a pair of a collection (`projects` or `domains`) and an ID,
+/// `actor` is a pair of a collection (`users` or `groups`) and an ID.
+pub async fn grant_role<S: AsRef<str>>(
+    session: &Session,
+    target: (&str, &str),
+    actor: (&str, &str),
+    role_id: S,
+) -> Result<()> {
+    trace!(
+        "Granting role {} to {:?} on {:?}",
+        role_id.as_ref(),
+        actor,
+        target
+    );
+    let path = path(
+        session,
+        &[
+            target.0,
+            target.1,
+            actor.0,
+            actor.1,
+            "roles",
+            role_id.as_ref(),
+        ],
+    )
+    .await?;
+    let _ = session.put(IDENTITY, path).send().await?;
+    debug!(
+        "Granted role {} to {:?} on {:?}",
+        role_id.as_ref(),
+        actor,
+        target
+    );
+    Ok(())
+}
+
+/// List application credentials of a user.
+pub async fn list_application_credentials<S, Q>(
+    session: &Session,
+    user_id: S,
+    query: &Q,
+) -> Result<Vec<ApplicationCredential>>
+where
+    S: AsRef<str>,
+    Q: Serialize + Sync + Debug,
+{
+    trace!(
+        "Listing application credentials of user {} with {:?}",
+        user_id.as_ref(),
+        query
+    );
+    let path = path(
+        session,
+        &["users", user_id.as_ref(), "application_credentials"],
+    )
+    .await?;
+    let root: ApplicationCredentialsRoot = session.get(IDENTITY, path).query(query).fetch().await?;
+    trace!(
+        "Received application credentials: {:?}",
+        root.application_credentials
+    );
+    Ok(root.application_credentials)
+}
+
+/// List domains.
+pub async fn list_domains<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<Vec<Domain>> {
+    trace!("Listing domains with {:?}", query);
+    let root: DomainsRoot = session
+        .get(IDENTITY, path(session, &["domains"]).await?)
+        .query(query)
+        .fetch()
+        .await?;
+    trace!("Received domains: {:?}", root.domains);
+    Ok(root.domains)
+}
+
+/// List users in a group.
+pub async fn list_group_users<S: AsRef<str>>(session: &Session, id: S) -> Result<Vec<User>> {
+    trace!("Listing users in group {}", id.as_ref());
+    let root: UsersRoot = session
+        .get(
+            IDENTITY,
+            path(session, &["groups", id.as_ref(), "users"]).await?,
+        )
+        .fetch()
+        .await?;
+    trace!("Received users: {:?}", root.users);
+    Ok(root.users)
+}
+
+/// List groups.
+pub async fn list_groups<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<Vec<Group>> {
+    trace!("Listing groups with {:?}", query);
+    let root: GroupsRoot = session
+        .get(IDENTITY, path(session, &["groups"]).await?)
+        .query(query)
+        .fetch()
+        .await?;
+    trace!("Received groups: {:?}", root.groups);
+    Ok(root.groups)
+}
+
+/// List projects.
+pub async fn list_projects<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<Vec<Project>> {
+    trace!("Listing projects with {:?}", query);
+    let root: ProjectsRoot = session
+        .get(IDENTITY, path(session, &["projects"]).await?)
+        .query(query)
+        .fetch()
+        .await?;
+    trace!("Received projects: {:?}", root.projects);
+    Ok(root.projects)
+}
+
+/// List role assignments.
+pub async fn list_role_assignments<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<Vec<RoleAssignment>> {
+    trace!("Listing role assignments with {:?}", query);
+    let root: RoleAssignmentsRoot = session
+        .get(IDENTITY, path(session, &["role_assignments"]).await?)
+        .query(query)
+        .fetch()
+        .await?;
+    trace!("Received role assignments: {:?}", root.role_assignments);
+    Ok(root.role_assignments)
+}
+
+/// List roles.
+pub async fn list_roles<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<Vec<Role>> {
+    trace!("Listing roles with {:?}", query);
+    let root: RolesRoot = session
+        .get(IDENTITY, path(session, &["roles"]).await?)
+        .query(query)
+        .fetch()
+        .await?;
+    trace!("Received roles: {:?}", root.roles);
+    Ok(root.roles)
+}
+
+/// List users.
+pub async fn list_users<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<Vec<User>> {
+    trace!("Listing users with {:?}", query);
+    let root: UsersRoot = session
+        .get(IDENTITY, path(session, &["users"]).await?)
+        .query(query)
+        .fetch()
+        .await?;
+    trace!("Received users: {:?}", root.users);
+    Ok(root.users)
+}
+
+/// Remove a user from a group.
+pub async fn remove_group_user<S1, S2>(session: &Session, id: S1, user_id: S2) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    trace!(
+        "Removing user {} from group {}",
+        user_id.as_ref(),
+        id.as_ref()
+    );
+    let path = path(session, &["groups", id.as_ref(), "users", user_id.as_ref()]).await?;
+    let _ = session.delete(IDENTITY, path).send().await?;
+    debug!(
+        "Removed user {} from group {}",
+        user_id.as_ref(),
+        id.as_ref()
+    );
+    Ok(())
+}
+
+/// Revoke a role from a user or a group on a project or a domain.
+///
+/// See `grant_role` for the meaning of the arguments.
+pub async fn revoke_role<S: AsRef<str>>(
+    session: &Session,
+    target: (&str, &str),
+    actor: (&str, &str),
+    role_id: S,
+) -> Result<()> {
+    trace!(
+        "Revoking role {} from {:?} on {:?}",
+        role_id.as_ref(),
+        actor,
+        target
+    );
+    let path = path(
+        session,
+        &[
+            target.0,
+            target.1,
+            actor.0,
+            actor.1,
+            "roles",
+            role_id.as_ref(),
+        ],
+    )
+    .await?;
+    let _ = session.delete(IDENTITY, path).send().await?;
+    debug!(
+        "Revoked role {} from {:?} on {:?}",
+        role_id.as_ref(),
+        actor,
+        target
+    );
+    Ok(())
+}
+
+/// Update a domain.
+pub async fn update_domain<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    update: DomainUpdate,
+) -> Result<Domain> {
+    debug!("Updating domain {} with {:?}", id.as_ref(), update);
+    let body = DomainUpdateRoot { domain: update };
+    let root: DomainRoot = session
+        .request(
+            IDENTITY,
+            Method::PATCH,
+            path(session, &["domains", id.as_ref()]).await?,
+        )
+        .json(&body)
+        .fetch()
+        .await?;
+    debug!("Updated domain {:?}", root.domain);
+    Ok(root.domain)
+}
+
+/// Update a group.
+pub async fn update_group<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    update: GroupUpdate,
+) -> Result<Group> {
+    debug!("Updating group {} with {:?}", id.as_ref(), update);
+    let body = GroupUpdateRoot { group: update };
+    let root: GroupRoot = session
+        .request(
+            IDENTITY,
+            Method::PATCH,
+            path(session, &["groups", id.as_ref()]).await?,
+        )
+        .json(&body)
+        .fetch()
+        .await?;
+    debug!("Updated group {:?}", root.group);
+    Ok(root.group)
+}
+
+/// Update a project.
+pub async fn update_project<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    update: ProjectUpdate,
+) -> Result<Project> {
+    debug!("Updating project {} with {:?}", id.as_ref(), update);
+    let body = ProjectUpdateRoot { project: update };
+    let root: ProjectRoot = session
+        .request(
+            IDENTITY,
+            Method::PATCH,
+            path(session, &["projects", id.as_ref()]).await?,
+        )
+        .json(&body)
+        .fetch()
+        .await?;
+    debug!("Updated project {:?}", root.project);
+    Ok(root.project)
+}
+
+/// Update a role.
+pub async fn update_role<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    update: RoleUpdate,
+) -> Result<Role> {
+    debug!("Updating role {} with {:?}", id.as_ref(), update);
+    let body = RoleUpdateRoot { role: update };
+    let root: RoleRoot = session
+        .request(
+            IDENTITY,
+            Method::PATCH,
+            path(session, &["roles", id.as_ref()]).await?,
+        )
+        .json(&body)
+        .fetch()
+        .await?;
+    debug!("Updated role {:?}", root.role);
+    Ok(root.role)
+}
+
+/// Update a user.
+pub async fn update_user<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    update: UserUpdate,
+) -> Result<User> {
+    // Do not log the update, it may contain a password.
+    debug!("Updating user {}", id.as_ref());
+    let body = UserUpdateRoot { user: update };
+    let root: UserRoot = session
+        .request(
+            IDENTITY,
+            Method::PATCH,
+            path(session, &["users", id.as_ref()]).await?,
+        )
+        .json(&body)
+        .fetch()
+        .await?;
+    debug!("Updated user {:?}", root.user);
+    Ok(root.user)
+}
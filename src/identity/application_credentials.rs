@@ -0,0 +1,340 @@
+// Copyright 2026 The rust-openstack Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Application credential management via Identity API.
+
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset};
+use futures::stream::{Stream, TryStreamExt};
+use osauth::common::IdAndName;
+
+use super::super::auth;
+use super::super::common::{Refresh, ResourceIterator, ResourceQuery, RoleRef, UserRef};
+use super::super::session::Session;
+use super::super::utils::Query;
+use super::super::{Error, ErrorKind, Result};
+use super::{api, protocol};
+
+/// A query to application credential list.
+#[derive(Clone, Debug)]
+pub struct ApplicationCredentialQuery {
+    session: Session,
+    query: Query,
+    user: Option<UserRef>,
+}
+
+/// Structure representing a single application credential.
+#[derive(Clone, Debug)]
+pub struct ApplicationCredential {
+    session: Session,
+    inner: protocol::ApplicationCredential,
+    user_id: String,
+}
+
+/// A request to create an application credential.
+#[derive(Clone, Debug)]
+pub struct NewApplicationCredential {
+    session: Session,
+    inner: protocol::ApplicationCredentialCreate,
+    roles: Vec<RoleRef>,
+    user: Option<UserRef>,
+}
+
+/// Resolve the user to work with, defaulting to the current one.
+async fn user_id(session: &Session, user: Option<UserRef>) -> Result<String> {
+    match user {
+        Some(user) => Ok(user.into_verified(session).await?.into()),
+        None => api::get_current_user_id(session).await,
+    }
+}
+
+impl ApplicationCredential {
+    /// Load an application credential of the current user.
+    pub(crate) async fn new<Id: AsRef<str>>(
+        session: Session,
+        id: Id,
+    ) -> Result<ApplicationCredential> {
+        let user_id = api::get_current_user_id(&session).await?;
+        let inner = api::get_application_credential(&session, &user_id, id).await?;
+        Ok(ApplicationCredential {
+            session,
+            inner,
+            user_id,
+        })
+    }
+
+    transparent_property! {
+        #[doc = "Access rules limiting the API calls this credential can make."]
+        access_rules: ref Vec<protocol::AccessRule>
+    }
+
+    transparent_property! {
+        #[doc = "Application credential description."]
+        description: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "Expiration time (if any)."]
+        expires_at: Option<DateTime<FixedOffset>>
+    }
+
+    transparent_property! {
+        #[doc = "Unique ID."]
+        id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Application credential name."]
+        name: ref String
+    }
+
+    transparent_property! {
+        #[doc = "ID of the project the credential is scoped to."]
+        project_id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Roles delegated to the credential."]
+        roles: ref Vec<IdAndName>
+    }
+
+    transparent_property! {
+        #[doc = "The secret, only available right after creation."]
+        secret: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "Whether the credential can create other credentials and trusts."]
+        unrestricted: bool
+    }
+
+    /// ID of the user owning the application credential.
+    #[inline]
+    pub fn user_id(&self) -> &String {
+        &self.user_id
+    }
+
+    /// Build an authentication method using this application credential.
+    ///
+    /// Only works right after creation since the secret is not returned
+    /// afterwards. The resulting object can be passed to `Cloud::new`.
+    pub async fn to_auth(&self) -> Result<auth::ApplicationCredential> {
+        let secret = self.inner.secret.as_ref().ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                "The application credential secret is only available after creation",
+            )
+        })?;
+        let auth_url = api::get_auth_url(&self.session).await?;
+        auth::ApplicationCredential::new(auth_url, &self.inner.id, secret)
+    }
+
+    /// Delete the application credential.
+    pub async fn delete(self) -> Result<()> {
+        api::delete_application_credential(&self.session, &self.user_id, &self.inner.id).await
+    }
+}
+
+#[async_trait]
+impl Refresh for ApplicationCredential {
+    /// Refresh the application credential.
+    ///
+    /// Note that the secret is lost after refreshing.
+    async fn refresh(&mut self) -> Result<()> {
+        self.inner =
+            api::get_application_credential_by_id(&self.session, &self.user_id, &self.inner.id)
+                .await?;
+        Ok(())
+    }
+}
+
+impl ApplicationCredentialQuery {
+    pub(crate) fn new(session: Session) -> ApplicationCredentialQuery {
+        ApplicationCredentialQuery {
+            session,
+            query: Query::new(),
+            user: None,
+        }
+    }
+
+    query_filter! {
+        #[doc = "Filter by application credential name."]
+        with_name -> name
+    }
+
+    /// List application credentials of this user (defaults to the current user).
+    pub fn with_user<U: Into<UserRef>>(mut self, value: U) -> Self {
+        self.user = Some(value.into());
+        self
+    }
+
+    /// Convert this query into a stream executing the request.
+    ///
+    /// Returns a `TryStream`, which is a stream with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_stream(self) -> impl Stream<Item = Result<ApplicationCredential>> {
+        debug!("Fetching application credentials with {:?}", self.query);
+        ResourceIterator::new(self).into_stream()
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_stream().try_collect().await`.
+    pub async fn all(self) -> Result<Vec<ApplicationCredential>> {
+        self.into_stream().try_collect().await
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub async fn one(self) -> Result<ApplicationCredential> {
+        debug!("Fetching one application credential with {:?}", self.query);
+        ResourceIterator::new(self).one().await
+    }
+}
+
+#[async_trait]
+impl ResourceQuery for ApplicationCredentialQuery {
+    type Item = ApplicationCredential;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    async fn can_paginate(&self) -> Result<bool> {
+        // The Identity API does not support pagination.
+        Ok(false)
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.id().clone()
+    }
+
+    async fn fetch_chunk(
+        &self,
+        _limit: Option<usize>,
+        _marker: Option<String>,
+    ) -> Result<Vec<Self::Item>> {
+        let user_id = user_id(&self.session, self.user.clone()).await?;
+        Ok(
+            api::list_application_credentials(&self.session, &user_id, &self.query)
+                .await?
+                .into_iter()
+                .map(|item| ApplicationCredential {
+                    session: self.session.clone(),
+                    inner: item,
+                    user_id: user_id.clone(),
+                })
+                .collect(),
+        )
+    }
+}
+
+impl NewApplicationCredential {
+    /// Start creating an application credential.
+    pub(crate) fn new(session: Session, name: String) -> NewApplicationCredential {
+        NewApplicationCredential {
+            session,
+            inner: protocol::ApplicationCredentialCreate {
+                name,
+                access_rules: Vec::new(),
+                description: None,
+                expires_at: None,
+                roles: Vec::new(),
+                secret: None,
+                unrestricted: None,
+            },
+            roles: Vec::new(),
+            user: None,
+        }
+    }
+
+    /// Request creation of the application credential.
+    ///
+    /// The secret is only available in the returned object.
+    pub async fn create(mut self) -> Result<ApplicationCredential> {
+        // Listing roles is usually admin-only, let Keystone resolve names instead.
+        for role in self.roles {
+            self.inner.roles.push(if role.verified {
+                protocol::RoleIdOrName::Id { id: role.into() }
+            } else {
+                protocol::RoleIdOrName::Name { name: role.into() }
+            });
+        }
+
+        let user_id = user_id(&self.session, self.user).await?;
+        let inner = api::create_application_credential(&self.session, &user_id, self.inner).await?;
+        Ok(ApplicationCredential {
+            session: self.session,
+            inner,
+            user_id,
+        })
+    }
+
+    creation_inner_vec! {
+        #[doc = "Add an access rule limiting the API calls the credential can make."]
+        add_access_rule, with_access_rule -> access_rules: protocol::AccessRule
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the description of the application credential."]
+        set_description, with_description -> description: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the expiration time (the default is to never expire)."]
+        set_expires_at, with_expires_at -> expires_at: optional DateTime<FixedOffset>
+    }
+
+    /// Add a role to delegate (defaults to all roles of the current token).
+    ///
+    /// Strings are sent as role names, use a `Role` object to refer to a role by its ID.
+    pub fn add_role<R: Into<RoleRef>>(&mut self, value: R) {
+        self.roles.push(value.into());
+    }
+
+    /// Add a role to delegate (defaults to all roles of the current token).
+    #[inline]
+    pub fn with_role<R: Into<RoleRef>>(mut self, value: R) -> Self {
+        self.add_role(value);
+        self
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the secret (generated by the server by default)."]
+        set_secret, with_secret -> secret: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Allow the credential to create other application credentials and trusts."]
+        set_unrestricted, with_unrestricted -> unrestricted: optional bool
+    }
+
+    /// Create the credential for this user (defaults to the current user).
+    ///
+    /// Users can only create application credentials for themselves.
+    pub fn set_user<U: Into<UserRef>>(&mut self, value: U) {
+        self.user = Some(value.into());
+    }
+
+    /// Create the credential for this user (defaults to the current user).
+    ///
+    /// Users can only create application credentials for themselves.
+    #[inline]
+    pub fn with_user<U: Into<UserRef>>(mut self, value: U) -> Self {
+        self.set_user(value);
+        self
+    }
+}
@@ -0,0 +1,261 @@
+// Copyright 2026 The rust-openstack Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Domain management via Identity API.
+
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use futures::stream::{Stream, TryStreamExt};
+
+use super::super::common::{DomainRef, Refresh, ResourceIterator, ResourceQuery};
+use super::super::session::Session;
+use super::super::utils::Query;
+use super::super::Result;
+use super::{api, protocol};
+
+/// A query to domain list.
+#[derive(Clone, Debug)]
+pub struct DomainQuery {
+    session: Session,
+    query: Query,
+}
+
+/// Structure representing a single domain.
+#[derive(Clone, Debug)]
+pub struct Domain {
+    session: Session,
+    inner: protocol::Domain,
+    dirty: HashSet<&'static str>,
+}
+
+/// A request to create a domain.
+#[derive(Clone, Debug)]
+pub struct NewDomain {
+    session: Session,
+    inner: protocol::DomainCreate,
+}
+
+impl Domain {
+    /// Create a domain object.
+    fn from_inner(session: Session, inner: protocol::Domain) -> Domain {
+        Domain {
+            session,
+            inner,
+            dirty: HashSet::new(),
+        }
+    }
+
+    /// Load a Domain object.
+    pub(crate) async fn new<Id: AsRef<str>>(session: Session, id: Id) -> Result<Domain> {
+        let inner = api::get_domain(&session, id).await?;
+        Ok(Domain::from_inner(session, inner))
+    }
+
+    transparent_property! {
+        #[doc = "Domain description."]
+        description: ref Option<String>
+    }
+
+    update_field! {
+        #[doc = "Update the description."]
+        set_description, with_description -> description: optional String
+    }
+
+    transparent_property! {
+        #[doc = "Whether the domain is enabled."]
+        enabled: bool
+    }
+
+    update_field! {
+        #[doc = "Enable or disable the domain."]
+        set_enabled, with_enabled -> enabled: bool
+    }
+
+    transparent_property! {
+        #[doc = "Unique ID."]
+        id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Domain name."]
+        name: ref String
+    }
+
+    update_field! {
+        #[doc = "Update the name."]
+        set_name, with_name -> name
+    }
+
+    /// Delete the domain.
+    ///
+    /// Only disabled domains can be deleted, use `set_enabled(false)` and
+    /// `save` first.
+    pub async fn delete(self) -> Result<()> {
+        api::delete_domain(&self.session, &self.inner.id).await
+    }
+
+    /// Whether the domain is modified.
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Save the changes to the domain.
+    #[allow(clippy::field_reassign_with_default)]
+    pub async fn save(&mut self) -> Result<()> {
+        let mut update = protocol::DomainUpdate::default();
+        save_fields! {
+            self -> update: enabled name
+        };
+        save_option_fields! {
+            self -> update: description
+        };
+        let inner = api::update_domain(&self.session, self.id(), update).await?;
+        self.dirty.clear();
+        self.inner = inner;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Refresh for Domain {
+    /// Refresh the domain.
+    async fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_domain_by_id(&self.session, &self.inner.id).await?;
+        self.dirty.clear();
+        Ok(())
+    }
+}
+
+impl DomainQuery {
+    pub(crate) fn new(session: Session) -> DomainQuery {
+        DomainQuery {
+            session,
+            query: Query::new(),
+        }
+    }
+
+    query_filter! {
+        #[doc = "Filter by whether the domain is enabled."]
+        with_enabled -> enabled: bool
+    }
+
+    query_filter! {
+        #[doc = "Filter by domain name."]
+        with_name -> name
+    }
+
+    /// Convert this query into a stream executing the request.
+    ///
+    /// Returns a `TryStream`, which is a stream with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_stream(self) -> impl Stream<Item = Result<Domain>> {
+        debug!("Fetching domains with {:?}", self.query);
+        ResourceIterator::new(self).into_stream()
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_stream().try_collect().await`.
+    pub async fn all(self) -> Result<Vec<Domain>> {
+        self.into_stream().try_collect().await
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub async fn one(self) -> Result<Domain> {
+        debug!("Fetching one domain with {:?}", self.query);
+        ResourceIterator::new(self).one().await
+    }
+}
+
+#[async_trait]
+impl ResourceQuery for DomainQuery {
+    type Item = Domain;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    async fn can_paginate(&self) -> Result<bool> {
+        // The Identity API does not support pagination.
+        Ok(false)
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.id().clone()
+    }
+
+    async fn fetch_chunk(
+        &self,
+        _limit: Option<usize>,
+        _marker: Option<String>,
+    ) -> Result<Vec<Self::Item>> {
+        Ok(api::list_domains(&self.session, &self.query)
+            .await?
+            .into_iter()
+            .map(|item| Domain::from_inner(self.session.clone(), item))
+            .collect())
+    }
+}
+
+impl NewDomain {
+    /// Start creating a domain.
+    pub(crate) fn new(session: Session, name: String) -> NewDomain {
+        NewDomain {
+            session,
+            inner: protocol::DomainCreate {
+                name,
+                description: None,
+                enabled: None,
+            },
+        }
+    }
+
+    /// Request creation of the domain.
+    pub async fn create(self) -> Result<Domain> {
+        let inner = api::create_domain(&self.session, self.inner).await?;
+        Ok(Domain::from_inner(self.session, inner))
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the description of the domain."]
+        set_description, with_description -> description: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set whether the domain is enabled (the default is true)."]
+        set_enabled, with_enabled -> enabled: optional bool
+    }
+}
+
+impl From<Domain> for DomainRef {
+    fn from(value: Domain) -> DomainRef {
+        DomainRef::new_verified(value.inner.id)
+    }
+}
+
+#[cfg(feature = "identity")]
+impl DomainRef {
+    /// Verify this reference and convert to an ID, if possible.
+    pub(crate) async fn into_verified(self, session: &Session) -> Result<DomainRef> {
+        Ok(if self.verified {
+            self
+        } else {
+            DomainRef::new_verified(api::get_domain(session, &self.value).await?.id)
+        })
+    }
+}
@@ -0,0 +1,294 @@
+// Copyright 2026 The rust-openstack Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Group management via Identity API.
+
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use futures::stream::{Stream, TryStreamExt};
+
+use super::super::common::{
+    DomainRef, GroupRef, Refresh, ResourceIterator, ResourceQuery, UserRef,
+};
+use super::super::session::Session;
+use super::super::utils::Query;
+use super::super::Result;
+use super::{api, protocol, Domain, User};
+
+/// A query to group list.
+#[derive(Clone, Debug)]
+pub struct GroupQuery {
+    session: Session,
+    query: Query,
+}
+
+/// Structure representing a single group.
+#[derive(Clone, Debug)]
+pub struct Group {
+    session: Session,
+    inner: protocol::Group,
+    dirty: HashSet<&'static str>,
+}
+
+/// A request to create a group.
+#[derive(Clone, Debug)]
+pub struct NewGroup {
+    session: Session,
+    inner: protocol::GroupCreate,
+    domain: Option<DomainRef>,
+}
+
+impl Group {
+    /// Create a group object.
+    fn from_inner(session: Session, inner: protocol::Group) -> Group {
+        Group {
+            session,
+            inner,
+            dirty: HashSet::new(),
+        }
+    }
+
+    /// Load a Group object.
+    pub(crate) async fn new<Id: AsRef<str>>(session: Session, id: Id) -> Result<Group> {
+        let inner = api::get_group(&session, id).await?;
+        Ok(Group::from_inner(session, inner))
+    }
+
+    transparent_property! {
+        #[doc = "Group description."]
+        description: ref Option<String>
+    }
+
+    update_field! {
+        #[doc = "Update the description."]
+        set_description, with_description -> description: optional String
+    }
+
+    transparent_property! {
+        #[doc = "ID of the domain the group belongs to."]
+        domain_id: ref String
+    }
+
+    /// Fetch the domain the group belongs to.
+    pub async fn domain(&self) -> Result<Domain> {
+        Domain::new(self.session.clone(), &self.inner.domain_id).await
+    }
+
+    transparent_property! {
+        #[doc = "Unique ID."]
+        id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Group name."]
+        name: ref String
+    }
+
+    update_field! {
+        #[doc = "Update the name."]
+        set_name, with_name -> name
+    }
+
+    /// Add a user to the group.
+    pub async fn add_user<U: Into<UserRef>>(&self, user: U) -> Result<()> {
+        let user = user.into().into_verified(&self.session).await?;
+        api::add_group_user(&self.session, &self.inner.id, user).await
+    }
+
+    /// Delete the group.
+    pub async fn delete(self) -> Result<()> {
+        api::delete_group(&self.session, &self.inner.id).await
+    }
+
+    /// Whether the group is modified.
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Remove a user from the group.
+    pub async fn remove_user<U: Into<UserRef>>(&self, user: U) -> Result<()> {
+        let user = user.into().into_verified(&self.session).await?;
+        api::remove_group_user(&self.session, &self.inner.id, user).await
+    }
+
+    /// Save the changes to the group.
+    #[allow(clippy::field_reassign_with_default)]
+    pub async fn save(&mut self) -> Result<()> {
+        let mut update = protocol::GroupUpdate::default();
+        save_fields! {
+            self -> update: name
+        };
+        save_option_fields! {
+            self -> update: description
+        };
+        let inner = api::update_group(&self.session, self.id(), update).await?;
+        self.dirty.clear();
+        self.inner = inner;
+        Ok(())
+    }
+
+    /// List users in the group.
+    pub async fn users(&self) -> Result<Vec<User>> {
+        Ok(api::list_group_users(&self.session, &self.inner.id)
+            .await?
+            .into_iter()
+            .map(|item| User::from_inner(self.session.clone(), item))
+            .collect())
+    }
+}
+
+#[async_trait]
+impl Refresh for Group {
+    /// Refresh the group.
+    async fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_group_by_id(&self.session, &self.inner.id).await?;
+        self.dirty.clear();
+        Ok(())
+    }
+}
+
+impl GroupQuery {
+    pub(crate) fn new(session: Session) -> GroupQuery {
+        GroupQuery {
+            session,
+            query: Query::new(),
+        }
+    }
+
+    query_filter! {
+        #[doc = "Filter by domain ID."]
+        with_domain_id -> domain_id
+    }
+
+    query_filter! {
+        #[doc = "Filter by group name."]
+        with_name -> name
+    }
+
+    /// Convert this query into a stream executing the request.
+    ///
+    /// Returns a `TryStream`, which is a stream with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_stream(self) -> impl Stream<Item = Result<Group>> {
+        debug!("Fetching groups with {:?}", self.query);
+        ResourceIterator::new(self).into_stream()
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_stream().try_collect().await`.
+    pub async fn all(self) -> Result<Vec<Group>> {
+        self.into_stream().try_collect().await
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub async fn one(self) -> Result<Group> {
+        debug!("Fetching one group with {:?}", self.query);
+        ResourceIterator::new(self).one().await
+    }
+}
+
+#[async_trait]
+impl ResourceQuery for GroupQuery {
+    type Item = Group;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    async fn can_paginate(&self) -> Result<bool> {
+        // The Identity API does not support pagination.
+        Ok(false)
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.id().clone()
+    }
+
+    async fn fetch_chunk(
+        &self,
+        _limit: Option<usize>,
+        _marker: Option<String>,
+    ) -> Result<Vec<Self::Item>> {
+        Ok(api::list_groups(&self.session, &self.query)
+            .await?
+            .into_iter()
+            .map(|item| Group::from_inner(self.session.clone(), item))
+            .collect())
+    }
+}
+
+impl NewGroup {
+    /// Start creating a group.
+    pub(crate) fn new(session: Session, name: String) -> NewGroup {
+        NewGroup {
+            session,
+            inner: protocol::GroupCreate {
+                name,
+                description: None,
+                domain_id: None,
+            },
+            domain: None,
+        }
+    }
+
+    /// Request creation of the group.
+    pub async fn create(mut self) -> Result<Group> {
+        if let Some(domain) = self.domain {
+            self.inner.domain_id = Some(domain.into_verified(&self.session).await?.into());
+        }
+
+        let inner = api::create_group(&self.session, self.inner).await?;
+        Ok(Group::from_inner(self.session, inner))
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the description of the group."]
+        set_description, with_description -> description: optional String
+    }
+
+    /// Set the domain of the group (defaults to the domain of the current user).
+    pub fn set_domain<D: Into<DomainRef>>(&mut self, value: D) {
+        self.domain = Some(value.into());
+    }
+
+    /// Set the domain of the group (defaults to the domain of the current user).
+    #[inline]
+    pub fn with_domain<D: Into<DomainRef>>(mut self, value: D) -> Self {
+        self.set_domain(value);
+        self
+    }
+}
+
+impl From<Group> for GroupRef {
+    fn from(value: Group) -> GroupRef {
+        GroupRef::new_verified(value.inner.id)
+    }
+}
+
+#[cfg(feature = "identity")]
+impl GroupRef {
+    /// Verify this reference and convert to an ID, if possible.
+    pub(crate) async fn into_verified(self, session: &Session) -> Result<GroupRef> {
+        Ok(if self.verified {
+            self
+        } else {
+            GroupRef::new_verified(api::get_group(session, &self.value).await?.id)
+        })
+    }
+}
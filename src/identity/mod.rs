@@ -0,0 +1,39 @@
+// Copyright 2026 The rust-openstack Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Identity API implementation bits.
+//!
+//! Only version 3 of the Identity API is supported. Most of the calls here
+//! require administrative privileges by default.
+
+mod api;
+mod application_credentials;
+mod domains;
+mod groups;
+mod projects;
+mod protocol;
+mod roles;
+mod users;
+
+pub use self::application_credentials::{
+    ApplicationCredential, ApplicationCredentialQuery, NewApplicationCredential,
+};
+pub use self::domains::{Domain, DomainQuery, NewDomain};
+pub use self::groups::{Group, GroupQuery, NewGroup};
+pub use self::projects::{NewProject, Project, ProjectQuery};
+pub use self::protocol::AccessRule;
+pub use self::roles::{
+    NewRole, NewRoleAssignment, Role, RoleAssignment, RoleAssignmentQuery, RoleQuery,
+};
+pub use self::users::{NewUser, User, UserQuery};
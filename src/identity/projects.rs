@@ -0,0 +1,371 @@
+// Copyright 2026 The rust-openstack Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Project management via Identity API.
+
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use futures::stream::{Stream, TryStreamExt};
+
+use super::super::common::{DomainRef, ProjectRef, Refresh, ResourceIterator, ResourceQuery};
+use super::super::session::Session;
+use super::super::utils::Query;
+use super::super::Result;
+use super::{api, protocol, Domain};
+
+/// A query to project list.
+#[derive(Clone, Debug)]
+pub struct ProjectQuery {
+    session: Session,
+    query: Query,
+}
+
+/// Structure representing a single project.
+#[derive(Clone, Debug)]
+pub struct Project {
+    session: Session,
+    inner: protocol::Project,
+    dirty: HashSet<&'static str>,
+}
+
+/// A request to create a project.
+#[derive(Clone, Debug)]
+pub struct NewProject {
+    session: Session,
+    inner: protocol::ProjectCreate,
+    domain: Option<DomainRef>,
+    parent: Option<ProjectRef>,
+}
+
+impl Project {
+    /// Create a project object.
+    fn from_inner(session: Session, inner: protocol::Project) -> Project {
+        Project {
+            session,
+            inner,
+            dirty: HashSet::new(),
+        }
+    }
+
+    /// Load a Project object.
+    pub(crate) async fn new<Id: AsRef<str>>(session: Session, id: Id) -> Result<Project> {
+        let inner = api::get_project(&session, id).await?;
+        Ok(Project::from_inner(session, inner))
+    }
+
+    transparent_property! {
+        #[doc = "Project description."]
+        description: ref Option<String>
+    }
+
+    update_field! {
+        #[doc = "Update the description."]
+        set_description, with_description -> description: optional String
+    }
+
+    transparent_property! {
+        #[doc = "ID of the domain the project belongs to."]
+        domain_id: ref String
+    }
+
+    /// Fetch the domain the project belongs to.
+    pub async fn domain(&self) -> Result<Domain> {
+        Domain::new(self.session.clone(), &self.inner.domain_id).await
+    }
+
+    transparent_property! {
+        #[doc = "Whether the project is enabled."]
+        enabled: bool
+    }
+
+    update_field! {
+        #[doc = "Enable or disable the project."]
+        set_enabled, with_enabled -> enabled: bool
+    }
+
+    transparent_property! {
+        #[doc = "Unique ID."]
+        id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Whether the project also acts as a domain."]
+        is_domain: bool
+    }
+
+    transparent_property! {
+        #[doc = "Project name."]
+        name: ref String
+    }
+
+    update_field! {
+        #[doc = "Update the name."]
+        set_name, with_name -> name
+    }
+
+    /// Fetch the parent project (if any).
+    ///
+    /// Top-level projects have their domain as a parent, `None` is returned
+    /// in this case.
+    pub async fn parent(&self) -> Result<Option<Project>> {
+        Ok(match self.inner.parent_id {
+            Some(ref id) if id != &self.inner.domain_id => {
+                Some(Project::new(self.session.clone(), id).await?)
+            }
+            _ => None,
+        })
+    }
+
+    transparent_property! {
+        #[doc = "ID of the parent project or domain."]
+        parent_id: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "Project tags."]
+        tags: ref Vec<String>
+    }
+
+    update_field_mut! {
+        #[doc = "Update the tags."]
+        tags_mut, set_tags, with_tags -> tags: Vec<String>
+    }
+
+    /// Delete the project.
+    pub async fn delete(self) -> Result<()> {
+        api::delete_project(&self.session, &self.inner.id).await
+    }
+
+    /// Whether the project is modified.
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Save the changes to the project.
+    #[allow(clippy::field_reassign_with_default)]
+    pub async fn save(&mut self) -> Result<()> {
+        let mut update = protocol::ProjectUpdate::default();
+        save_fields! {
+            self -> update: enabled name tags
+        };
+        save_option_fields! {
+            self -> update: description
+        };
+        let inner = api::update_project(&self.session, self.id(), update).await?;
+        self.dirty.clear();
+        self.inner = inner;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Refresh for Project {
+    /// Refresh the project.
+    async fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_project_by_id(&self.session, &self.inner.id).await?;
+        self.dirty.clear();
+        Ok(())
+    }
+}
+
+impl ProjectQuery {
+    pub(crate) fn new(session: Session) -> ProjectQuery {
+        ProjectQuery {
+            session,
+            query: Query::new(),
+        }
+    }
+
+    query_filter! {
+        #[doc = "Filter by domain ID."]
+        with_domain_id -> domain_id
+    }
+
+    query_filter! {
+        #[doc = "Filter by whether the project is enabled."]
+        with_enabled -> enabled: bool
+    }
+
+    query_filter! {
+        #[doc = "Filter by whether the project acts as a domain."]
+        with_is_domain -> is_domain: bool
+    }
+
+    query_filter! {
+        #[doc = "Filter by project name."]
+        with_name -> name
+    }
+
+    query_filter! {
+        #[doc = "Filter by parent project ID."]
+        with_parent_id -> parent_id
+    }
+
+    query_filter! {
+        #[doc = "Filter by comma-separated tags (projects must have all of them)."]
+        with_tags -> tags
+    }
+
+    /// Convert this query into a stream executing the request.
+    ///
+    /// Returns a `TryStream`, which is a stream with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_stream(self) -> impl Stream<Item = Result<Project>> {
+        debug!("Fetching projects with {:?}", self.query);
+        ResourceIterator::new(self).into_stream()
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_stream().try_collect().await`.
+    pub async fn all(self) -> Result<Vec<Project>> {
+        self.into_stream().try_collect().await
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub async fn one(self) -> Result<Project> {
+        debug!("Fetching one project with {:?}", self.query);
+        ResourceIterator::new(self).one().await
+    }
+}
+
+#[async_trait]
+impl ResourceQuery for ProjectQuery {
+    type Item = Project;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    async fn can_paginate(&self) -> Result<bool> {
+        // The Identity API does not support pagination.
+        Ok(false)
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.id().clone()
+    }
+
+    async fn fetch_chunk(
+        &self,
+        _limit: Option<usize>,
+        _marker: Option<String>,
+    ) -> Result<Vec<Self::Item>> {
+        Ok(api::list_projects(&self.session, &self.query)
+            .await?
+            .into_iter()
+            .map(|item| Project::from_inner(self.session.clone(), item))
+            .collect())
+    }
+}
+
+impl NewProject {
+    /// Start creating a project.
+    pub(crate) fn new(session: Session, name: String) -> NewProject {
+        NewProject {
+            session,
+            inner: protocol::ProjectCreate {
+                name,
+                description: None,
+                domain_id: None,
+                enabled: None,
+                is_domain: None,
+                parent_id: None,
+                tags: Vec::new(),
+            },
+            domain: None,
+            parent: None,
+        }
+    }
+
+    /// Request creation of the project.
+    pub async fn create(mut self) -> Result<Project> {
+        if let Some(domain) = self.domain {
+            self.inner.domain_id = Some(domain.into_verified(&self.session).await?.into());
+        }
+
+        if let Some(parent) = self.parent {
+            self.inner.parent_id = Some(parent.into_verified(&self.session).await?.into());
+        }
+
+        let inner = api::create_project(&self.session, self.inner).await?;
+        Ok(Project::from_inner(self.session, inner))
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the description of the project."]
+        set_description, with_description -> description: optional String
+    }
+
+    /// Set the domain of the project (defaults to the domain of the current user).
+    pub fn set_domain<D: Into<DomainRef>>(&mut self, value: D) {
+        self.domain = Some(value.into());
+    }
+
+    /// Set the domain of the project (defaults to the domain of the current user).
+    #[inline]
+    pub fn with_domain<D: Into<DomainRef>>(mut self, value: D) -> Self {
+        self.set_domain(value);
+        self
+    }
+
+    creation_inner_field! {
+        #[doc = "Set whether the project is enabled (the default is true)."]
+        set_enabled, with_enabled -> enabled: optional bool
+    }
+
+    creation_inner_field! {
+        #[doc = "Set whether the project also acts as a domain."]
+        set_is_domain, with_is_domain -> is_domain: optional bool
+    }
+
+    /// Set the parent project.
+    pub fn set_parent<P: Into<ProjectRef>>(&mut self, value: P) {
+        self.parent = Some(value.into());
+    }
+
+    /// Set the parent project.
+    #[inline]
+    pub fn with_parent<P: Into<ProjectRef>>(mut self, value: P) -> Self {
+        self.set_parent(value);
+        self
+    }
+
+    creation_inner_vec! {
+        #[doc = "Add a tag to the project."]
+        add_tag, with_tag -> tags
+    }
+}
+
+impl From<Project> for ProjectRef {
+    fn from(value: Project) -> ProjectRef {
+        ProjectRef::new_verified(value.inner.id)
+    }
+}
+
+#[cfg(feature = "identity")]
+impl ProjectRef {
+    /// Verify this reference and convert to an ID, if possible.
+    pub(crate) async fn into_verified(self, session: &Session) -> Result<ProjectRef> {
+        Ok(if self.verified {
+            self
+        } else {
+            ProjectRef::new_verified(api::get_project(session, &self.value).await?.id)
+        })
+    }
+}
@@ -0,0 +1,583 @@
+// Copyright 2026 The rust-openstack Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! JSON structures and protocol bits for the Identity API.
+
+#![allow(missing_docs)]
+
+use chrono::{DateTime, FixedOffset, NaiveDateTime, TimeZone, Utc};
+use osauth::common::IdAndName;
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Deserialize an optional date and time that may come without a timezone.
+///
+/// Keystone returns timestamps in UTC without specifying a timezone.
+fn deser_optional_datetime<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<DateTime<FixedOffset>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value: Option<String> = Deserialize::deserialize(deserializer)?;
+    value
+        .map(|s| match DateTime::parse_from_rfc3339(&s) {
+            Ok(value) => Ok(value),
+            Err(_) => NaiveDateTime::parse_from_str(&s, "%Y-%m-%dT%H:%M:%S%.f")
+                .map(|naive| Utc.from_utc_datetime(&naive).into())
+                .map_err(serde::de::Error::custom),
+        })
+        .transpose()
+}
+
+/// An access rule of an application credential.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct AccessRule {
+    /// Unique ID of the access rule (set by the server).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// HTTP method, e.g. `GET`.
+    pub method: String,
+    /// API path, may contain `*` and `**` wildcards.
+    pub path: String,
+    /// Service type, e.g. `compute`.
+    pub service: String,
+}
+
+impl AccessRule {
+    /// Create a new access rule.
+    pub fn new<S1, S2, S3>(service: S1, method: S2, path: S3) -> AccessRule
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<String>,
+    {
+        AccessRule {
+            id: None,
+            method: method.into(),
+            path: path.into(),
+            service: service.into(),
+        }
+    }
+}
+
+/// An application credential.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApplicationCredential {
+    #[serde(default)]
+    pub access_rules: Vec<AccessRule>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default, deserialize_with = "deser_optional_datetime")]
+    pub expires_at: Option<DateTime<FixedOffset>>,
+    pub id: String,
+    pub name: String,
+    pub project_id: String,
+    #[serde(default)]
+    pub roles: Vec<IdAndName>,
+    #[serde(default)]
+    pub secret: Option<String>,
+    #[serde(default)]
+    pub unrestricted: bool,
+}
+
+/// An application credential root.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApplicationCredentialRoot {
+    pub application_credential: ApplicationCredential,
+}
+
+/// A list of application credentials.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApplicationCredentialsRoot {
+    pub application_credentials: Vec<ApplicationCredential>,
+}
+
+/// A reference to a role by its ID or name.
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum RoleIdOrName {
+    Id { id: String },
+    Name { name: String },
+}
+
+/// Application credential arguments for a create request.
+#[derive(Debug, Clone, Serialize)]
+pub struct ApplicationCredentialCreate {
+    pub name: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub access_rules: Vec<AccessRule>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime<FixedOffset>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub roles: Vec<RoleIdOrName>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unrestricted: Option<bool>,
+}
+
+/// An application credential create request.
+#[derive(Debug, Clone, Serialize)]
+pub struct ApplicationCredentialCreateRoot {
+    pub application_credential: ApplicationCredentialCreate,
+}
+
+/// A domain.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Domain {
+    #[serde(default)]
+    pub description: Option<String>,
+    pub enabled: bool,
+    pub id: String,
+    pub name: String,
+}
+
+/// A domain root.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DomainRoot {
+    pub domain: Domain,
+}
+
+/// A list of domains.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DomainsRoot {
+    pub domains: Vec<Domain>,
+}
+
+/// Domain arguments for a create request.
+#[derive(Debug, Clone, Serialize)]
+pub struct DomainCreate {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+}
+
+/// A domain create request.
+#[derive(Debug, Clone, Serialize)]
+pub struct DomainCreateRoot {
+    pub domain: DomainCreate,
+}
+
+/// A domain update.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DomainUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// A domain update request.
+#[derive(Debug, Clone, Serialize)]
+pub struct DomainUpdateRoot {
+    pub domain: DomainUpdate,
+}
+
+/// A group.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Group {
+    #[serde(default)]
+    pub description: Option<String>,
+    pub domain_id: String,
+    pub id: String,
+    pub name: String,
+}
+
+/// A group root.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GroupRoot {
+    pub group: Group,
+}
+
+/// A list of groups.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GroupsRoot {
+    pub groups: Vec<Group>,
+}
+
+/// Group arguments for a create request.
+#[derive(Debug, Clone, Serialize)]
+pub struct GroupCreate {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain_id: Option<String>,
+}
+
+/// A group create request.
+#[derive(Debug, Clone, Serialize)]
+pub struct GroupCreateRoot {
+    pub group: GroupCreate,
+}
+
+/// A group update.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GroupUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// A group update request.
+#[derive(Debug, Clone, Serialize)]
+pub struct GroupUpdateRoot {
+    pub group: GroupUpdate,
+}
+
+/// A project.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Project {
+    #[serde(default)]
+    pub description: Option<String>,
+    pub domain_id: String,
+    pub enabled: bool,
+    pub id: String,
+    #[serde(default)]
+    pub is_domain: bool,
+    pub name: String,
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// A project root.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProjectRoot {
+    pub project: Project,
+}
+
+/// A list of projects.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProjectsRoot {
+    pub projects: Vec<Project>,
+}
+
+/// Project arguments for a create request.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectCreate {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_domain: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+}
+
+/// A project create request.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectCreateRoot {
+    pub project: ProjectCreate,
+}
+
+/// A project update.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ProjectUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+}
+
+/// A project update request.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectUpdateRoot {
+    pub project: ProjectUpdate,
+}
+
+/// A role.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Role {
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub domain_id: Option<String>,
+    pub id: String,
+    pub name: String,
+}
+
+/// A role root.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RoleRoot {
+    pub role: Role,
+}
+
+/// A list of roles.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RolesRoot {
+    pub roles: Vec<Role>,
+}
+
+/// Role arguments for a create request.
+#[derive(Debug, Clone, Serialize)]
+pub struct RoleCreate {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain_id: Option<String>,
+}
+
+/// A role create request.
+#[derive(Debug, Clone, Serialize)]
+pub struct RoleCreateRoot {
+    pub role: RoleCreate,
+}
+
+/// A role update.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RoleUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// A role update request.
+#[derive(Debug, Clone, Serialize)]
+pub struct RoleUpdateRoot {
+    pub role: RoleUpdate,
+}
+
+/// A reference to a resource in a role assignment.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AssignmentRef {
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+/// A scope of a role assignment.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AssignmentScope {
+    #[serde(default)]
+    pub domain: Option<AssignmentRef>,
+    #[serde(default)]
+    pub project: Option<AssignmentRef>,
+}
+
+/// A role assignment.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RoleAssignment {
+    #[serde(default)]
+    pub group: Option<AssignmentRef>,
+    pub role: AssignmentRef,
+    pub scope: AssignmentScope,
+    #[serde(default)]
+    pub user: Option<AssignmentRef>,
+}
+
+/// A list of role assignments.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RoleAssignmentsRoot {
+    pub role_assignments: Vec<RoleAssignment>,
+}
+
+/// A token validation result.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Token {
+    pub user: AssignmentRef,
+}
+
+/// A token validation root.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TokenRoot {
+    pub token: Token,
+}
+
+/// A user.
+#[derive(Debug, Clone, Deserialize)]
+pub struct User {
+    #[serde(default)]
+    pub default_project_id: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub domain_id: String,
+    pub enabled: bool,
+    pub id: String,
+    pub name: String,
+}
+
+/// A user root.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UserRoot {
+    pub user: User,
+}
+
+/// A list of users.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UsersRoot {
+    pub users: Vec<User>,
+}
+
+/// User arguments for a create request.
+#[derive(Debug, Clone, Serialize)]
+pub struct UserCreate {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_project_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+}
+
+/// A user create request.
+#[derive(Debug, Clone, Serialize)]
+pub struct UserCreateRoot {
+    pub user: UserCreate,
+}
+
+/// A user update.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UserUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_project_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+}
+
+/// A user update request.
+#[derive(Debug, Clone, Serialize)]
+pub struct UserUpdateRoot {
+    pub user: UserUpdate,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_role_id_or_name() {
+        assert_eq!(
+            serde_json::to_value(vec![
+                RoleIdOrName::Id { id: "1234".into() },
+                RoleIdOrName::Name {
+                    name: "reader".into()
+                },
+            ])
+            .unwrap(),
+            serde_json::json!([{"id": "1234"}, {"name": "reader"}])
+        );
+    }
+
+    #[test]
+    fn test_parse_application_credential() {
+        let root: ApplicationCredentialRoot = serde_json::from_value(serde_json::json!({
+            "application_credential": {
+                "access_rules": [
+                    {"id": "abcdef", "method": "GET", "path": "/v2.1/servers", "service": "compute"}
+                ],
+                "description": null,
+                "expires_at": "2026-02-27T18:30:59.000000",
+                "id": "58d61ff8e6e34accb35874016d1dba8b",
+                "name": "monitoring",
+                "project_id": "231c62fb0fbd485b995e8b060c3f0d98",
+                "roles": [{"domain_id": null, "id": "6aff702516544aeca22817fd3bc39683", "name": "reader"}],
+                "secret": "rEaqvJka48mpv",
+                "unrestricted": false
+            }
+        }))
+        .unwrap();
+        let cred = root.application_credential;
+        assert_eq!(cred.name, "monitoring");
+        assert_eq!(
+            cred.access_rules,
+            vec![AccessRule {
+                id: Some("abcdef".into()),
+                ..AccessRule::new("compute", "GET", "/v2.1/servers")
+            }]
+        );
+        assert_eq!(
+            cred.expires_at.unwrap().to_rfc3339(),
+            "2026-02-27T18:30:59+00:00"
+        );
+        assert_eq!(cred.roles[0].name, "reader");
+        assert_eq!(cred.secret.as_deref(), Some("rEaqvJka48mpv"));
+        assert!(!cred.unrestricted);
+    }
+
+    #[test]
+    fn test_parse_application_credential_no_expiry() {
+        let cred: ApplicationCredential = serde_json::from_value(serde_json::json!({
+            "expires_at": null,
+            "id": "58d61ff8e6e34accb35874016d1dba8b",
+            "name": "monitoring",
+            "project_id": "231c62fb0fbd485b995e8b060c3f0d98",
+            "unrestricted": true
+        }))
+        .unwrap();
+        assert!(cred.expires_at.is_none());
+        assert!(cred.access_rules.is_empty());
+        assert!(cred.unrestricted);
+    }
+
+    #[test]
+    fn test_parse_role_assignments() {
+        let root: RoleAssignmentsRoot = serde_json::from_value(serde_json::json!({
+            "role_assignments": [
+                {
+                    "links": {"assignment": "http://example.com/identity/v3/..."},
+                    "role": {"id": "123456", "name": "member"},
+                    "scope": {"project": {"id": "456789", "name": "demo"}},
+                    "user": {"id": "313233"}
+                },
+                {
+                    "group": {"id": "101112"},
+                    "role": {"id": "123456"},
+                    "scope": {"domain": {"id": "161718"}}
+                }
+            ]
+        }))
+        .unwrap();
+        let first = &root.role_assignments[0];
+        assert_eq!(first.role.name.as_deref(), Some("member"));
+        assert_eq!(first.scope.project.as_ref().unwrap().id, "456789");
+        assert_eq!(first.user.as_ref().unwrap().id, "313233");
+        assert!(first.group.is_none());
+        let second = &root.role_assignments[1];
+        assert_eq!(second.group.as_ref().unwrap().id, "101112");
+        assert_eq!(second.scope.domain.as_ref().unwrap().id, "161718");
+        assert!(second.scope.project.is_none());
+    }
+}
@@ -0,0 +1,584 @@
+// Copyright 2026 The rust-openstack Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Role and role assignment management via Identity API.
+
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use futures::stream::{Stream, TryStreamExt};
+
+use super::super::common::{
+    DomainRef, GroupRef, ProjectRef, Refresh, ResourceIterator, ResourceQuery, RoleRef, UserRef,
+};
+use super::super::session::Session;
+use super::super::utils::Query;
+use super::super::{Error, ErrorKind, Result};
+use super::{api, protocol};
+
+/// A query to role list.
+#[derive(Clone, Debug)]
+pub struct RoleQuery {
+    session: Session,
+    query: Query,
+}
+
+/// Structure representing a single role.
+#[derive(Clone, Debug)]
+pub struct Role {
+    session: Session,
+    inner: protocol::Role,
+    dirty: HashSet<&'static str>,
+}
+
+/// A request to create a role.
+#[derive(Clone, Debug)]
+pub struct NewRole {
+    session: Session,
+    inner: protocol::RoleCreate,
+    domain: Option<DomainRef>,
+}
+
+/// A query to role assignment list.
+#[derive(Clone, Debug)]
+pub struct RoleAssignmentQuery {
+    session: Session,
+    query: Query,
+}
+
+/// Structure representing a role assignment.
+///
+/// A role assignment grants a role to a user or a group on a project or
+/// a domain.
+#[derive(Clone, Debug)]
+pub struct RoleAssignment {
+    session: Session,
+    inner: protocol::RoleAssignment,
+}
+
+/// A request to assign a role.
+#[derive(Clone, Debug)]
+pub struct NewRoleAssignment {
+    session: Session,
+    role: RoleRef,
+    user: Option<UserRef>,
+    group: Option<GroupRef>,
+    project: Option<ProjectRef>,
+    domain: Option<DomainRef>,
+}
+
+impl Role {
+    /// Create a role object.
+    fn from_inner(session: Session, inner: protocol::Role) -> Role {
+        Role {
+            session,
+            inner,
+            dirty: HashSet::new(),
+        }
+    }
+
+    /// Load a Role object.
+    pub(crate) async fn new<Id: AsRef<str>>(session: Session, id: Id) -> Result<Role> {
+        let inner = api::get_role(&session, id).await?;
+        Ok(Role::from_inner(session, inner))
+    }
+
+    transparent_property! {
+        #[doc = "Role description."]
+        description: ref Option<String>
+    }
+
+    update_field! {
+        #[doc = "Update the description."]
+        set_description, with_description -> description: optional String
+    }
+
+    transparent_property! {
+        #[doc = "ID of the domain for domain-specific roles."]
+        domain_id: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "Unique ID."]
+        id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Role name."]
+        name: ref String
+    }
+
+    update_field! {
+        #[doc = "Update the name."]
+        set_name, with_name -> name
+    }
+
+    /// Delete the role.
+    ///
+    /// All assignments of this role are removed as well.
+    pub async fn delete(self) -> Result<()> {
+        api::delete_role(&self.session, &self.inner.id).await
+    }
+
+    /// Whether the role is modified.
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Save the changes to the role.
+    #[allow(clippy::field_reassign_with_default)]
+    pub async fn save(&mut self) -> Result<()> {
+        let mut update = protocol::RoleUpdate::default();
+        save_fields! {
+            self -> update: name
+        };
+        save_option_fields! {
+            self -> update: description
+        };
+        let inner = api::update_role(&self.session, self.id(), update).await?;
+        self.dirty.clear();
+        self.inner = inner;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Refresh for Role {
+    /// Refresh the role.
+    async fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_role_by_id(&self.session, &self.inner.id).await?;
+        self.dirty.clear();
+        Ok(())
+    }
+}
+
+impl RoleQuery {
+    pub(crate) fn new(session: Session) -> RoleQuery {
+        RoleQuery {
+            session,
+            query: Query::new(),
+        }
+    }
+
+    query_filter! {
+        #[doc = "Filter by domain ID (only returns domain-specific roles)."]
+        with_domain_id -> domain_id
+    }
+
+    query_filter! {
+        #[doc = "Filter by role name."]
+        with_name -> name
+    }
+
+    /// Convert this query into a stream executing the request.
+    ///
+    /// Returns a `TryStream`, which is a stream with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_stream(self) -> impl Stream<Item = Result<Role>> {
+        debug!("Fetching roles with {:?}", self.query);
+        ResourceIterator::new(self).into_stream()
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_stream().try_collect().await`.
+    pub async fn all(self) -> Result<Vec<Role>> {
+        self.into_stream().try_collect().await
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub async fn one(self) -> Result<Role> {
+        debug!("Fetching one role with {:?}", self.query);
+        ResourceIterator::new(self).one().await
+    }
+}
+
+#[async_trait]
+impl ResourceQuery for RoleQuery {
+    type Item = Role;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    async fn can_paginate(&self) -> Result<bool> {
+        // The Identity API does not support pagination.
+        Ok(false)
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.id().clone()
+    }
+
+    async fn fetch_chunk(
+        &self,
+        _limit: Option<usize>,
+        _marker: Option<String>,
+    ) -> Result<Vec<Self::Item>> {
+        Ok(api::list_roles(&self.session, &self.query)
+            .await?
+            .into_iter()
+            .map(|item| Role::from_inner(self.session.clone(), item))
+            .collect())
+    }
+}
+
+impl NewRole {
+    /// Start creating a role.
+    pub(crate) fn new(session: Session, name: String) -> NewRole {
+        NewRole {
+            session,
+            inner: protocol::RoleCreate {
+                name,
+                description: None,
+                domain_id: None,
+            },
+            domain: None,
+        }
+    }
+
+    /// Request creation of the role.
+    pub async fn create(mut self) -> Result<Role> {
+        if let Some(domain) = self.domain {
+            self.inner.domain_id = Some(domain.into_verified(&self.session).await?.into());
+        }
+
+        let inner = api::create_role(&self.session, self.inner).await?;
+        Ok(Role::from_inner(self.session, inner))
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the description of the role."]
+        set_description, with_description -> description: optional String
+    }
+
+    /// Make the role specific to a domain (global by default).
+    pub fn set_domain<D: Into<DomainRef>>(&mut self, value: D) {
+        self.domain = Some(value.into());
+    }
+
+    /// Make the role specific to a domain (global by default).
+    #[inline]
+    pub fn with_domain<D: Into<DomainRef>>(mut self, value: D) -> Self {
+        self.set_domain(value);
+        self
+    }
+}
+
+impl From<Role> for RoleRef {
+    fn from(value: Role) -> RoleRef {
+        RoleRef::new_verified(value.inner.id)
+    }
+}
+
+#[cfg(feature = "identity")]
+impl RoleRef {
+    /// Verify this reference and convert to an ID, if possible.
+    pub(crate) async fn into_verified(self, session: &Session) -> Result<RoleRef> {
+        Ok(if self.verified {
+            self
+        } else {
+            RoleRef::new_verified(api::get_role(session, &self.value).await?.id)
+        })
+    }
+}
+
+impl RoleAssignment {
+    /// ID of the domain the role is assigned on (if any).
+    #[inline]
+    pub fn domain_id(&self) -> Option<&String> {
+        self.inner.scope.domain.as_ref().map(|item| &item.id)
+    }
+
+    /// ID of the group the role is assigned to (if any).
+    #[inline]
+    pub fn group_id(&self) -> Option<&String> {
+        self.inner.group.as_ref().map(|item| &item.id)
+    }
+
+    /// ID of the project the role is assigned on (if any).
+    #[inline]
+    pub fn project_id(&self) -> Option<&String> {
+        self.inner.scope.project.as_ref().map(|item| &item.id)
+    }
+
+    /// ID of the assigned role.
+    #[inline]
+    pub fn role_id(&self) -> &String {
+        &self.inner.role.id
+    }
+
+    /// Name of the assigned role.
+    ///
+    /// Only available if the query used `with_names`.
+    #[inline]
+    pub fn role_name(&self) -> Option<&String> {
+        self.inner.role.name.as_ref()
+    }
+
+    /// ID of the user the role is assigned to (if any).
+    #[inline]
+    pub fn user_id(&self) -> Option<&String> {
+        self.inner.user.as_ref().map(|item| &item.id)
+    }
+
+    /// Revoke the role assignment.
+    ///
+    /// Effective assignments (e.g. ones coming from a group membership)
+    /// cannot be revoked directly.
+    pub async fn delete(self) -> Result<()> {
+        let target = match (&self.inner.scope.project, &self.inner.scope.domain) {
+            (Some(project), _) => ("projects", project.id.as_str()),
+            (None, Some(domain)) => ("domains", domain.id.as_str()),
+            (None, None) => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "Only project or domain role assignments can be revoked",
+                ))
+            }
+        };
+        let actor = match (&self.inner.user, &self.inner.group) {
+            (Some(user), _) => ("users", user.id.as_str()),
+            (None, Some(group)) => ("groups", group.id.as_str()),
+            (None, None) => {
+                return Err(Error::new(
+                    ErrorKind::InvalidResponse,
+                    "Role assignment has neither a user nor a group",
+                ))
+            }
+        };
+        api::revoke_role(&self.session, target, actor, &self.inner.role.id).await
+    }
+}
+
+impl RoleAssignmentQuery {
+    pub(crate) fn new(session: Session) -> RoleAssignmentQuery {
+        RoleAssignmentQuery {
+            session,
+            query: Query::new(),
+        }
+    }
+
+    /// Filter by domain ID.
+    pub fn with_domain_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.query.push_str("scope.domain.id", value);
+        self
+    }
+
+    /// Include effective assignments, e.g. ones coming from group membership.
+    pub fn with_effective(mut self) -> Self {
+        self.query.push("effective", true);
+        self
+    }
+
+    /// Filter by group ID.
+    pub fn with_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.query.push_str("group.id", value);
+        self
+    }
+
+    /// Include names of roles, users, groups, projects and domains.
+    pub fn with_names(mut self) -> Self {
+        self.query.push("include_names", true);
+        self
+    }
+
+    /// Filter by project ID.
+    pub fn with_project_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.query.push_str("scope.project.id", value);
+        self
+    }
+
+    /// Filter by role ID.
+    pub fn with_role_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.query.push_str("role.id", value);
+        self
+    }
+
+    /// Filter by user ID.
+    pub fn with_user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.query.push_str("user.id", value);
+        self
+    }
+
+    /// Convert this query into a stream executing the request.
+    ///
+    /// Returns a `TryStream`, which is a stream with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_stream(self) -> impl Stream<Item = Result<RoleAssignment>> {
+        debug!("Fetching role assignments with {:?}", self.query);
+        ResourceIterator::new(self).into_stream()
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_stream().try_collect().await`.
+    pub async fn all(self) -> Result<Vec<RoleAssignment>> {
+        self.into_stream().try_collect().await
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub async fn one(self) -> Result<RoleAssignment> {
+        debug!("Fetching one role assignment with {:?}", self.query);
+        ResourceIterator::new(self).one().await
+    }
+}
+
+#[async_trait]
+impl ResourceQuery for RoleAssignmentQuery {
+    type Item = RoleAssignment;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    async fn can_paginate(&self) -> Result<bool> {
+        // The Identity API does not support pagination.
+        Ok(false)
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.role_id().clone()
+    }
+
+    async fn fetch_chunk(
+        &self,
+        _limit: Option<usize>,
+        _marker: Option<String>,
+    ) -> Result<Vec<Self::Item>> {
+        Ok(api::list_role_assignments(&self.session, &self.query)
+            .await?
+            .into_iter()
+            .map(|item| RoleAssignment {
+                session: self.session.clone(),
+                inner: item,
+            })
+            .collect())
+    }
+}
+
+impl NewRoleAssignment {
+    /// Start assigning a role.
+    pub(crate) fn new(session: Session, role: RoleRef) -> NewRoleAssignment {
+        NewRoleAssignment {
+            session,
+            role,
+            user: None,
+            group: None,
+            project: None,
+            domain: None,
+        }
+    }
+
+    /// Request the role assignment.
+    ///
+    /// Exactly one of a user or a group and exactly one of a project or
+    /// a domain must be provided.
+    pub async fn create(self) -> Result<()> {
+        let target = match (self.project, self.domain) {
+            (Some(project), None) => (
+                "projects",
+                String::from(project.into_verified(&self.session).await?),
+            ),
+            (None, Some(domain)) => (
+                "domains",
+                String::from(domain.into_verified(&self.session).await?),
+            ),
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "Exactly one of a project or a domain is required",
+                ))
+            }
+        };
+        let actor = match (self.user, self.group) {
+            (Some(user), None) => (
+                "users",
+                String::from(user.into_verified(&self.session).await?),
+            ),
+            (None, Some(group)) => (
+                "groups",
+                String::from(group.into_verified(&self.session).await?),
+            ),
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "Exactly one of a user or a group is required",
+                ))
+            }
+        };
+        let role = self.role.into_verified(&self.session).await?;
+        api::grant_role(
+            &self.session,
+            (target.0, &target.1),
+            (actor.0, &actor.1),
+            role,
+        )
+        .await
+    }
+
+    /// Assign the role on a domain.
+    pub fn set_domain<D: Into<DomainRef>>(&mut self, value: D) {
+        self.domain = Some(value.into());
+    }
+
+    /// Assign the role to a group.
+    pub fn set_group<G: Into<GroupRef>>(&mut self, value: G) {
+        self.group = Some(value.into());
+    }
+
+    /// Assign the role on a project.
+    pub fn set_project<P: Into<ProjectRef>>(&mut self, value: P) {
+        self.project = Some(value.into());
+    }
+
+    /// Assign the role to a user.
+    pub fn set_user<U: Into<UserRef>>(&mut self, value: U) {
+        self.user = Some(value.into());
+    }
+
+    /// Assign the role on a domain.
+    #[inline]
+    pub fn with_domain<D: Into<DomainRef>>(mut self, value: D) -> Self {
+        self.set_domain(value);
+        self
+    }
+
+    /// Assign the role to a group.
+    #[inline]
+    pub fn with_group<G: Into<GroupRef>>(mut self, value: G) -> Self {
+        self.set_group(value);
+        self
+    }
+
+    /// Assign the role on a project.
+    #[inline]
+    pub fn with_project<P: Into<ProjectRef>>(mut self, value: P) -> Self {
+        self.set_project(value);
+        self
+    }
+
+    /// Assign the role to a user.
+    #[inline]
+    pub fn with_user<U: Into<UserRef>>(mut self, value: U) -> Self {
+        self.set_user(value);
+        self
+    }
+}
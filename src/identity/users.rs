@@ -0,0 +1,350 @@
+// Copyright 2026 The rust-openstack Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! User management via Identity API.
+
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use futures::stream::{Stream, TryStreamExt};
+
+use super::super::common::{
+    DomainRef, ProjectRef, Refresh, ResourceIterator, ResourceQuery, UserRef,
+};
+use super::super::session::Session;
+use super::super::utils::Query;
+use super::super::Result;
+use super::{api, protocol, Domain, Project};
+
+/// A query to user list.
+#[derive(Clone, Debug)]
+pub struct UserQuery {
+    session: Session,
+    query: Query,
+}
+
+/// Structure representing a single user.
+#[derive(Clone, Debug)]
+pub struct User {
+    session: Session,
+    inner: protocol::User,
+    dirty: HashSet<&'static str>,
+}
+
+/// A request to create a user.
+#[derive(Clone, Debug)]
+pub struct NewUser {
+    session: Session,
+    inner: protocol::UserCreate,
+    default_project: Option<ProjectRef>,
+    domain: Option<DomainRef>,
+}
+
+impl User {
+    /// Create a user object.
+    pub(crate) fn from_inner(session: Session, inner: protocol::User) -> User {
+        User {
+            session,
+            inner,
+            dirty: HashSet::new(),
+        }
+    }
+
+    /// Load a User object.
+    pub(crate) async fn new<Id: AsRef<str>>(session: Session, id: Id) -> Result<User> {
+        let inner = api::get_user(&session, id).await?;
+        Ok(User::from_inner(session, inner))
+    }
+
+    /// Fetch the default project of the user (if any).
+    pub async fn default_project(&self) -> Result<Option<Project>> {
+        Ok(match self.inner.default_project_id {
+            Some(ref id) => Some(Project::new(self.session.clone(), id).await?),
+            None => None,
+        })
+    }
+
+    transparent_property! {
+        #[doc = "ID of the default project of the user (if any)."]
+        default_project_id: ref Option<String>
+    }
+
+    update_field! {
+        #[doc = "Update the default project ID."]
+        set_default_project_id, with_default_project_id -> default_project_id: optional String
+    }
+
+    transparent_property! {
+        #[doc = "User description."]
+        description: ref Option<String>
+    }
+
+    update_field! {
+        #[doc = "Update the description."]
+        set_description, with_description -> description: optional String
+    }
+
+    transparent_property! {
+        #[doc = "ID of the domain the user belongs to."]
+        domain_id: ref String
+    }
+
+    /// Fetch the domain the user belongs to.
+    pub async fn domain(&self) -> Result<Domain> {
+        Domain::new(self.session.clone(), &self.inner.domain_id).await
+    }
+
+    transparent_property! {
+        #[doc = "Whether the user is enabled."]
+        enabled: bool
+    }
+
+    update_field! {
+        #[doc = "Enable or disable the user."]
+        set_enabled, with_enabled -> enabled: bool
+    }
+
+    transparent_property! {
+        #[doc = "Unique ID."]
+        id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "User name."]
+        name: ref String
+    }
+
+    update_field! {
+        #[doc = "Update the name."]
+        set_name, with_name -> name
+    }
+
+    /// Delete the user.
+    pub async fn delete(self) -> Result<()> {
+        api::delete_user(&self.session, &self.inner.id).await
+    }
+
+    /// Whether the user is modified.
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Save the changes to the user.
+    #[allow(clippy::field_reassign_with_default)]
+    pub async fn save(&mut self) -> Result<()> {
+        let mut update = protocol::UserUpdate::default();
+        save_fields! {
+            self -> update: enabled name
+        };
+        save_option_fields! {
+            self -> update: default_project_id description
+        };
+        let inner = api::update_user(&self.session, self.id(), update).await?;
+        self.dirty.clear();
+        self.inner = inner;
+        Ok(())
+    }
+
+    /// Set a new password for the user (admin-only).
+    ///
+    /// Unlike other updates, this call is executed immediately.
+    pub async fn update_password<S: Into<String>>(&self, password: S) -> Result<()> {
+        let update = protocol::UserUpdate {
+            password: Some(password.into()),
+            ..Default::default()
+        };
+        let _ = api::update_user(&self.session, &self.inner.id, update).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Refresh for User {
+    /// Refresh the user.
+    async fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_user_by_id(&self.session, &self.inner.id).await?;
+        self.dirty.clear();
+        Ok(())
+    }
+}
+
+impl UserQuery {
+    pub(crate) fn new(session: Session) -> UserQuery {
+        UserQuery {
+            session,
+            query: Query::new(),
+        }
+    }
+
+    query_filter! {
+        #[doc = "Filter by domain ID."]
+        with_domain_id -> domain_id
+    }
+
+    query_filter! {
+        #[doc = "Filter by whether the user is enabled."]
+        with_enabled -> enabled: bool
+    }
+
+    query_filter! {
+        #[doc = "Filter by user name."]
+        with_name -> name
+    }
+
+    /// Convert this query into a stream executing the request.
+    ///
+    /// Returns a `TryStream`, which is a stream with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_stream(self) -> impl Stream<Item = Result<User>> {
+        debug!("Fetching users with {:?}", self.query);
+        ResourceIterator::new(self).into_stream()
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_stream().try_collect().await`.
+    pub async fn all(self) -> Result<Vec<User>> {
+        self.into_stream().try_collect().await
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub async fn one(self) -> Result<User> {
+        debug!("Fetching one user with {:?}", self.query);
+        ResourceIterator::new(self).one().await
+    }
+}
+
+#[async_trait]
+impl ResourceQuery for UserQuery {
+    type Item = User;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    async fn can_paginate(&self) -> Result<bool> {
+        // The Identity API does not support pagination.
+        Ok(false)
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.id().clone()
+    }
+
+    async fn fetch_chunk(
+        &self,
+        _limit: Option<usize>,
+        _marker: Option<String>,
+    ) -> Result<Vec<Self::Item>> {
+        Ok(api::list_users(&self.session, &self.query)
+            .await?
+            .into_iter()
+            .map(|item| User::from_inner(self.session.clone(), item))
+            .collect())
+    }
+}
+
+impl NewUser {
+    /// Start creating a user.
+    pub(crate) fn new(session: Session, name: String) -> NewUser {
+        NewUser {
+            session,
+            inner: protocol::UserCreate {
+                name,
+                default_project_id: None,
+                description: None,
+                domain_id: None,
+                enabled: None,
+                password: None,
+            },
+            default_project: None,
+            domain: None,
+        }
+    }
+
+    /// Request creation of the user.
+    pub async fn create(mut self) -> Result<User> {
+        if let Some(project) = self.default_project {
+            self.inner.default_project_id =
+                Some(project.into_verified(&self.session).await?.into());
+        }
+
+        if let Some(domain) = self.domain {
+            self.inner.domain_id = Some(domain.into_verified(&self.session).await?.into());
+        }
+
+        let inner = api::create_user(&self.session, self.inner).await?;
+        Ok(User::from_inner(self.session, inner))
+    }
+
+    /// Set the default project of the user.
+    pub fn set_default_project<P: Into<ProjectRef>>(&mut self, value: P) {
+        self.default_project = Some(value.into());
+    }
+
+    /// Set the default project of the user.
+    #[inline]
+    pub fn with_default_project<P: Into<ProjectRef>>(mut self, value: P) -> Self {
+        self.set_default_project(value);
+        self
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the description of the user."]
+        set_description, with_description -> description: optional String
+    }
+
+    /// Set the domain of the user (defaults to the domain of the current user).
+    pub fn set_domain<D: Into<DomainRef>>(&mut self, value: D) {
+        self.domain = Some(value.into());
+    }
+
+    /// Set the domain of the user (defaults to the domain of the current user).
+    #[inline]
+    pub fn with_domain<D: Into<DomainRef>>(mut self, value: D) -> Self {
+        self.set_domain(value);
+        self
+    }
+
+    creation_inner_field! {
+        #[doc = "Set whether the user is enabled (the default is true)."]
+        set_enabled, with_enabled -> enabled: optional bool
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the password of the user."]
+        set_password, with_password -> password: optional String
+    }
+}
+
+impl From<User> for UserRef {
+    fn from(value: User) -> UserRef {
+        UserRef::new_verified(value.inner.id)
+    }
+}
+
+#[cfg(feature = "identity")]
+impl UserRef {
+    /// Verify this reference and convert to an ID, if possible.
+    pub(crate) async fn into_verified(self, session: &Session) -> Result<UserRef> {
+        Ok(if self.verified {
+            self
+        } else {
+            UserRef::new_verified(api::get_user(session, &self.value).await?.id)
+        })
+    }
+}
@@ -16,15 +16,53 @@
 
 use std::fmt::Debug;
 
+use futures::io::AsyncRead;
 use osauth::services::IMAGE;
 use osauth::ErrorKind;
 use serde::Serialize;
 
 use super::super::session::Session;
 use super::super::utils;
+use super::super::utils::io::{async_read_to_body, body_to_async_read};
 use super::super::Result;
 use super::protocol::*;
 
+/// Create an image.
+pub async fn create_image(session: &Session, request: ImageCreate) -> Result<Image> {
+    debug!("Creating an image with {:?}", request);
+    let image: Image = session
+        .post(IMAGE, &["images"])
+        .json(&request)
+        .fetch()
+        .await?;
+    debug!("Created image {:?}", image);
+    Ok(image)
+}
+
+/// Delete an image.
+pub async fn delete_image<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
+    debug!("Deleting image {}", id.as_ref());
+    let _ = session
+        .delete(IMAGE, &["images", id.as_ref()])
+        .send()
+        .await?;
+    debug!("Image {} was deleted", id.as_ref());
+    Ok(())
+}
+
+/// Download image data.
+pub async fn download_image<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+) -> Result<impl AsyncRead + Send + Sync + 'static> {
+    trace!("Downloading data for image {}", id.as_ref());
+    let resp = session
+        .get(IMAGE, &["images", id.as_ref(), "file"])
+        .send()
+        .await?;
+    Ok(body_to_async_read(resp))
+}
+
 /// Get an image.
 pub async fn get_image<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<Image> {
     let s = id_or_name.as_ref();
@@ -72,3 +110,20 @@ pub async fn list_images<Q: Serialize + Sync + Debug>(
     trace!("Received images: {:?}", root.images);
     Ok(root.images)
 }
+
+/// Upload image data.
+pub async fn upload_image_data<S, R>(session: &Session, id: S, body: R) -> Result<()>
+where
+    S: AsRef<str>,
+    R: AsyncRead + Send + Sync + 'static,
+{
+    debug!("Uploading data for image {}", id.as_ref());
+    let _ = session
+        .put(IMAGE, &["images", id.as_ref(), "file"])
+        .header("Content-Type", "application/octet-stream")
+        .body(async_read_to_body(body))
+        .send()
+        .await?;
+    debug!("Successfully uploaded data for image {}", id.as_ref());
+    Ok(())
+}
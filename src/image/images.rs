@@ -14,14 +14,27 @@
 
 //! Image management via Image API.
 
+use std::collections::HashMap;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
 use async_trait::async_trait;
 use chrono::{DateTime, FixedOffset};
+use futures::io::AsyncRead;
+use futures::ready;
 use futures::stream::{Stream, TryStreamExt};
+use md5::Md5;
+use pin_project::pin_project;
+use sha2::digest::DynDigest;
+use sha2::{Sha224, Sha256, Sha384, Sha512};
 
 use super::super::common::{ImageRef, Refresh, ResourceIterator, ResourceQuery};
 use super::super::session::Session;
 use super::super::utils::Query;
-use super::super::{Result, Sort};
+use super::super::waiter::{DeletionWaiter, Waiter};
+use super::super::{Error, ErrorKind, Result, Sort};
 use super::{api, protocol};
 
 /// A query to image list.
@@ -40,6 +53,29 @@ pub struct Image {
     inner: protocol::Image,
 }
 
+/// A request to create an image.
+#[derive(Clone, Debug)]
+pub struct NewImage {
+    session: Session,
+    inner: protocol::ImageCreate,
+}
+
+/// Waiter for an image to become active, e.g. after its data is uploaded.
+#[derive(Debug)]
+pub struct ImageCreationWaiter {
+    image: Image,
+}
+
+/// A reader that verifies image checksums when the end of data is reached.
+#[pin_project]
+struct ChecksumReader<R> {
+    #[pin]
+    inner: R,
+    image_id: String,
+    checksums: Vec<(Box<dyn DynDigest + Send + Sync>, String)>,
+    verified: bool,
+}
+
 impl Image {
     /// Create an Image object.
     pub(crate) async fn new<Id: AsRef<str>>(session: Session, id: Id) -> Result<Image> {
@@ -59,7 +95,7 @@ impl Image {
 
     transparent_property! {
         #[doc = "Container format."]
-        container_format: Option<protocol::ImageContainerFormat>
+        container_format: clone Option<protocol::ImageContainerFormat>
     }
 
     transparent_property! {
@@ -69,7 +105,7 @@ impl Image {
 
     transparent_property! {
         #[doc = "Disk format."]
-        disk_format: Option<protocol::ImageDiskFormat>
+        disk_format: clone Option<protocol::ImageDiskFormat>
     }
 
     transparent_property! {
@@ -96,6 +132,16 @@ impl Image {
         name: ref String
     }
 
+    transparent_property! {
+        #[doc = "Algorithm used to calculate `os_hash_value`."]
+        os_hash_algo: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "Secure hash of the image data."]
+        os_hash_value: ref Option<String>
+    }
+
     transparent_property! {
         #[doc = "Image size in bytes."]
         size: Option<u64>
@@ -103,7 +149,7 @@ impl Image {
 
     transparent_property! {
         #[doc = "Image status."]
-        status: protocol::ImageStatus
+        status: clone protocol::ImageStatus
     }
 
     transparent_property! {
@@ -118,7 +164,38 @@ impl Image {
 
     transparent_property! {
         #[doc = "Image visibility."]
-        visibility: protocol::ImageVisibility
+        visibility: clone protocol::ImageVisibility
+    }
+
+    /// Download the image data.
+    ///
+    /// The `checksum` and, if present, `os_hash_value` of the image are
+    /// verified while reading. A mismatch results in an I/O error of kind
+    /// `InvalidData` wrapping an `InvalidResponse` error.
+    pub async fn download(&self) -> Result<impl AsyncRead + Send + Sync + '_> {
+        let data = api::download_image(&self.session, &self.inner.id).await?;
+        Ok(ChecksumReader::new(data, &self.inner))
+    }
+
+    /// Delete the image.
+    pub async fn delete(self) -> Result<DeletionWaiter<Image>> {
+        api::delete_image(&self.session, &self.inner.id).await?;
+        Ok(DeletionWaiter::new(
+            self,
+            Duration::new(60, 0),
+            Duration::new(1, 0),
+        ))
+    }
+
+    /// Upload data for the image, optionally wait for it to become active.
+    ///
+    /// Data can only be uploaded to an image in the `Queued` status.
+    pub async fn upload<R>(self, data: R) -> Result<ImageCreationWaiter>
+    where
+        R: AsyncRead + Send + Sync + 'static,
+    {
+        api::upload_image_data(&self.session, &self.inner.id, data).await?;
+        Ok(ImageCreationWaiter { image: self })
     }
 }
 
@@ -251,6 +328,237 @@ impl ResourceQuery for ImageQuery {
     }
 }
 
+impl NewImage {
+    /// Start creating an image.
+    pub(crate) fn new(session: Session) -> NewImage {
+        NewImage {
+            session,
+            inner: protocol::ImageCreate::default(),
+        }
+    }
+
+    /// Request creation of the image without data.
+    ///
+    /// The resulting image is in the `Queued` status until data is uploaded
+    /// to it with [Image::upload](struct.Image.html#method.upload).
+    pub async fn create(self) -> Result<Image> {
+        let inner = api::create_image(&self.session, self.inner).await?;
+        Ok(Image {
+            session: self.session,
+            inner,
+        })
+    }
+
+    /// Request creation of the image and upload its data.
+    ///
+    /// The image is deleted if the data cannot be uploaded.
+    pub async fn create_with_data<R>(self, data: R) -> Result<ImageCreationWaiter>
+    where
+        R: AsyncRead + Send + Sync + 'static,
+    {
+        let image = self.create().await?;
+        if let Err(err) = api::upload_image_data(&image.session, &image.inner.id, data).await {
+            warn!(
+                "Failed to upload data for image {}, deleting it: {}",
+                image.inner.id, err
+            );
+            if let Err(del_err) = api::delete_image(&image.session, &image.inner.id).await {
+                warn!("Failed to delete image {}: {}", image.inner.id, del_err);
+            }
+            return Err(err);
+        }
+        Ok(ImageCreationWaiter { image })
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the container format."]
+        set_container_format, with_container_format -> container_format: optional protocol::ImageContainerFormat
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the disk format."]
+        set_disk_format, with_disk_format -> disk_format: optional protocol::ImageDiskFormat
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the minimum required disk size in GiB."]
+        set_min_disk, with_min_disk -> min_disk: optional u32
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the minimum required RAM size in MiB."]
+        set_min_ram, with_min_ram -> min_ram: optional u32
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the image name."]
+        set_name, with_name -> name: optional String
+    }
+
+    /// Additional properties of the image.
+    #[inline]
+    pub fn properties(&mut self) -> &mut HashMap<String, String> {
+        &mut self.inner.properties
+    }
+
+    /// Add an additional property to the image.
+    pub fn with_property<K, V>(mut self, key: K, value: V) -> NewImage
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let _ = self.inner.properties.insert(key.into(), value.into());
+        self
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the image visibility."]
+        set_visibility, with_visibility -> visibility: optional protocol::ImageVisibility
+    }
+}
+
+impl ImageCreationWaiter {
+    /// Wait for an existing image to become active.
+    #[cfg(feature = "compute")]
+    pub(crate) fn new(image: Image) -> ImageCreationWaiter {
+        ImageCreationWaiter { image }
+    }
+
+    /// Current state of the image.
+    pub fn current_state(&self) -> &Image {
+        &self.image
+    }
+}
+
+#[async_trait]
+impl Waiter<Image, Error> for ImageCreationWaiter {
+    fn default_wait_timeout(&self) -> Option<Duration> {
+        Some(Duration::new(1800, 0))
+    }
+
+    fn default_delay(&self) -> Duration {
+        Duration::new(1, 0)
+    }
+
+    fn timeout_error(&self) -> Error {
+        Error::new(
+            ErrorKind::OperationTimedOut,
+            format!(
+                "Timeout waiting for image {} to become active",
+                self.image.id()
+            ),
+        )
+    }
+
+    async fn poll(&mut self) -> Result<Option<Image>> {
+        self.image.refresh().await?;
+        match self.image.status() {
+            protocol::ImageStatus::Active => {
+                debug!("Image {} successfully created", self.image.id());
+                Ok(Some(self.image.clone()))
+            }
+            protocol::ImageStatus::Killed | protocol::ImageStatus::Deleted => {
+                debug!(
+                    "Failed to create image {} - status is {}",
+                    self.image.id(),
+                    self.image.status()
+                );
+                Err(Error::new(
+                    ErrorKind::OperationFailed,
+                    format!(
+                        "Image {} got into {} state",
+                        self.image.id(),
+                        self.image.status()
+                    ),
+                ))
+            }
+            status => {
+                trace!(
+                    "Still waiting for image {} to become active, current is {}",
+                    self.image.id(),
+                    status
+                );
+                Ok(None)
+            }
+        }
+    }
+}
+
+fn new_hasher(algo: &str) -> Option<Box<dyn DynDigest + Send + Sync>> {
+    Some(match algo.to_lowercase().as_str() {
+        "md5" => Box::<Md5>::default(),
+        "sha224" => Box::<Sha224>::default(),
+        "sha256" => Box::<Sha256>::default(),
+        "sha384" => Box::<Sha384>::default(),
+        "sha512" => Box::<Sha512>::default(),
+        _ => return None,
+    })
+}
+
+impl<R> ChecksumReader<R> {
+    fn new(inner: R, image: &protocol::Image) -> ChecksumReader<R> {
+        let mut checksums = Vec::new();
+        if let Some(ref value) = image.checksum {
+            checksums.push((new_hasher("md5").unwrap(), value.to_lowercase()));
+        }
+        if let (Some(algo), Some(value)) = (&image.os_hash_algo, &image.os_hash_value) {
+            if let Some(hasher) = new_hasher(algo) {
+                checksums.push((hasher, value.to_lowercase()));
+            } else {
+                warn!(
+                    "Unsupported hash algorithm {} for image {}, not verifying it",
+                    algo, image.id
+                );
+            }
+        }
+
+        ChecksumReader {
+            inner,
+            image_id: image.id.clone(),
+            checksums,
+            verified: false,
+        }
+    }
+}
+
+impl<R: AsyncRead> AsyncRead for ChecksumReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.project();
+        let size = ready!(this.inner.poll_read(cx, buf))?;
+        if size > 0 {
+            for (hasher, _) in this.checksums.iter_mut() {
+                hasher.update(&buf[..size]);
+            }
+        } else if !buf.is_empty() && !*this.verified {
+            *this.verified = true;
+            for (hasher, expected) in this.checksums.iter_mut() {
+                let actual = hasher
+                    .finalize_reset()
+                    .iter()
+                    .map(|byte| format!("{:02x}", byte))
+                    .collect::<String>();
+                if &actual != expected {
+                    let err = Error::new(
+                        ErrorKind::InvalidResponse,
+                        format!(
+                            "Checksum mismatch for image {}: expected {}, got {}",
+                            this.image_id, expected, actual
+                        ),
+                    );
+                    return Poll::Ready(Err(io::Error::new(io::ErrorKind::InvalidData, err)));
+                }
+            }
+            trace!("Checksums verified for image {}", this.image_id);
+        }
+
+        Poll::Ready(Ok(size))
+    }
+}
+
 impl From<Image> for ImageRef {
     fn from(value: Image) -> ImageRef {
         ImageRef::new_verified(value.inner.id)
@@ -268,3 +576,61 @@ impl ImageRef {
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use futures::io::{AsyncReadExt, Cursor};
+
+    use super::super::protocol;
+    use super::ChecksumReader;
+
+    fn image(checksum: &str, algo: &str, value: &str) -> protocol::Image {
+        serde_json::from_value(serde_json::json!({
+            "checksum": checksum,
+            "created_at": "2026-01-01T00:00:00Z",
+            "id": "b8f4e1a9-33ee-4bb7-a43c-0e3b0e8a3c2d",
+            "os_hash_algo": algo,
+            "os_hash_value": value,
+            "status": "active",
+            "updated_at": "2026-01-01T00:00:00Z",
+            "visibility": "private"
+        }))
+        .unwrap()
+    }
+
+    const MD5: &str = "5eb63bbbe01eeed093cb22bb8f5acdc3";
+    const SHA256: &str = "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9";
+
+    #[tokio::test]
+    async fn test_checksum_reader() {
+        let mut reader = ChecksumReader::new(
+            Cursor::new(b"hello world".to_vec()),
+            &image(MD5, "sha256", SHA256),
+        );
+        let mut data = Vec::new();
+        let _ = reader.read_to_end(&mut data).await.unwrap();
+        assert_eq!(data, b"hello world");
+    }
+
+    #[tokio::test]
+    async fn test_checksum_reader_md5_mismatch() {
+        let mut reader = ChecksumReader::new(
+            Cursor::new(b"hello world!".to_vec()),
+            &image(MD5, "unknown", "abcd"),
+        );
+        let mut data = Vec::new();
+        let err = reader.read_to_end(&mut data).await.unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[tokio::test]
+    async fn test_checksum_reader_hash_mismatch() {
+        let mut reader = ChecksumReader::new(
+            Cursor::new(b"hello world".to_vec()),
+            &image(MD5, "sha256", MD5),
+        );
+        let mut data = Vec::new();
+        let err = reader.read_to_end(&mut data).await.unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+}
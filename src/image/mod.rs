@@ -18,7 +18,7 @@ mod api;
 mod images;
 mod protocol;
 
-pub use self::images::{Image, ImageQuery};
+pub use self::images::{Image, ImageCreationWaiter, ImageQuery, NewImage};
 pub use self::protocol::{
     ImageContainerFormat, ImageDiskFormat, ImageSortKey, ImageStatus, ImageVisibility,
 };
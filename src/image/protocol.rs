@@ -17,8 +17,11 @@
 #![allow(non_snake_case)]
 #![allow(missing_docs)]
 
+use std::collections::HashMap;
+
 use chrono::{DateTime, FixedOffset};
-use serde::Deserialize;
+use osauth::common::empty_as_default;
+use serde::{Deserialize, Serialize};
 
 protocol_enum! {
     #[doc = "Possible image statuses."]
@@ -108,8 +111,13 @@ pub struct Image {
     pub min_disk: u32,
     #[serde(default)]
     pub min_ram: u32,
+    #[serde(deserialize_with = "empty_as_default", default)]
     pub name: String,
     #[serde(default)]
+    pub os_hash_algo: Option<String>,
+    #[serde(default)]
+    pub os_hash_value: Option<String>,
+    #[serde(default)]
     pub size: Option<u64>,
     pub status: ImageStatus,
     pub updated_at: DateTime<FixedOffset>,
@@ -118,8 +126,65 @@ pub struct Image {
     pub visibility: ImageVisibility,
 }
 
+/// A request to create an image.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ImageCreate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container_format: Option<ImageContainerFormat>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disk_format: Option<ImageDiskFormat>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_disk: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_ram: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(flatten)]
+    pub properties: HashMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<ImageVisibility>,
+}
+
 /// A list of images.
 #[derive(Debug, Clone, Deserialize)]
 pub struct ImagesRoot {
     pub images: Vec<Image>,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_unknown_enum_values() {
+        assert_unknown_protocol_enums!(
+            ImageContainerFormat,
+            ImageDiskFormat,
+            ImageSortKey,
+            ImageStatus,
+            ImageVisibility
+        );
+    }
+
+    #[test]
+    fn test_image_create_properties() {
+        let mut request = ImageCreate {
+            container_format: Some(ImageContainerFormat::Bare),
+            disk_format: Some(ImageDiskFormat::QCOW2),
+            name: Some("cirros".into()),
+            ..Default::default()
+        };
+        let _ = request
+            .properties
+            .insert("hw_disk_bus".into(), "scsi".into());
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "container_format": "bare",
+                "disk_format": "qcow2",
+                "hw_disk_bus": "scsi",
+                "name": "cirros"
+            })
+        );
+    }
+}
@@ -164,6 +164,14 @@ macro_rules! transparent_property {
         }
     );
 
+    ($(#[$attr:meta])* $name:ident: clone $type:ty) => (
+        $(#[$attr])*
+        #[inline]
+        pub fn $name(&self) -> $type {
+            self.inner.$name.clone()
+        }
+    );
+
     ($(#[$attr:meta])* $name:ident: $type:ty) => (
         $(#[$attr])*
         #[inline]
@@ -421,6 +429,7 @@ macro_rules! update_field {
 
     ($(#[$attr:meta])* $set_func:ident, $with_func:ident -> $name:ident) => (
         $(#[$attr])*
+        #[allow(unused_results)]
         pub fn $set_func<S: Into<String>>(&mut self, value: S)  {
             self.inner.$name = value.into();
             self.dirty.insert(stringify!($name));
@@ -544,6 +553,8 @@ macro_rules! protocol_enum {
         #[non_exhaustive]
         pub enum $name {
             $($(#[$iattr])* $item),+,
+            #[doc = "A value unknown to this library."]
+            Unknown($carrier),
         }
 
         impl<'de> ::serde::de::Deserialize<'de> for $name {
@@ -551,24 +562,17 @@ macro_rules! protocol_enum {
                     where D: ::serde::de::Deserializer<'de> {
                 let value: $carrier = ::serde::de::Deserialize::deserialize(
                     deserializer)?;
-                match value {
-                    $($val => Ok($name::$item)),+,
-                    other => {
-                        use ::serde::de::Error;
-                        let err = format!("Unexpected {}: {}",
-                                          stringify!($name), other);
-                        Err(D::Error::custom(err))
-                    }
-                }
+                Ok(match value {
+                    $($val => $name::$item),+,
+                    other => $name::Unknown(other),
+                })
             }
         }
 
         impl ::serde::ser::Serialize for $name {
             fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
                     where S: ::serde::ser::Serializer {
-                match self {
-                    $($name::$item => $val),+,
-                }.serialize(serializer)
+                <$carrier>::from(*self).serialize(serializer)
             }
         }
 
@@ -576,6 +580,7 @@ macro_rules! protocol_enum {
             fn from(value: $name) -> $carrier {
                 match value {
                     $($name::$item => $val),+,
+                    $name::Unknown(other) => other,
                 }
             }
         }
@@ -585,16 +590,19 @@ macro_rules! protocol_enum {
         $($(#[$iattr:meta])* $item:ident = $val:expr),+
     }} => (
         $(#[$attr])*
-        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[derive(Debug, Clone, PartialEq, Eq)]
         #[non_exhaustive]
         pub enum $name {
             $($(#[$iattr])* $item),+,
+            #[doc = "A value unknown to this library."]
+            Unknown(String),
         }
 
         impl $name {
-            fn as_ref(&self) -> &'static str {
-                match *self {
+            fn as_ref(&self) -> &str {
+                match self {
                     $($name::$item => $val),+,
+                    $name::Unknown(other) => other,
                 }
             }
         }
@@ -602,15 +610,11 @@ macro_rules! protocol_enum {
         impl<'de> ::serde::de::Deserialize<'de> for $name {
             fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
                     where D: ::serde::de::Deserializer<'de> {
-                match String::deserialize(deserializer)?.as_ref() {
-                    $($val => Ok($name::$item)),+,
-                    other => {
-                        use ::serde::de::Error;
-                        let err = format!("Unexpected {}: {}",
-                                          stringify!($name), other);
-                        Err(D::Error::custom(err))
-                    }
-                }
+                let value = String::deserialize(deserializer)?;
+                Ok(match value.as_str() {
+                    $($val => $name::$item),+,
+                    _ => $name::Unknown(value),
+                })
             }
         }
 
@@ -629,17 +633,31 @@ macro_rules! protocol_enum {
 
         impl From<$name> for String {
             fn from(value: $name) -> String {
-                String::from(value.as_ref())
+                match value {
+                    $name::Unknown(other) => other,
+                    known => String::from(known.as_ref()),
+                }
             }
         }
     );
 }
 
+#[cfg(test)]
+macro_rules! assert_unknown_protocol_enums {
+    ($($name:ident),+) => {$(
+        let value: $name = serde_json::from_value(serde_json::json!("x-unknown")).unwrap();
+        assert_eq!(value, $name::Unknown(String::from("x-unknown")));
+        assert_eq!(value.to_string(), "x-unknown");
+        assert_eq!(serde_json::to_value(&value).unwrap(), serde_json::json!("x-unknown"));
+        assert_eq!(String::from(value), "x-unknown");
+    )+};
+}
+
 /// Reimports of authentication bits from `osauth`.
 ///
 /// See [osauth documentation](https://docs.rs/osauth/) for details.
 pub mod auth {
-    pub use osauth::identity::{Password, Scope, Token};
+    pub use osauth::identity::{ApplicationCredential, Password, Scope, Token};
     pub use osauth::{AuthType, NoAuth};
 }
 #[cfg(feature = "block-storage")]
@@ -648,6 +666,8 @@ mod cloud;
 pub mod common;
 #[cfg(feature = "compute")]
 pub mod compute;
+#[cfg(feature = "identity")]
+pub mod identity;
 #[cfg(feature = "image")]
 pub mod image;
 #[cfg(feature = "network")]
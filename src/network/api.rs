@@ -162,6 +162,42 @@ pub async fn create_router(session: &Session, request: Router) -> Result<Router>
     Ok(root.router)
 }
 
+/// Create a security group.
+pub async fn create_security_group(
+    session: &Session,
+    request: SecurityGroup,
+) -> Result<SecurityGroup> {
+    debug!("Creating a new security group with {:?}", request);
+    let body = SecurityGroupRoot {
+        security_group: request,
+    };
+    let root: SecurityGroupRoot = session
+        .post(NETWORK, &["security-groups"])
+        .json(&body)
+        .fetch()
+        .await?;
+    debug!("Created security group {:?}", root.security_group);
+    Ok(root.security_group)
+}
+
+/// Create a security group rule.
+pub async fn create_security_group_rule(
+    session: &Session,
+    request: SecurityGroupRule,
+) -> Result<SecurityGroupRule> {
+    debug!("Creating a new security group rule with {:?}", request);
+    let body = SecurityGroupRuleRoot {
+        security_group_rule: request,
+    };
+    let root: SecurityGroupRuleRoot = session
+        .post(NETWORK, &["security-group-rules"])
+        .json(&body)
+        .fetch()
+        .await?;
+    debug!("Created security group rule {:?}", root.security_group_rule);
+    Ok(root.security_group_rule)
+}
+
 /// Create a subnet.
 pub async fn create_subnet(session: &Session, request: Subnet) -> Result<Subnet> {
     debug!("Creating a new subnet with {:?}", request);
@@ -219,6 +255,28 @@ pub async fn delete_router<S: AsRef<str>>(session: &Session, id: S) -> Result<()
     Ok(())
 }
 
+/// Delete a security group.
+pub async fn delete_security_group<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
+    debug!("Deleting security group {}", id.as_ref());
+    let _ = session
+        .delete(NETWORK, &["security-groups", id.as_ref()])
+        .send()
+        .await?;
+    debug!("Security group {} was deleted", id.as_ref());
+    Ok(())
+}
+
+/// Delete a security group rule.
+pub async fn delete_security_group_rule<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
+    debug!("Deleting security group rule {}", id.as_ref());
+    let _ = session
+        .delete(NETWORK, &["security-group-rules", id.as_ref()])
+        .send()
+        .await?;
+    debug!("Security group rule {} was deleted", id.as_ref());
+    Ok(())
+}
+
 /// Delete a subnet.
 pub async fn delete_subnet<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
     debug!("Deleting subnet {}", id.as_ref());
@@ -351,6 +409,67 @@ pub async fn get_router_by_name<S: AsRef<str>>(session: &Session, name: S) -> Re
     Ok(result)
 }
 
+/// Get a security group.
+pub async fn get_security_group<S: AsRef<str>>(
+    session: &Session,
+    id_or_name: S,
+) -> Result<SecurityGroup> {
+    let s = id_or_name.as_ref();
+    match get_security_group_by_id(session, s).await {
+        Ok(value) => Ok(value),
+        Err(err) if err.kind() == ErrorKind::ResourceNotFound => {
+            get_security_group_by_name(session, s).await
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Get a security group by its ID.
+pub async fn get_security_group_by_id<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+) -> Result<SecurityGroup> {
+    trace!("Get security group by ID {}", id.as_ref());
+    let root: SecurityGroupRoot = session
+        .get_json(NETWORK, &["security-groups", id.as_ref()])
+        .await?;
+    trace!("Received {:?}", root.security_group);
+    Ok(root.security_group)
+}
+
+/// Get a security group by its name.
+pub async fn get_security_group_by_name<S: AsRef<str>>(
+    session: &Session,
+    name: S,
+) -> Result<SecurityGroup> {
+    trace!("Get security group by name {}", name.as_ref());
+    let root: SecurityGroupsRoot = session
+        .get(NETWORK, &["security-groups"])
+        .query(&[("name", name.as_ref())])
+        .fetch()
+        .await?;
+    let result = utils::one(
+        root.security_groups,
+        "Security group with given name or ID not found",
+        "Too many security groups found with given name",
+    )?;
+    trace!("Received {:?}", result);
+    Ok(result)
+}
+
+/// Get a security group rule.
+pub async fn get_security_group_rule<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+) -> Result<SecurityGroupRule> {
+    trace!("Get security group rule by ID {}", id.as_ref());
+    let root: SecurityGroupRuleRoot = session
+        .get_json(NETWORK, &["security-group-rules", id.as_ref()])
+        .await?;
+    trace!("Received {:?}", root.security_group_rule);
+    Ok(root.security_group_rule)
+}
+
 /// Get a subnet.
 pub async fn get_subnet<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<Subnet> {
     let s = id_or_name.as_ref();
@@ -448,6 +567,21 @@ pub async fn list_routers<Q: Serialize + Sync + Debug>(
     Ok(root.routers)
 }
 
+/// List security groups.
+pub async fn list_security_groups<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<Vec<SecurityGroup>> {
+    trace!("Listing security groups with {:?}", query);
+    let root: SecurityGroupsRoot = session
+        .get(NETWORK, &["security-groups"])
+        .query(query)
+        .fetch()
+        .await?;
+    trace!("Received security groups: {:?}", root.security_groups);
+    Ok(root.security_groups)
+}
+
 /// List subnets.
 pub async fn list_subnets<Q: Serialize + Sync + Debug>(
     session: &Session,
@@ -581,6 +715,25 @@ pub async fn update_router<S: AsRef<str>>(
     Ok(root.router)
 }
 
+/// Update a security group.
+pub async fn update_security_group<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    update: SecurityGroupUpdate,
+) -> Result<SecurityGroup> {
+    debug!("Updating security group {} with {:?}", id.as_ref(), update);
+    let body = SecurityGroupUpdateRoot {
+        security_group: update,
+    };
+    let root: SecurityGroupRoot = session
+        .put(NETWORK, &["security-groups", id.as_ref()])
+        .json(&body)
+        .fetch()
+        .await?;
+    debug!("Updated security group {:?}", root.security_group);
+    Ok(root.security_group)
+}
+
 /// Update a subnet.
 pub async fn update_subnet<S: AsRef<str>>(
     session: &Session,
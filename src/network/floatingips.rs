@@ -165,7 +165,7 @@ impl FloatingIp {
 
     transparent_property! {
         #[doc = "Status of the floating IP."]
-        status: protocol::FloatingIpStatus
+        status: clone protocol::FloatingIpStatus
     }
 
     transparent_property! {
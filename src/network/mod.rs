@@ -20,16 +20,24 @@ mod networks;
 mod ports;
 mod protocol;
 mod routers;
+mod security_groups;
 mod subnets;
 
 pub use self::floatingips::{FloatingIp, FloatingIpQuery, NewFloatingIp};
 pub use self::networks::{Network, NetworkQuery, NewNetwork};
-pub use self::ports::{NewPort, Port, PortIpAddress, PortIpRequest, PortQuery};
+pub use self::ports::{
+    NewPort, Port, PortAttachmentWaiter, PortDetachmentWaiter, PortIpAddress, PortIpRequest,
+    PortQuery,
+};
 pub use self::protocol::{
-    AllocationPool, AllowedAddressPair, ConntrackHelper, ExternalGateway, FloatingIpSortKey,
-    FloatingIpStatus, Helper, HostRoute, IpVersion, Ipv6Mode, MacAddress, NetworkProtocol,
-    NetworkSortKey, NetworkStatus, PortExtraDhcpOption, PortForwarding, PortSortKey, RouterSortKey,
-    RouterStatus, SubnetSortKey,
+    AllocationPool, AllowedAddressPair, ConntrackHelper, EtherType, ExternalGateway,
+    FloatingIpSortKey, FloatingIpStatus, Helper, HostRoute, IpVersion, Ipv6Mode, MacAddress,
+    NetworkProtocol, NetworkSortKey, NetworkStatus, PortExtraDhcpOption, PortForwarding,
+    PortSortKey, RouterSortKey, RouterStatus, SecurityGroupRuleDirection,
+    SecurityGroupRuleProtocol, SecurityGroupSortKey, SubnetSortKey,
 };
 pub use self::routers::{NewRouter, Router, RouterQuery};
+pub use self::security_groups::{
+    NewSecurityGroup, NewSecurityGroupRule, SecurityGroup, SecurityGroupQuery, SecurityGroupRule,
+};
 pub use self::subnets::{NewSubnet, Subnet, SubnetQuery};
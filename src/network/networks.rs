@@ -180,7 +180,7 @@ impl Network {
 
     transparent_property! {
         #[doc = "Status of the network."]
-        status: protocol::NetworkStatus
+        status: clone protocol::NetworkStatus
     }
 
     // TODO(dtantsur): subnets
@@ -28,8 +28,8 @@ use super::super::common::{
 };
 use super::super::session::Session;
 use super::super::utils::Query;
-use super::super::waiter::DeletionWaiter;
-use super::super::{Result, Sort};
+use super::super::waiter::{DeletionWaiter, Waiter};
+use super::super::{Error, ErrorKind, Result, Sort};
 use super::{api, protocol, MacAddress, Network, Subnet};
 
 /// A query to port list.
@@ -71,6 +71,23 @@ pub enum PortIpRequest {
     IpFromSubnet(net::IpAddr, SubnetRef),
 }
 
+/// Waiter for a port to be attached to a server.
+#[derive(Debug)]
+pub struct PortAttachmentWaiter {
+    port: Port,
+    device_id: String,
+}
+
+/// Waiter for a port to be detached from a server.
+///
+/// Ports created by the Compute service for a network are deleted on detaching,
+/// in this case the waiter returns `None`.
+#[derive(Debug)]
+pub struct PortDetachmentWaiter {
+    port: Port,
+    device_id: String,
+}
+
 /// A request to create a port
 #[derive(Clone, Debug)]
 pub struct NewPort {
@@ -243,9 +260,14 @@ impl Port {
         network_id: ref String
     }
 
+    transparent_property! {
+        #[doc = "Security groups applied to the port."]
+        security_groups: ref Vec<SecurityGroupRef>
+    }
+
     transparent_property! {
         #[doc = "Port status."]
-        status: protocol::NetworkStatus
+        status: clone protocol::NetworkStatus
     }
 
     transparent_property! {
@@ -298,6 +320,117 @@ impl Refresh for Port {
     }
 }
 
+impl PortAttachmentWaiter {
+    #[cfg(feature = "compute")]
+    pub(crate) fn new(port: Port, device_id: String) -> PortAttachmentWaiter {
+        PortAttachmentWaiter { port, device_id }
+    }
+
+    /// Current state of the port.
+    pub fn current_state(&self) -> &Port {
+        &self.port
+    }
+}
+
+#[async_trait]
+impl Waiter<Port, Error> for PortAttachmentWaiter {
+    fn default_wait_timeout(&self) -> Option<Duration> {
+        Some(Duration::new(300, 0))
+    }
+
+    fn default_delay(&self) -> Duration {
+        Duration::new(1, 0)
+    }
+
+    fn timeout_error(&self) -> Error {
+        Error::new(
+            ErrorKind::OperationTimedOut,
+            format!(
+                "Timeout waiting for port {} to be attached to {}",
+                self.port.id(),
+                self.device_id
+            ),
+        )
+    }
+
+    async fn poll(&mut self) -> Result<Option<Port>> {
+        self.port.refresh().await?;
+        if self.port.device_id().as_ref() == Some(&self.device_id) {
+            debug!("Port {} is attached to {}", self.port.id(), self.device_id);
+            Ok(Some(self.port.clone()))
+        } else {
+            trace!(
+                "Still waiting for port {} to be attached to {}, current device is {:?}",
+                self.port.id(),
+                self.device_id,
+                self.port.device_id()
+            );
+            Ok(None)
+        }
+    }
+}
+
+impl PortDetachmentWaiter {
+    #[cfg(feature = "compute")]
+    pub(crate) fn new(port: Port, device_id: String) -> PortDetachmentWaiter {
+        PortDetachmentWaiter { port, device_id }
+    }
+
+    /// Current state of the port.
+    pub fn current_state(&self) -> &Port {
+        &self.port
+    }
+}
+
+#[async_trait]
+impl Waiter<Option<Port>, Error> for PortDetachmentWaiter {
+    fn default_wait_timeout(&self) -> Option<Duration> {
+        Some(Duration::new(300, 0))
+    }
+
+    fn default_delay(&self) -> Duration {
+        Duration::new(1, 0)
+    }
+
+    fn timeout_error(&self) -> Error {
+        Error::new(
+            ErrorKind::OperationTimedOut,
+            format!(
+                "Timeout waiting for port {} to be detached from {}",
+                self.port.id(),
+                self.device_id
+            ),
+        )
+    }
+
+    async fn poll(&mut self) -> Result<Option<Option<Port>>> {
+        match self.port.refresh().await {
+            Ok(()) => {}
+            Err(err) if err.kind() == ErrorKind::ResourceNotFound => {
+                debug!("Port {} was deleted on detaching", self.port.id());
+                return Ok(Some(None));
+            }
+            Err(err) => return Err(err),
+        }
+
+        if self.port.device_id().as_ref() == Some(&self.device_id) {
+            trace!(
+                "Still waiting for port {} to be detached from {}",
+                self.port.id(),
+                self.device_id
+            );
+            Ok(None)
+        } else {
+            debug!(
+                "Port {} is detached from {}",
+                self.port.id(),
+                self.device_id
+            );
+            Ok(Some(Some(self.port.clone())))
+        }
+    }
+}
+
 impl PortIpAddress {
     /// Get subnet to which this IP address belongs.
     pub async fn subnet(&self) -> Result<Subnet> {
@@ -492,6 +625,11 @@ impl NewPort {
     /// Request creation of the port.
     pub async fn create(mut self) -> Result<Port> {
         self.inner.network_id = self.network.into_verified(&self.session).await?.into();
+        let mut security_groups = Vec::with_capacity(self.inner.security_groups.len());
+        for sg in self.inner.security_groups {
+            security_groups.push(sg.into_verified(&self.session).await?);
+        }
+        self.inner.security_groups = security_groups;
         for request in self.fixed_ips {
             self.inner.fixed_ips.push(match request {
                 PortIpRequest::IpAddress(ip) => protocol::FixedIp {
@@ -152,6 +152,60 @@ protocol_enum! {
     }
 }
 
+protocol_enum! {
+    #[doc = "Ethernet type of a security group rule."]
+    enum EtherType {
+        Ipv4 = "IPv4",
+        Ipv6 = "IPv6"
+    }
+}
+
+protocol_enum! {
+    #[doc = "Direction in which a security group rule is applied."]
+    enum SecurityGroupRuleDirection {
+        Egress = "egress",
+        Ingress = "ingress"
+    }
+}
+
+protocol_enum! {
+    #[doc = "IP protocol matched by a security group rule."]
+    enum SecurityGroupRuleProtocol {
+        Ah = "ah",
+        Dccp = "dccp",
+        Egp = "egp",
+        Esp = "esp",
+        Gre = "gre",
+        Icmp = "icmp",
+        Icmpv6 = "icmpv6",
+        Igmp = "igmp",
+        Ipip = "ipip",
+        Ipv6Encap = "ipv6-encap",
+        Ipv6Frag = "ipv6-frag",
+        Ipv6Icmp = "ipv6-icmp",
+        Ipv6Nonxt = "ipv6-nonxt",
+        Ipv6Opts = "ipv6-opts",
+        Ipv6Route = "ipv6-route",
+        Ospf = "ospf",
+        Pgm = "pgm",
+        Rsvp = "rsvp",
+        Sctp = "sctp",
+        TCP = "tcp",
+        UDP = "udp",
+        Udplite = "udplite",
+        Vrrp = "vrrp"
+    }
+}
+
+protocol_enum! {
+    #[doc = "Available sort keys."]
+    enum SecurityGroupSortKey {
+        Id = "id",
+        Name = "name",
+        ProjectId = "project_id"
+    }
+}
+
 /// An network.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Network {
@@ -513,7 +567,7 @@ protocol_enum! {
 /// ConntrackHelper object.
 /// See [here](https://home.regit.org/netfilter-en/secure-use-of-helpers/) for in-depth info about
 /// conntrack helpers.
-#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct ConntrackHelper {
     /// Conntrack Helper
     pub helper: Helper,
@@ -905,10 +959,131 @@ pub struct FloatingIpsRoot {
     pub floatingips: Vec<FloatingIp>,
 }
 
+/// A security group rule.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SecurityGroupRule {
+    #[serde(default, skip_serializing)]
+    pub created_at: Option<DateTime<FixedOffset>>,
+    #[serde(
+        deserialize_with = "empty_as_default",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub description: Option<String>,
+    pub direction: SecurityGroupRuleDirection,
+    pub ethertype: EtherType,
+    #[serde(skip_serializing)]
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port_range_max: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port_range_min: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<SecurityGroupRuleProtocol>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_group_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_ip_prefix: Option<ipnet::IpNet>,
+    pub security_group_id: String,
+    #[serde(default, skip_serializing)]
+    pub updated_at: Option<DateTime<FixedOffset>>,
+}
+
+/// A security group rule.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SecurityGroupRuleRoot {
+    pub security_group_rule: SecurityGroupRule,
+}
+
+/// A security group.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SecurityGroup {
+    #[serde(default, skip_serializing)]
+    pub created_at: Option<DateTime<FixedOffset>>,
+    #[serde(
+        deserialize_with = "empty_as_default",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub description: Option<String>,
+    #[serde(skip_serializing)]
+    pub id: String,
+    #[serde(
+        deserialize_with = "empty_as_default",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    #[serde(default, skip_serializing)]
+    pub security_group_rules: Vec<SecurityGroupRule>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stateful: Option<bool>,
+    #[serde(default, skip_serializing)]
+    pub updated_at: Option<DateTime<FixedOffset>>,
+}
+
+/// A security group.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SecurityGroupUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stateful: Option<bool>,
+}
+
+/// A security group.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SecurityGroupRoot {
+    pub security_group: SecurityGroup,
+}
+
+/// A security group.
+#[derive(Debug, Clone, Serialize)]
+pub struct SecurityGroupUpdateRoot {
+    pub security_group: SecurityGroupUpdate,
+}
+
+/// A list of security groups.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SecurityGroupsRoot {
+    pub security_groups: Vec<SecurityGroup>,
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn test_unknown_enum_values() {
+        assert_unknown_protocol_enums!(
+            EtherType,
+            FloatingIpSortKey,
+            FloatingIpStatus,
+            Helper,
+            Ipv6Mode,
+            NetworkProtocol,
+            NetworkSortKey,
+            NetworkStatus,
+            PortSortKey,
+            RouterSortKey,
+            RouterStatus,
+            SecurityGroupRuleDirection,
+            SecurityGroupRuleProtocol,
+            SecurityGroupSortKey,
+            SubnetSortKey
+        );
+
+        let version: IpVersion = serde_json::from_value(serde_json::json!(5)).unwrap();
+        assert_eq!(version, IpVersion::Unknown(5));
+        assert_eq!(serde_json::to_value(version).unwrap(), serde_json::json!(5));
+    }
+
     #[test]
     fn test_parse_macaddr() {
         // Test that a JSON deserialisation of MAC addresses work
@@ -938,4 +1113,66 @@ mod test {
                 .expect("Cannot parse this JSON");
         assert_eq!(a.mac_address, None);
     }
+
+    #[test]
+    fn test_parse_security_group() {
+        let sg: SecurityGroup = serde_json::from_value(serde_json::json!({
+            "id": "85cc3048-abc3-43cc-89b3-377341426ac5",
+            "name": "default",
+            "description": "",
+            "project_id": "e4f50856753b4dc6afee5fa6b9b6c550",
+            "stateful": true,
+            "security_group_rules": [
+                {
+                    "direction": "egress",
+                    "ethertype": "IPv6",
+                    "id": "3c0e45ff-adaf-4124-b083-bf390e5482ff",
+                    "port_range_max": null,
+                    "port_range_min": null,
+                    "protocol": null,
+                    "remote_group_id": null,
+                    "remote_ip_prefix": null,
+                    "security_group_id": "85cc3048-abc3-43cc-89b3-377341426ac5"
+                },
+                {
+                    "direction": "ingress",
+                    "ethertype": "IPv4",
+                    "id": "93aa42e5-80db-4581-9391-3a608bd0e448",
+                    "port_range_max": 22,
+                    "port_range_min": 22,
+                    "protocol": "tcp",
+                    "remote_group_id": null,
+                    "remote_ip_prefix": "10.0.0.0/8",
+                    "security_group_id": "85cc3048-abc3-43cc-89b3-377341426ac5"
+                }
+            ]
+        }))
+        .expect("Could not parse this JSON");
+        assert_eq!(sg.name.as_deref(), Some("default"));
+        assert_eq!(sg.description, None);
+        assert_eq!(sg.security_group_rules.len(), 2);
+        let rule = &sg.security_group_rules[1];
+        assert_eq!(rule.direction, SecurityGroupRuleDirection::Ingress);
+        assert_eq!(rule.ethertype, EtherType::Ipv4);
+        assert_eq!(rule.protocol, Some(SecurityGroupRuleProtocol::TCP));
+        assert_eq!(rule.port_range_min, Some(22));
+        assert_eq!(
+            rule.remote_ip_prefix,
+            Some("10.0.0.0/8".parse().expect("Invalid CIDR"))
+        );
+
+        // Read-only fields and unset values are not sent when creating
+        assert_eq!(
+            serde_json::to_value(rule).expect("Could not serialize"),
+            serde_json::json!({
+                "direction": "ingress",
+                "ethertype": "IPv4",
+                "port_range_max": 22,
+                "port_range_min": 22,
+                "protocol": "tcp",
+                "remote_ip_prefix": "10.0.0.0/8",
+                "security_group_id": "85cc3048-abc3-43cc-89b3-377341426ac5"
+            })
+        );
+    }
 }
@@ -196,7 +196,7 @@ impl Router {
 
     transparent_property! {
         #[doc = "Status of the router."]
-        status: protocol::RouterStatus
+        status: clone protocol::RouterStatus
     }
 
     transparent_property! {
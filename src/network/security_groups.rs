@@ -0,0 +1,582 @@
+// Copyright 2026 The rust-openstack Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Security groups and security group rules.
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset};
+use futures::stream::{Stream, TryStreamExt};
+
+use super::super::common::{Refresh, ResourceIterator, ResourceQuery, SecurityGroupRef};
+use super::super::session::Session;
+use super::super::utils::Query;
+use super::super::waiter::DeletionWaiter;
+use super::super::{Error, ErrorKind, Result, Sort};
+use super::{api, protocol};
+
+/// A query to security group list.
+#[derive(Clone, Debug)]
+pub struct SecurityGroupQuery {
+    session: Session,
+    query: Query,
+    can_paginate: bool,
+}
+
+/// Structure representing a single security group.
+#[derive(Clone, Debug)]
+pub struct SecurityGroup {
+    session: Session,
+    inner: protocol::SecurityGroup,
+    rules: Vec<SecurityGroupRule>,
+    dirty: HashSet<&'static str>,
+}
+
+/// Structure representing a single security group rule.
+#[derive(Clone, Debug)]
+pub struct SecurityGroupRule {
+    session: Session,
+    inner: protocol::SecurityGroupRule,
+}
+
+/// A request to create a security group.
+#[derive(Clone, Debug)]
+pub struct NewSecurityGroup {
+    session: Session,
+    inner: protocol::SecurityGroup,
+}
+
+/// A request to create a security group rule.
+#[derive(Clone, Debug)]
+pub struct NewSecurityGroupRule {
+    session: Session,
+    inner: protocol::SecurityGroupRule,
+    security_group: SecurityGroupRef,
+    remote_group: Option<SecurityGroupRef>,
+}
+
+fn convert_rules(session: &Session, inner: &protocol::SecurityGroup) -> Vec<SecurityGroupRule> {
+    inner
+        .security_group_rules
+        .iter()
+        .cloned()
+        .map(|rule| SecurityGroupRule::new(session.clone(), rule))
+        .collect()
+}
+
+impl SecurityGroup {
+    /// Create a security group object.
+    fn new(session: Session, inner: protocol::SecurityGroup) -> SecurityGroup {
+        let rules = convert_rules(&session, &inner);
+        SecurityGroup {
+            session,
+            inner,
+            rules,
+            dirty: HashSet::new(),
+        }
+    }
+
+    /// Load a SecurityGroup object.
+    pub(crate) async fn load<Id: AsRef<str>>(session: Session, id: Id) -> Result<SecurityGroup> {
+        let inner = api::get_security_group(&session, id).await?;
+        Ok(SecurityGroup::new(session, inner))
+    }
+
+    transparent_property! {
+        #[doc = "Creation data and time (if available)."]
+        created_at: Option<DateTime<FixedOffset>>
+    }
+
+    transparent_property! {
+        #[doc = "Security group description."]
+        description: ref Option<String>
+    }
+
+    update_field! {
+        #[doc = "Update the description."]
+        set_description, with_description -> description: optional String
+    }
+
+    transparent_property! {
+        #[doc = "Unique ID."]
+        id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Security group name."]
+        name: ref Option<String>
+    }
+
+    update_field! {
+        #[doc = "Update the security group name."]
+        set_name, with_name -> name: optional String
+    }
+
+    transparent_property! {
+        #[doc = "ID of the project owning the security group."]
+        project_id: ref Option<String>
+    }
+
+    /// Rules of this security group.
+    pub fn rules(&self) -> &Vec<SecurityGroupRule> {
+        &self.rules
+    }
+
+    transparent_property! {
+        #[doc = "Whether the security group is stateful (if known)."]
+        stateful: Option<bool>
+    }
+
+    update_field! {
+        #[doc = "Update whether the security group is stateful."]
+        set_stateful, with_stateful -> stateful: optional bool
+    }
+
+    transparent_property! {
+        #[doc = "Last update data and time (if available)."]
+        updated_at: Option<DateTime<FixedOffset>>
+    }
+
+    /// Delete the security group.
+    pub async fn delete(self) -> Result<DeletionWaiter<SecurityGroup>> {
+        api::delete_security_group(&self.session, &self.inner.id).await?;
+        Ok(DeletionWaiter::new(
+            self,
+            Duration::new(60, 0),
+            Duration::new(1, 0),
+        ))
+    }
+
+    /// Whether the security group is modified.
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Save the changes to the security group.
+    #[allow(clippy::field_reassign_with_default)]
+    pub async fn save(&mut self) -> Result<()> {
+        let mut update = protocol::SecurityGroupUpdate::default();
+        save_option_fields! {
+            self -> update: description name stateful
+        };
+        let inner = api::update_security_group(&self.session, self.id(), update).await?;
+        self.rules = convert_rules(&self.session, &inner);
+        self.dirty.clear();
+        self.inner = inner;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Refresh for SecurityGroup {
+    /// Refresh the security group.
+    async fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_security_group_by_id(&self.session, &self.inner.id).await?;
+        self.rules = convert_rules(&self.session, &self.inner);
+        self.dirty.clear();
+        Ok(())
+    }
+}
+
+impl SecurityGroupRule {
+    /// Create a security group rule object.
+    fn new(session: Session, inner: protocol::SecurityGroupRule) -> SecurityGroupRule {
+        SecurityGroupRule { session, inner }
+    }
+
+    transparent_property! {
+        #[doc = "Creation data and time (if available)."]
+        created_at: Option<DateTime<FixedOffset>>
+    }
+
+    transparent_property! {
+        #[doc = "Rule description."]
+        description: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "Direction in which the rule is applied."]
+        direction: clone protocol::SecurityGroupRuleDirection
+    }
+
+    transparent_property! {
+        #[doc = "Ethernet type matched by the rule."]
+        ethertype: clone protocol::EtherType
+    }
+
+    transparent_property! {
+        #[doc = "Unique ID."]
+        id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Maximum port matched by the rule (if any)."]
+        port_range_max: Option<u16>
+    }
+
+    transparent_property! {
+        #[doc = "Minimum port matched by the rule (if any)."]
+        port_range_min: Option<u16>
+    }
+
+    transparent_property! {
+        #[doc = "ID of the project owning the rule."]
+        project_id: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "IP protocol matched by the rule (any if not set)."]
+        protocol: clone Option<protocol::SecurityGroupRuleProtocol>
+    }
+
+    transparent_property! {
+        #[doc = "ID of the remote security group matched by the rule (if any)."]
+        remote_group_id: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "Remote CIDR matched by the rule (if any)."]
+        remote_ip_prefix: Option<ipnet::IpNet>
+    }
+
+    /// Get the security group this rule belongs to.
+    pub async fn security_group(&self) -> Result<SecurityGroup> {
+        SecurityGroup::load(self.session.clone(), &self.inner.security_group_id).await
+    }
+
+    transparent_property! {
+        #[doc = "ID of the security group this rule belongs to."]
+        security_group_id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Last update data and time (if available)."]
+        updated_at: Option<DateTime<FixedOffset>>
+    }
+
+    /// Delete the security group rule.
+    pub async fn delete(self) -> Result<DeletionWaiter<SecurityGroupRule>> {
+        api::delete_security_group_rule(&self.session, &self.inner.id).await?;
+        Ok(DeletionWaiter::new(
+            self,
+            Duration::new(60, 0),
+            Duration::new(1, 0),
+        ))
+    }
+}
+
+#[async_trait]
+impl Refresh for SecurityGroupRule {
+    /// Refresh the security group rule.
+    async fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_security_group_rule(&self.session, &self.inner.id).await?;
+        Ok(())
+    }
+}
+
+impl SecurityGroupQuery {
+    pub(crate) fn new(session: Session) -> SecurityGroupQuery {
+        SecurityGroupQuery {
+            session,
+            query: Query::new(),
+            can_paginate: true,
+        }
+    }
+
+    /// Add marker to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_marker<T: Into<String>>(mut self, marker: T) -> Self {
+        self.can_paginate = false;
+        self.query.push_str("marker", marker);
+        self
+    }
+
+    /// Add limit to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.can_paginate = false;
+        self.query.push("limit", limit);
+        self
+    }
+
+    /// Add sorting to the request.
+    pub fn sort_by(mut self, sort: Sort<protocol::SecurityGroupSortKey>) -> Self {
+        let (field, direction) = sort.into();
+        self.query.push_str("sort_key", field);
+        self.query.push("sort_dir", direction);
+        self
+    }
+
+    query_filter! {
+        #[doc = "Filter by description."]
+        set_description, with_description -> description
+    }
+
+    query_filter! {
+        #[doc = "Filter by security group name."]
+        set_name, with_name -> name
+    }
+
+    query_filter! {
+        #[doc = "Filter by project ID."]
+        set_project_id, with_project_id -> project_id
+    }
+
+    /// Convert this query into a stream executing the request.
+    ///
+    /// Returns a `TryStream`, which is a stream with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_stream(self) -> impl Stream<Item = Result<SecurityGroup>> {
+        debug!("Fetching security groups with {:?}", self.query);
+        ResourceIterator::new(self).into_stream()
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_stream().try_collect().await`.
+    pub async fn all(self) -> Result<Vec<SecurityGroup>> {
+        self.into_stream().try_collect().await
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub async fn one(mut self) -> Result<SecurityGroup> {
+        debug!("Fetching one security group with {:?}", self.query);
+        if self.can_paginate {
+            // We need only one result. We fetch maximum two to be able
+            // to check if the query yieled more than one result.
+            self.query.push("limit", 2);
+        }
+
+        ResourceIterator::new(self).one().await
+    }
+}
+
+#[async_trait]
+impl ResourceQuery for SecurityGroupQuery {
+    type Item = SecurityGroup;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    async fn can_paginate(&self) -> Result<bool> {
+        Ok(self.can_paginate)
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.id().clone()
+    }
+
+    async fn fetch_chunk(
+        &self,
+        limit: Option<usize>,
+        marker: Option<String>,
+    ) -> Result<Vec<Self::Item>> {
+        let query = self.query.with_marker_and_limit(limit, marker);
+        Ok(api::list_security_groups(&self.session, &query)
+            .await?
+            .into_iter()
+            .map(|item| SecurityGroup::new(self.session.clone(), item))
+            .collect())
+    }
+}
+
+impl NewSecurityGroup {
+    /// Start creating a security group.
+    pub(crate) fn new(session: Session) -> NewSecurityGroup {
+        NewSecurityGroup {
+            session,
+            inner: protocol::SecurityGroup {
+                created_at: None,
+                description: None,
+                // Dummy value, not used when serializing
+                id: String::new(),
+                name: None,
+                project_id: None,
+                security_group_rules: Vec::new(),
+                stateful: None,
+                updated_at: None,
+            },
+        }
+    }
+
+    /// Request creation of a security group.
+    pub async fn create(self) -> Result<SecurityGroup> {
+        let inner = api::create_security_group(&self.session, self.inner).await?;
+        Ok(SecurityGroup::new(self.session, inner))
+    }
+
+    creation_inner_field! {
+        #[doc = "Set description of the security group."]
+        set_description, with_description -> description: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set a name for the security group."]
+        set_name, with_name -> name: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set a project ID for the security group (admin-only)."]
+        set_project_id, with_project_id -> project_id: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set whether the security group is stateful."]
+        set_stateful, with_stateful -> stateful: optional bool
+    }
+}
+
+impl NewSecurityGroupRule {
+    /// Start creating a security group rule.
+    pub(crate) fn new(
+        session: Session,
+        security_group: SecurityGroupRef,
+        direction: protocol::SecurityGroupRuleDirection,
+    ) -> NewSecurityGroupRule {
+        NewSecurityGroupRule {
+            session,
+            inner: protocol::SecurityGroupRule {
+                created_at: None,
+                description: None,
+                direction,
+                ethertype: protocol::EtherType::Ipv4,
+                // Dummy value, not used when serializing
+                id: String::new(),
+                port_range_max: None,
+                port_range_min: None,
+                project_id: None,
+                protocol: None,
+                remote_group_id: None,
+                remote_ip_prefix: None,
+                // Will be replaced in create()
+                security_group_id: String::new(),
+                updated_at: None,
+            },
+            security_group,
+            remote_group: None,
+        }
+    }
+
+    /// Request creation of a security group rule.
+    ///
+    /// Fails with `InvalidInput` if both a remote group and a remote CIDR
+    /// are requested.
+    pub async fn create(mut self) -> Result<SecurityGroupRule> {
+        if self.remote_group.is_some() && self.inner.remote_ip_prefix.is_some() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Remote group and remote CIDR are mutually exclusive",
+            ));
+        }
+
+        self.inner.security_group_id = self
+            .security_group
+            .into_verified(&self.session)
+            .await?
+            .into();
+        if let Some(remote_group) = self.remote_group {
+            self.inner.remote_group_id =
+                Some(remote_group.into_verified(&self.session).await?.into());
+        }
+
+        let inner = api::create_security_group_rule(&self.session, self.inner).await?;
+        Ok(SecurityGroupRule::new(self.session, inner))
+    }
+
+    creation_inner_field! {
+        #[doc = "Set description of the rule."]
+        set_description, with_description -> description: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set ethernet type of the rule (defaults to IPv4)."]
+        set_ethertype, with_ethertype -> ethertype: protocol::EtherType
+    }
+
+    /// Set the port range matched by the rule.
+    ///
+    /// For ICMP rules these are the ICMP type and code.
+    pub fn set_port_range(&mut self, min: u16, max: u16) {
+        self.inner.port_range_min = Some(min);
+        self.inner.port_range_max = Some(max);
+    }
+
+    /// Set the port range matched by the rule.
+    ///
+    /// For ICMP rules these are the ICMP type and code.
+    #[inline]
+    pub fn with_port_range(mut self, min: u16, max: u16) -> Self {
+        self.set_port_range(min, max);
+        self
+    }
+
+    creation_inner_field! {
+        #[doc = "Set a project ID for the rule (admin-only)."]
+        set_project_id, with_project_id -> project_id: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set IP protocol matched by the rule (any by default)."]
+        set_protocol, with_protocol -> protocol: optional protocol::SecurityGroupRuleProtocol
+    }
+
+    /// Set the remote security group matched by the rule.
+    pub fn set_remote_group<G>(&mut self, remote_group: G)
+    where
+        G: Into<SecurityGroupRef>,
+    {
+        self.remote_group = Some(remote_group.into());
+    }
+
+    /// Set the remote security group matched by the rule.
+    #[inline]
+    pub fn with_remote_group<G>(mut self, remote_group: G) -> Self
+    where
+        G: Into<SecurityGroupRef>,
+    {
+        self.set_remote_group(remote_group);
+        self
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the remote CIDR matched by the rule."]
+        set_remote_ip_prefix, with_remote_ip_prefix -> remote_ip_prefix: optional ipnet::IpNet
+    }
+}
+
+impl From<SecurityGroup> for SecurityGroupRef {
+    fn from(value: SecurityGroup) -> SecurityGroupRef {
+        SecurityGroupRef::new_verified(value.inner.id)
+    }
+}
+
+#[cfg(feature = "network")]
+impl SecurityGroupRef {
+    /// Verify this reference and convert to an ID, if possible.
+    pub(crate) async fn into_verified(self, session: &Session) -> Result<SecurityGroupRef> {
+        Ok(if self.verified {
+            self
+        } else {
+            SecurityGroupRef::new_verified(api::get_security_group(session, &self.value).await?.id)
+        })
+    }
+}
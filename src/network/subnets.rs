@@ -155,12 +155,12 @@ impl Subnet {
 
     transparent_property! {
         #[doc = "Address assignment mode for IPv6."]
-        ipv6_address_mode: Option<protocol::Ipv6Mode>
+        ipv6_address_mode: clone Option<protocol::Ipv6Mode>
     }
 
     transparent_property! {
         #[doc = "Router advertisement mode for IPv6."]
-        ipv6_router_advertisement_mode: Option<protocol::Ipv6Mode>
+        ipv6_router_advertisement_mode: clone Option<protocol::Ipv6Mode>
     }
 
     transparent_property! {
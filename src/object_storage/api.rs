@@ -21,11 +21,11 @@ use osauth::services::OBJECT_STORAGE;
 use reqwest::{Method, StatusCode};
 
 use super::super::session::Session;
+use super::super::utils::io::{async_read_to_body, body_to_async_read};
 use super::super::utils::Query;
 use super::super::Result;
 use super::objects::ObjectHeaders;
 use super::protocol::*;
-use super::utils::{async_read_to_body, body_to_async_read};
 
 /// Create a new container.
 ///
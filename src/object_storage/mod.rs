@@ -18,7 +18,6 @@ mod api;
 mod containers;
 mod objects;
 mod protocol;
-mod utils;
 
 pub use containers::{Container, ContainerQuery};
 pub use objects::{NewObject, Object, ObjectQuery};
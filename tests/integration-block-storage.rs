@@ -68,3 +68,52 @@ async fn test_volume_create_with_fields() {
 
     volume.delete().await.expect("Could not delete volume");
 }
+
+#[tokio::test]
+async fn test_volume_update_extend() {
+    use openstack::block_storage::VolumeStatus;
+    use openstack::waiter::Waiter;
+    use openstack::Refresh;
+
+    let os = set_up().await;
+
+    let mut volume = os
+        .new_volume(1u64)
+        .with_name("test_volume_update".to_string())
+        .create()
+        .await
+        .expect("Could not create volume");
+
+    for attempt in 0..30 {
+        if volume.status() == VolumeStatus::Available {
+            break;
+        }
+        assert!(attempt < 29, "Volume did not become available");
+        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+        volume.refresh().await.expect("Could not refresh volume");
+    }
+    volume.set_description("updated description");
+    let _ = volume
+        .metadata_mut()
+        .insert("purpose".to_string(), "testing".to_string());
+    assert!(volume.is_dirty());
+    volume.save().await.expect("Could not update volume");
+    assert!(!volume.is_dirty());
+    assert_eq!(
+        *volume.description(),
+        Some("updated description".to_string())
+    );
+    assert_eq!(volume.metadata().get("purpose").unwrap(), "testing");
+
+    let volume = volume
+        .extend(2)
+        .await
+        .expect("Could not request volume extension")
+        .wait()
+        .await
+        .expect("Volume was not extended");
+    assert_eq!(volume.size(), 2u64);
+    assert_eq!(volume.status(), VolumeStatus::Available);
+
+    volume.delete().await.expect("Could not delete volume");
+}
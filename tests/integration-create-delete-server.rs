@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::env;
 use std::sync::Once;
 use std::time;
@@ -104,6 +105,55 @@ async fn validate_server(os: &openstack::Cloud, server: &mut openstack::compute:
     assert!(flavor.root_size > 0);
 }
 
+async fn update_server(os: &openstack::Cloud, server: &mut openstack::compute::Server) {
+    server
+        .set_metadata_item("extra", "value")
+        .await
+        .expect("Cannot set a metadata item");
+    server
+        .delete_metadata_item("meta")
+        .await
+        .expect("Cannot delete a metadata item");
+
+    let _ = server
+        .metadata_mut()
+        .insert("saved".to_string(), "yes".to_string());
+    server.set_description("rust-openstack description");
+    server.save().await.expect("Cannot save the server");
+    assert!(!server.is_dirty());
+    assert_eq!(
+        server.description().as_ref().map(String::as_str),
+        Some("rust-openstack description")
+    );
+    assert_eq!(server.metadata().get("saved"), Some(&"yes".to_string()));
+    assert_eq!(server.metadata().get("extra"), Some(&"value".to_string()));
+    assert!(server.metadata().get("meta").is_none());
+
+    server
+        .replace_tags(vec!["rust-openstack".to_string(), "other".to_string()])
+        .await
+        .expect("Cannot replace tags");
+    server
+        .remove_tag("other")
+        .await
+        .expect("Cannot remove a tag");
+    server
+        .add_tag("rust-openstack-2")
+        .await
+        .expect("Cannot add a tag");
+    server.refresh().await.expect("Cannot refresh the server");
+    assert_eq!(server.tags().len(), 2);
+
+    let found = os
+        .find_servers()
+        .with_tags("rust-openstack,rust-openstack-2")
+        .with_not_tags_any("other")
+        .one()
+        .await
+        .expect("Cannot find the server by tags");
+    assert_eq!(found.id(), server.id());
+}
+
 #[tokio::test]
 async fn test_basic_server_ops() {
     let os = set_up().await;
@@ -135,6 +185,7 @@ async fn test_basic_server_ops() {
         .expect("Server was not created");
 
     validate_server(&os, &mut server).await;
+    update_server(&os, &mut server).await;
 
     let ports = os
         .find_ports()
@@ -367,3 +418,309 @@ async fn test_server_boot_from_new_volume() {
         .await
         .expect("Cannot delete key pair");
 }
+
+#[tokio::test]
+async fn test_flavor_create_access_delete() {
+    let os = set_up().await;
+
+    let mut flavor = os
+        .new_flavor("rust-openstack-integration", 64, 1, 1)
+        .with_public(false)
+        .with_swap_size(16)
+        .create()
+        .await
+        .expect("Cannot create a flavor");
+    assert!(!flavor.is_public());
+    assert_eq!(flavor.swap_size(), 16);
+
+    flavor
+        .set_extra_spec("hw:cpu_policy", "shared")
+        .await
+        .expect("Cannot set an extra spec");
+    flavor.refresh().await.expect("Cannot refresh the flavor");
+    let fetched = os
+        .get_flavor(flavor.id())
+        .await
+        .expect("Cannot get the flavor");
+    assert_eq!(
+        fetched.extra_specs().get("hw:cpu_policy"),
+        Some(&"shared".to_string())
+    );
+    flavor
+        .unset_extra_spec("hw:cpu_policy")
+        .await
+        .expect("Cannot unset an extra spec");
+    assert!(flavor.extra_specs().is_empty());
+
+    let project = os
+        .find_projects()
+        .with_name("demo")
+        .one()
+        .await
+        .expect("Cannot find the demo project");
+    flavor
+        .add_access(project.clone())
+        .await
+        .expect("Cannot grant flavor access");
+    let access = flavor.list_access().await.expect("Cannot list access");
+    assert!(access.iter().any(|item| item.as_ref() == project.id()));
+    flavor
+        .remove_access(project)
+        .await
+        .expect("Cannot revoke flavor access");
+
+    let found = os
+        .find_flavors()
+        .with_public(false)
+        .with_min_ram(64u64)
+        .all()
+        .await
+        .expect("Cannot list private flavors");
+    assert!(found.iter().any(|item| item.id() == flavor.id()));
+
+    flavor.delete().await.expect("Cannot delete the flavor");
+}
+
+#[tokio::test]
+async fn test_server_group_create_delete() {
+    let os = set_up().await;
+    let image_id = env::var("RUST_OPENSTACK_IMAGE").expect("Missing RUST_OPENSTACK_IMAGE");
+    let flavor_id = env::var("RUST_OPENSTACK_FLAVOR").expect("Missing RUST_OPENSTACK_FLAVOR");
+    let network_id = env::var("RUST_OPENSTACK_NETWORK").expect("Missing RUST_OPENSTACK_NETWORK");
+
+    let group = os
+        .new_server_group(
+            "rust-openstack-integration",
+            openstack::compute::ServerGroupPolicy::AntiAffinity,
+        )
+        .create()
+        .await
+        .expect("Cannot create a server group");
+    assert_eq!(
+        group.policy(),
+        Some(openstack::compute::ServerGroupPolicy::AntiAffinity)
+    );
+    assert!(group.members().is_empty());
+
+    let server = os
+        .new_server("rust-openstack-integration", flavor_id)
+        .with_image(image_id)
+        .with_network(network_id)
+        .with_server_group(group.clone())
+        .create()
+        .await
+        .expect("Failed to request server creation")
+        .wait()
+        .await
+        .expect("Server was not created");
+
+    let groups = server
+        .server_groups()
+        .await
+        .expect("Cannot fetch server groups");
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].id(), group.id());
+    assert!(groups[0].members().contains(server.id()));
+
+    server
+        .delete()
+        .await
+        .expect("Failed to request deletion")
+        .wait()
+        .await
+        .expect("Failed to delete server");
+
+    let found = os
+        .get_server_group(group.name())
+        .await
+        .expect("Cannot get the server group");
+    assert_eq!(found.id(), group.id());
+    group
+        .delete()
+        .await
+        .expect("Cannot delete the server group");
+}
+
+#[tokio::test]
+async fn test_server_batch_create_delete() {
+    let os = set_up().await;
+    let image_id = env::var("RUST_OPENSTACK_IMAGE").expect("Missing RUST_OPENSTACK_IMAGE");
+    let flavor_id = env::var("RUST_OPENSTACK_FLAVOR").expect("Missing RUST_OPENSTACK_FLAVOR");
+    let network_id = env::var("RUST_OPENSTACK_NETWORK").expect("Missing RUST_OPENSTACK_NETWORK");
+
+    let waiter = os
+        .new_server("rust-openstack-integration", flavor_id)
+        .with_image(image_id)
+        .with_network(network_id)
+        .with_count(2, 2)
+        .create_batch()
+        .await
+        .expect("Failed to request server creation");
+    let reservation_id = waiter.reservation_id().clone();
+    let servers = waiter.wait().await.expect("Servers were not created");
+    assert_eq!(servers.len(), 2);
+
+    let found = os
+        .find_servers()
+        .with_reservation_id(reservation_id)
+        .all()
+        .await
+        .expect("Cannot list servers by reservation ID");
+    assert_eq!(found.len(), 2);
+
+    for server in servers {
+        server
+            .delete()
+            .await
+            .expect("Failed to request deletion")
+            .wait()
+            .await
+            .expect("Failed to delete server");
+    }
+}
+
+#[tokio::test]
+async fn test_server_create_image() {
+    let os = set_up().await;
+    let image_id = env::var("RUST_OPENSTACK_IMAGE").expect("Missing RUST_OPENSTACK_IMAGE");
+    let flavor_id = env::var("RUST_OPENSTACK_FLAVOR").expect("Missing RUST_OPENSTACK_FLAVOR");
+    let network_id = env::var("RUST_OPENSTACK_NETWORK").expect("Missing RUST_OPENSTACK_NETWORK");
+
+    let server = os
+        .new_server("rust-openstack-integration", flavor_id)
+        .with_image(image_id)
+        .with_network(network_id)
+        .create()
+        .await
+        .expect("Failed to request server creation")
+        .wait()
+        .await
+        .expect("Server was not created");
+
+    let image = server
+        .create_image("rust-openstack-integration-snapshot", HashMap::new())
+        .await
+        .expect("Failed to request a snapshot")
+        .wait()
+        .await
+        .expect("Snapshot was not created");
+    assert_eq!(image.name(), "rust-openstack-integration-snapshot");
+
+    image
+        .delete()
+        .await
+        .expect("Failed to request image deletion")
+        .wait()
+        .await
+        .expect("Failed to delete image");
+
+    server
+        .delete()
+        .await
+        .expect("Failed to request deletion")
+        .wait()
+        .await
+        .expect("Failed to delete server");
+}
+
+#[tokio::test]
+async fn test_server_interface_attach_detach() {
+    let os = set_up().await;
+    let image_id = env::var("RUST_OPENSTACK_IMAGE").expect("Missing RUST_OPENSTACK_IMAGE");
+    let flavor_id = env::var("RUST_OPENSTACK_FLAVOR").expect("Missing RUST_OPENSTACK_FLAVOR");
+    let network_id = env::var("RUST_OPENSTACK_NETWORK").expect("Missing RUST_OPENSTACK_NETWORK");
+
+    let server = os
+        .new_server("rust-openstack-integration", flavor_id)
+        .with_image(image_id)
+        .with_network(network_id.clone())
+        .create()
+        .await
+        .expect("Failed to request server creation")
+        .wait()
+        .await
+        .expect("Server was not created");
+    let interfaces = server.interfaces().await.expect("Cannot list interfaces");
+    assert_eq!(interfaces.len(), 1);
+
+    let port = os
+        .new_port(network_id.clone())
+        .with_name("rust-openstack-integration")
+        .create()
+        .await
+        .expect("Cannot create a port");
+    let port = server
+        .attach_interface(port)
+        .await
+        .expect("Failed to request attaching a port")
+        .wait()
+        .await
+        .expect("Port was not attached");
+    assert_eq!(port.device_id().as_ref(), Some(server.id()));
+
+    let new_port = server
+        .attach_network_interface(network_id, None)
+        .await
+        .expect("Failed to request attaching a network")
+        .wait()
+        .await
+        .expect("Network was not attached");
+    let interfaces = server.interfaces().await.expect("Cannot list interfaces");
+    assert_eq!(interfaces.len(), 3);
+
+    let detached = server
+        .detach_interface(new_port)
+        .await
+        .expect("Failed to request detaching a port")
+        .wait()
+        .await
+        .expect("Port was not detached");
+    assert!(detached.is_none());
+
+    let port = server
+        .detach_interface(port)
+        .await
+        .expect("Failed to request detaching a port")
+        .wait()
+        .await
+        .expect("Port was not detached")
+        .expect("Port was deleted");
+    port.delete()
+        .await
+        .expect("Failed to request port deletion")
+        .wait()
+        .await
+        .expect("Failed to delete port");
+
+    server
+        .delete()
+        .await
+        .expect("Failed to request deletion")
+        .wait()
+        .await
+        .expect("Failed to delete server");
+}
+
+#[tokio::test]
+async fn test_keypair_import() {
+    let os = set_up().await;
+    let keypair_file_name =
+        env::var("RUST_OPENSTACK_KEYPAIR").expect("Missing RUST_OPENSTACK_KEYPAIR");
+
+    let public_key = openstack::compute::PublicKey::from_file(keypair_file_name)
+        .await
+        .expect("Cannot parse RUST_OPENSTACK_KEYPAIR");
+    let keypair = os
+        .new_keypair("rust-openstack-integration-import")
+        .with_ssh_public_key(public_key.clone())
+        .create()
+        .await
+        .expect("Cannot import a key pair");
+    assert_eq!(keypair.fingerprint(), &public_key.fingerprint());
+    assert_eq!(
+        keypair.key_type(),
+        Some(openstack::compute::KeyPairType::SSH)
+    );
+
+    keypair.delete().await.expect("Cannot delete key pair");
+}
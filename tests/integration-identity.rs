@@ -0,0 +1,133 @@
+// Copyright 2026 The rust-openstack Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Once;
+
+use futures::TryStreamExt;
+use openstack::Refresh;
+
+static INIT: Once = Once::new();
+
+async fn set_up() -> openstack::Cloud {
+    INIT.call_once(|| {
+        env_logger::init();
+    });
+
+    openstack::Cloud::from_env()
+        .await
+        .expect("Failed to create an identity provider from the environment")
+}
+
+#[tokio::test]
+async fn test_project_user_role_assignment() {
+    let os = set_up().await;
+
+    let mut project = os
+        .new_project("rust-openstack-test-project")
+        .with_description("Project for integration testing")
+        .with_tag("rust-openstack")
+        .create()
+        .await
+        .expect("Could not create a project");
+    assert_eq!(project.name(), "rust-openstack-test-project");
+    assert_eq!(project.tags(), &vec!["rust-openstack".to_string()]);
+
+    project.set_enabled(false);
+    project.save().await.expect("Could not update the project");
+    project
+        .refresh()
+        .await
+        .expect("Could not refresh the project");
+    assert!(!project.enabled());
+
+    let user = os
+        .new_user("rust-openstack-test-user")
+        .with_default_project(project.clone())
+        .with_password("0p3nSt4ck!")
+        .create()
+        .await
+        .expect("Could not create a user");
+    assert_eq!(user.default_project_id().as_ref(), Some(project.id()));
+
+    let role = os.get_role("member").await.expect("Could not find a role");
+    os.new_role_assignment(role.clone())
+        .with_user(user.clone())
+        .with_project(project.clone())
+        .create()
+        .await
+        .expect("Could not assign a role");
+
+    let assignments: Vec<_> = os
+        .find_role_assignments()
+        .with_project_id(project.id())
+        .into_stream()
+        .try_collect()
+        .await
+        .expect("Could not list role assignments");
+    assert_eq!(assignments.len(), 1);
+    assert_eq!(assignments[0].role_id(), role.id());
+    assert_eq!(assignments[0].user_id(), Some(user.id()));
+
+    for assignment in assignments {
+        assignment
+            .delete()
+            .await
+            .expect("Could not revoke a role assignment");
+    }
+
+    user.delete().await.expect("Could not delete the user");
+    project
+        .delete()
+        .await
+        .expect("Could not delete the project");
+}
+
+#[tokio::test]
+async fn test_application_credential_create_auth_delete() {
+    let os = set_up().await;
+
+    let credential = os
+        .new_application_credential("rust-openstack-test-credential")
+        .with_description("Credential for integration testing")
+        .create()
+        .await
+        .expect("Could not create an application credential");
+    assert!(credential.secret().is_some());
+    assert!(!credential.unrestricted());
+
+    let auth = credential
+        .to_auth()
+        .await
+        .expect("Could not build authentication");
+    let os2 = openstack::Cloud::new(auth)
+        .await
+        .expect("Could not authenticate with the application credential");
+    let _ = os2
+        .find_application_credentials()
+        .all()
+        .await
+        .expect("Could not list application credentials");
+
+    let found = os
+        .get_application_credential("rust-openstack-test-credential")
+        .await
+        .expect("Could not find the application credential");
+    assert_eq!(found.id(), credential.id());
+    assert!(found.secret().is_none());
+
+    credential
+        .delete()
+        .await
+        .expect("Could not delete the application credential");
+}
@@ -0,0 +1,99 @@
+// Copyright 2026 The rust-openstack Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Once;
+
+use futures::io::Cursor;
+use openstack::image::{ImageContainerFormat, ImageDiskFormat, ImageStatus, ImageVisibility};
+use openstack::waiter::Waiter;
+
+static INIT: Once = Once::new();
+
+async fn set_up() -> openstack::Cloud {
+    INIT.call_once(|| {
+        env_logger::init();
+    });
+
+    openstack::Cloud::from_env()
+        .await
+        .expect("Failed to create an identity provider from the environment")
+}
+
+#[tokio::test]
+async fn test_image_create_upload_delete() {
+    let os = set_up().await;
+    let data = vec![42u8; 1024 * 1024];
+
+    let image = os
+        .new_image()
+        .with_name("rust-openstack-integration")
+        .with_container_format(ImageContainerFormat::Bare)
+        .with_disk_format(ImageDiskFormat::Raw)
+        .with_visibility(ImageVisibility::Private)
+        .with_min_disk(1)
+        .with_property("rust_openstack", "integration")
+        .create()
+        .await
+        .expect("Could not create image");
+    assert_eq!(image.name(), "rust-openstack-integration");
+    assert_eq!(image.status(), ImageStatus::Queued);
+    assert_eq!(image.minimum_required_disk(), 1);
+
+    let image = image
+        .upload(Cursor::new(data.clone()))
+        .await
+        .expect("Could not upload image data")
+        .wait()
+        .await
+        .expect("Image did not become active");
+    assert_eq!(image.status(), ImageStatus::Active);
+    assert_eq!(image.size(), Some(data.len() as u64));
+
+    let found = os.get_image(image.id()).await.expect("Could not get image");
+    assert_eq!(found.status(), ImageStatus::Active);
+
+    image
+        .delete()
+        .await
+        .expect("Cannot request image deletion")
+        .wait()
+        .await
+        .expect("Image was not deleted");
+}
+
+#[tokio::test]
+async fn test_image_create_with_data() {
+    let os = set_up().await;
+
+    let image = os
+        .new_image()
+        .with_name("rust-openstack-integration-2")
+        .with_container_format(ImageContainerFormat::Bare)
+        .with_disk_format(ImageDiskFormat::Raw)
+        .create_with_data(Cursor::new(vec![1u8; 4096]))
+        .await
+        .expect("Could not create image")
+        .wait()
+        .await
+        .expect("Image did not become active");
+    assert_eq!(image.size(), Some(4096));
+
+    image
+        .delete()
+        .await
+        .expect("Cannot request image deletion")
+        .wait()
+        .await
+        .expect("Image was not deleted");
+}
@@ -26,6 +26,28 @@ async fn set_up() -> openstack::Cloud {
         .expect("Failed to create an identity provider from the environment")
 }
 
+#[tokio::test]
+async fn test_list_availability_zones() {
+    let os = set_up().await;
+    let items = os
+        .list_availability_zones()
+        .await
+        .expect("Cannot list availability zones");
+    assert!(!items.is_empty());
+}
+
+#[tokio::test]
+async fn test_compute_limits() {
+    let os = set_up().await;
+    let limits = os
+        .compute_limits()
+        .await
+        .expect("Cannot get compute limits");
+    if let Some(max) = limits.instances.max {
+        assert!(limits.instances.remaining().unwrap() <= max);
+    }
+}
+
 #[tokio::test]
 async fn test_list_containers() {
     let os = set_up().await;
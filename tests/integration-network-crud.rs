@@ -661,3 +661,116 @@ async fn test_router_update() {
         .await
         .expect("Router was not deleted.");
 }
+
+#[tokio::test]
+async fn test_security_group_create_update_delete() {
+    use openstack::network::{SecurityGroupRuleDirection, SecurityGroupRuleProtocol};
+
+    let os = set_up().await;
+    let network_id = env::var("RUST_OPENSTACK_NETWORK").expect("Missing RUST_OPENSTACK_NETWORK");
+
+    let mut security_group = os
+        .new_security_group()
+        .with_name("rust-openstack-integration")
+        .create()
+        .await
+        .expect("Could not create security group");
+    assert_eq!(
+        security_group.name().as_ref().unwrap(),
+        "rust-openstack-integration"
+    );
+    assert!(security_group.description().is_none());
+    // Neutron creates default egress rules
+    assert!(security_group
+        .rules()
+        .iter()
+        .all(|rule| rule.direction() == SecurityGroupRuleDirection::Egress));
+
+    security_group.set_description("Integration testing");
+    assert!(security_group.is_dirty());
+    security_group
+        .save()
+        .await
+        .expect("Cannot update security group");
+    assert_eq!(
+        security_group.description().as_ref().unwrap(),
+        "Integration testing"
+    );
+    assert!(!security_group.is_dirty());
+
+    let rule = os
+        .new_security_group_rule(
+            "rust-openstack-integration",
+            SecurityGroupRuleDirection::Ingress,
+        )
+        .with_protocol(SecurityGroupRuleProtocol::TCP)
+        .with_port_range(22, 22)
+        .with_remote_ip_prefix("10.0.0.0/8".parse().unwrap())
+        .create()
+        .await
+        .expect("Could not create security group rule");
+    assert_eq!(rule.security_group_id(), security_group.id());
+    assert_eq!(rule.protocol(), Some(SecurityGroupRuleProtocol::TCP));
+    assert_eq!(rule.port_range_min(), Some(22));
+    assert_eq!(rule.port_range_max(), Some(22));
+    assert!(rule.remote_group_id().is_none());
+
+    let err = os
+        .new_security_group_rule(security_group.clone(), SecurityGroupRuleDirection::Ingress)
+        .with_remote_group(security_group.clone())
+        .with_remote_ip_prefix("10.0.0.0/8".parse().unwrap())
+        .create()
+        .await
+        .expect_err("Conflicting remote group and CIDR were accepted");
+    assert_eq!(err.kind(), openstack::ErrorKind::InvalidInput);
+
+    security_group
+        .refresh()
+        .await
+        .expect("Cannot refresh security group");
+    assert!(security_group.rules().iter().any(|r| r.id() == rule.id()));
+
+    let found = os
+        .find_security_groups()
+        .with_name("rust-openstack-integration")
+        .one()
+        .await
+        .expect("Cannot find security group");
+    assert_eq!(found.id(), security_group.id());
+
+    let port = os
+        .new_port(network_id)
+        .with_security_group("rust-openstack-integration")
+        .create()
+        .await
+        .expect("Could not create port");
+    assert_eq!(
+        port.security_groups()
+            .iter()
+            .map(|sg| sg.as_ref())
+            .collect::<Vec<_>>(),
+        vec![security_group.id().as_str()]
+    );
+
+    port.delete()
+        .await
+        .expect("Cannot request port deletion")
+        .wait()
+        .await
+        .expect("Port was not deleted");
+
+    rule.delete()
+        .await
+        .expect("Cannot request rule deletion")
+        .wait()
+        .await
+        .expect("Rule was not deleted");
+
+    security_group
+        .delete()
+        .await
+        .expect("Cannot request security group deletion")
+        .wait()
+        .await
+        .expect("Security group was not deleted");
+}